@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use t4t::*;
+use t4t_games::dilemma::*;
+
+/// Time playing every matchup of a tournament, both with and without compiling the game first.
+fn time_matchups(game: &Repeated<Dilemma, 2>, matchups: &[Matchup<Repeated<Dilemma, 2>, 2>]) {
+    let time = |play: &dyn Fn(&Matchup<Repeated<Dilemma, 2>, 2>)| -> Duration {
+        let start = Instant::now();
+        for _ in 0..20 {
+            matchups.iter().for_each(play);
+        }
+        start.elapsed()
+    };
+
+    let uncompiled = time(&|matchup| {
+        game.play(matchup).unwrap();
+    });
+    let compiled_game = game.compile();
+    let compiled = time(&|matchup| {
+        compiled_game.play(matchup).unwrap();
+    });
+
+    println!("without compiling: {:?}", uncompiled);
+    println!("   with compiling: {:?}", compiled);
+}
+
+/// Compares the time to play the matchups of a repeated prisoner's dilemma tournament, with and
+/// without compiling the game's tree beforehand.
+pub fn main() {
+    let players = vec![
+        Arc::new(cooperator()),
+        Arc::new(defector()),
+        Arc::new(periodic(vec![C, D])),
+        Arc::new(tit_for_tat()),
+        Arc::new(tit_for_n_tats(2)),
+        Arc::new(generous_tit_for_tat()),
+        Arc::new(pavlov()),
+        Arc::new(grim_trigger()),
+    ];
+
+    for repetitions in [1, 100] {
+        println!("== {} repetition(s) ==", repetitions);
+        let game = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), repetitions);
+        let tournament = Tournament::combinations_with_replacement(Arc::new(game), &players);
+        time_matchups(tournament.game(), tournament.matchups());
+    }
+}
@@ -0,0 +1,398 @@
+//! The [Connect Four](https://en.wikipedia.org/wiki/Connect_Four) game.
+//!
+//! Two players take turns dropping pieces into the columns of a vertical grid with
+//! [`COLUMNS`] columns and [`ROWS`] rows. A piece falls to the lowest empty row of its column. The
+//! first player to line up four of their pieces horizontally, vertically, or diagonally wins. If
+//! the grid fills up before either player connects four, the game is a draw.
+//!
+//! A move is the index of the column to drop a piece into, starting from `0` at the left.
+
+use std::sync::Arc;
+use t4t::*;
+
+/// The number of columns in the grid.
+pub const COLUMNS: usize = 7;
+
+/// The number of rows in the grid.
+pub const ROWS: usize = 6;
+
+/// The number of pieces a player must line up to win.
+const CONNECT: usize = 4;
+
+/// A move in Connect Four: the index of the column to drop a piece into.
+pub type Column = usize;
+
+/// The state of a Connect Four game: the pieces in the grid, the player to move next, and the
+/// transcript of moves played so far.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Board {
+    cells: [[Option<PlayerIndex<2>>; ROWS]; COLUMNS],
+    heights: [usize; COLUMNS],
+    to_move: PlayerIndex<2>,
+    transcript: Transcript<Column, 2>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new()
+    }
+}
+
+impl Board {
+    /// Construct an empty board, where player `P0` moves first.
+    pub fn new() -> Self {
+        Board {
+            cells: [[None; ROWS]; COLUMNS],
+            heights: [0; COLUMNS],
+            to_move: for2::P0,
+            transcript: Transcript::new(),
+        }
+    }
+
+    /// Construct a board by playing the given sequence of moves from an empty board.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if any of the moves is invalid, that is, if the column
+    /// does not exist or is already full, or if the game was already won before the move.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    /// use t4t_games::connect_four::Board;
+    ///
+    /// let board = Board::from_moves(&[3, 3, 4]).unwrap();
+    /// assert_eq!(board.get(3, 0), Some(for2::P0));
+    /// assert_eq!(board.get(3, 1), Some(for2::P1));
+    /// assert_eq!(board.get(4, 0), Some(for2::P0));
+    /// assert_eq!(board.to_move(), for2::P1);
+    ///
+    /// assert!(Board::from_moves(&[7]).is_none());
+    /// ```
+    pub fn from_moves(columns: &[Column]) -> Option<Self> {
+        let mut board = Board::new();
+        for &column in columns {
+            if board.winner().is_some() {
+                log::error!(
+                    "Board::from_moves: move in column {} played after the game was won",
+                    column
+                );
+                return None;
+            }
+            board = board.drop_piece(column)?;
+        }
+        Some(board)
+    }
+
+    /// Get the player whose piece occupies the given cell, if any.
+    ///
+    /// Rows are indexed from `0` at the bottom. Returns `None` if the cell is empty or does not
+    /// exist.
+    pub fn get(&self, column: Column, row: usize) -> Option<PlayerIndex<2>> {
+        self.cells.get(column)?.get(row).copied().flatten()
+    }
+
+    /// Get the player to move next.
+    pub fn to_move(&self) -> PlayerIndex<2> {
+        self.to_move
+    }
+
+    /// Get the transcript of moves played so far.
+    pub fn transcript(&self) -> &Transcript<Column, 2> {
+        &self.transcript
+    }
+
+    /// Can a piece be dropped into the given column?
+    pub fn is_open(&self, column: Column) -> bool {
+        column < COLUMNS && self.heights[column] < ROWS
+    }
+
+    /// Get the columns that a piece can be dropped into.
+    pub fn open_columns(&self) -> Vec<Column> {
+        (0..COLUMNS)
+            .filter(|&column| self.is_open(column))
+            .collect()
+    }
+
+    /// Is every cell of the grid occupied?
+    pub fn is_full(&self) -> bool {
+        self.heights.iter().all(|&height| height == ROWS)
+    }
+
+    /// Drop a piece for the player to move into the given column, producing the next board.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the column does not exist or is already full.
+    pub fn drop_piece(&self, column: Column) -> Option<Self> {
+        if !self.is_open(column) {
+            log::error!(
+                "Board::drop_piece: cannot drop a piece into column {}",
+                column
+            );
+            return None;
+        }
+
+        let mut next = self.clone();
+        next.cells[column][self.heights[column]] = Some(self.to_move);
+        next.heights[column] += 1;
+        next.transcript.add_player_move(self.to_move, column);
+        next.to_move = if self.to_move == for2::P0 {
+            for2::P1
+        } else {
+            for2::P0
+        };
+        Some(next)
+    }
+
+    /// Get the player who has connected four pieces, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    /// use t4t_games::connect_four::Board;
+    ///
+    /// // P0 connects four diagonally, from the bottom left.
+    /// let board = Board::from_moves(&[0, 1, 1, 2, 2, 3, 2, 3, 3, 6, 3]).unwrap();
+    /// assert_eq!(board.winner(), Some(for2::P0));
+    ///
+    /// let board = Board::from_moves(&[0, 1, 1, 2, 2, 3, 2, 3, 3, 6]).unwrap();
+    /// assert_eq!(board.winner(), None);
+    /// ```
+    pub fn winner(&self) -> Option<PlayerIndex<2>> {
+        let directions: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+        for column in 0..COLUMNS {
+            for row in 0..ROWS {
+                let Some(player) = self.cells[column][row] else {
+                    continue;
+                };
+                for (dc, dr) in directions {
+                    let connected = (1..CONNECT as isize).all(|i| {
+                        let c = column as isize + i * dc;
+                        let r = row as isize + i * dr;
+                        c >= 0 && r >= 0 && self.get(c as usize, r as usize) == Some(player)
+                    });
+                    if connected {
+                        return Some(player);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Get the payoff for this board if the game is over, or `None` if the game is still in
+    /// progress.
+    ///
+    /// The winner is awarded `1` and the loser `-1`. In a draw, both players are awarded `0`.
+    pub fn final_payoff(&self) -> Option<Payoff<i64, 2>> {
+        match self.winner() {
+            Some(for2::P0) => Some(Payoff::from([1, -1])),
+            Some(_) => Some(Payoff::from([-1, 1])),
+            None if self.is_full() => Some(Payoff::zeros()),
+            None => None,
+        }
+    }
+}
+
+/// The Connect Four game, played on a [`COLUMNS`] by [`ROWS`] grid.
+///
+/// # Examples
+/// ```
+/// use t4t::*;
+/// use t4t_games::connect_four::{self, ConnectFour};
+///
+/// let leftmost = || Player::new("Leftmost".to_string(), connect_four::leftmost);
+/// let outcome = ConnectFour
+///     .play(&Matchup::from_players([leftmost(), leftmost()]))
+///     .unwrap();
+///
+/// // Filling the columns from left to right, P0 connects four along the bottom row.
+/// assert_eq!(*outcome.payoff(), Payoff::from([1, -1]));
+/// assert_eq!(outcome.transcript().into_iter().count(), 19);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ConnectFour;
+
+fn node(board: Board) -> GameTree<Board, Column, i64, SequentialOutcome<Column, i64, 2>, 2> {
+    if let Some(payoff) = board.final_payoff() {
+        let outcome = SequentialOutcome::new(board.transcript.clone(), payoff);
+        return GameTree::end(Arc::new(board), outcome);
+    }
+
+    let to_move = board.to_move;
+    GameTree::player(
+        Arc::new(board),
+        to_move,
+        move |board: Arc<Board>, column| match board.drop_piece(column) {
+            Some(next) => Ok(node(next)),
+            None => Err(ErrorKind::InvalidMove(to_move, column)),
+        },
+    )
+}
+
+impl Game<2> for ConnectFour {
+    type Move = Column;
+    type Utility = i64;
+    type Outcome = SequentialOutcome<Column, i64, 2>;
+    type State = Board;
+    type View = Board;
+
+    fn into_game_tree(self) -> GameTree<Board, Column, i64, Self::Outcome, 2> {
+        node(Board::new())
+    }
+
+    fn state_view(&self, state: &Board, _player: PlayerIndex<2>) -> Board {
+        state.clone()
+    }
+}
+
+impl FiniteGame<2> for ConnectFour {
+    fn possible_moves(&self, player: PlayerIndex<2>, state: &Board) -> PossibleMoves<'_, Column> {
+        if player == state.to_move() && state.final_payoff().is_none() {
+            PossibleMoves::from_vec(state.open_columns())
+        } else {
+            PossibleMoves::from_vec(Vec::new())
+        }
+    }
+}
+
+/// A strategy that always drops a piece into the leftmost open column.
+pub fn leftmost() -> Strategy<Board, Column, 2> {
+    Strategy::new(|context: &Context<Board, 2>| {
+        context
+            .state_view()
+            .open_columns()
+            .first()
+            .copied()
+            .unwrap_or(0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn observer_sees_one_move_per_ply_and_final_outcome() {
+        let matchup = Matchup::from_players([
+            Player::new("Left".to_string(), leftmost),
+            Player::new("Left".to_string(), leftmost),
+        ]);
+
+        let mut turns = 0;
+        let mut moves = Vec::new();
+        let mut ends = Vec::new();
+        let outcome = ConnectFour
+            .play_with_observer(&matchup, |event| match event {
+                TraceEvent::Turn { .. } => turns += 1,
+                TraceEvent::Move(ply, _) => moves.push(*ply),
+                TraceEvent::End { payoff, .. } => ends.push(**payoff),
+                TraceEvent::Chance { .. } => panic!("unexpected move of chance"),
+            })
+            .unwrap();
+
+        let plies: Vec<_> = outcome.transcript().plies().collect();
+        assert_eq!(moves, plies);
+        assert_eq!(turns, plies.len());
+        assert_eq!(ends, vec![*outcome.payoff()]);
+    }
+
+    #[test]
+    fn human_player_with_scripted_input_plays_to_completion() {
+        // The first entry is out of range, so the human is asked again on the same board.
+        let mut input = vec![9, 3, 3, 3, 3].into_iter();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let human = Player::human("Human".to_string(), move |context: &Context<Board, 2>| {
+            tx.send(context.state_view().clone()).unwrap();
+            input.next().unwrap_or(0)
+        });
+        let matchup = Matchup::from_players([human, Player::new("Left".to_string(), leftmost)]);
+
+        let outcome = ConnectFour.play_with_retries(&matchup, 1).unwrap();
+        let prompts: Vec<Board> = rx.try_iter().collect();
+
+        // The human stacks four pieces in column 3 while the bot fills column 0.
+        assert_eq!(*outcome.payoff(), Payoff::from([1, -1]));
+        assert_eq!(
+            outcome
+                .transcript()
+                .plies()
+                .map(|ply| ply.the_move)
+                .collect::<Vec<_>>(),
+            vec![3, 0, 3, 0, 3, 0, 3],
+        );
+        assert_eq!(prompts.len(), 5);
+        assert_eq!(prompts[0], prompts[1]);
+
+        // Without retries, the invalid entry ends the game.
+        let human = Player::human("Human".to_string(), |_: &Context<Board, 2>| 9);
+        let matchup = Matchup::from_players([human, Player::new("Left".to_string(), leftmost)]);
+        assert_eq!(
+            ConnectFour.play(&matchup).unwrap_err().kind,
+            ErrorKind::StrategyFailedToProduceValidMove(for2::P0, 9),
+        );
+    }
+
+    #[test]
+    fn horizontal_win() {
+        let board = Board::from_moves(&[2, 2, 3, 3, 4, 4]).unwrap();
+        assert_eq!(board.winner(), None);
+        let board = board.drop_piece(5).unwrap();
+        assert_eq!(board.winner(), Some(for2::P0));
+        assert_eq!(board.final_payoff(), Some(Payoff::from([1, -1])));
+    }
+
+    #[test]
+    fn vertical_win() {
+        let board = Board::from_moves(&[0, 6, 1, 6, 0, 6, 1]).unwrap();
+        assert_eq!(board.winner(), None);
+        let board = board.drop_piece(6).unwrap();
+        assert_eq!(board.winner(), Some(for2::P1));
+        assert_eq!(board.final_payoff(), Some(Payoff::from([-1, 1])));
+    }
+
+    #[test]
+    fn diagonal_wins() {
+        // Rising to the right, from (0, 0) to (3, 3).
+        let rising = Board::from_moves(&[0, 1, 1, 2, 2, 3, 2, 3, 3, 6, 3]).unwrap();
+        assert_eq!(rising.winner(), Some(for2::P0));
+
+        // Falling to the right, from (3, 3) to (6, 0).
+        let falling = Board::from_moves(&[6, 5, 5, 4, 4, 3, 4, 3, 3, 0, 3]).unwrap();
+        assert_eq!(falling.winner(), Some(for2::P0));
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let full_column = Board::from_moves(&[0, 0, 0, 0, 0, 0]).unwrap();
+        assert!(!full_column.is_open(0));
+        assert_eq!(full_column.drop_piece(0), None);
+        assert_eq!(full_column.drop_piece(COLUMNS), None);
+        assert_eq!(full_column.open_columns(), vec![1, 2, 3, 4, 5, 6]);
+
+        // The game ends after a win, so no further moves are possible.
+        assert!(Board::from_moves(&[0, 1, 0, 1, 0, 1, 0, 1]).is_none());
+    }
+
+    #[test]
+    fn draw() {
+        // Fill columns in pairs with a shifted pattern so that no one connects four.
+        let mut moves = Vec::new();
+        for pair in [[0, 1], [2, 3], [4, 5]] {
+            for _ in 0..3 {
+                moves.extend([pair[0], pair[1]]);
+            }
+            for _ in 0..3 {
+                moves.extend([pair[1], pair[0]]);
+            }
+        }
+        moves.extend([6; ROWS]);
+
+        let board = Board::from_moves(&moves).unwrap();
+        assert!(board.is_full());
+        assert_eq!(board.winner(), None);
+        assert_eq!(board.final_payoff(), Some(Payoff::zeros()));
+    }
+}
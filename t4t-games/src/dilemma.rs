@@ -27,6 +27,7 @@
 #![doc = include_str!("../examples/axelrod.rs")]
 //! ```
 
+use std::collections::VecDeque;
 use t4t::*;
 
 /// In a social dilemma game, each player may either cooperate or defect.
@@ -398,6 +399,12 @@ impl Game<2> for Dilemma {
     fn state_view(&self, _state: &(), _player: PlayerIndex<2>) {}
 }
 
+impl FiniteGame<2> for Dilemma {
+    fn possible_moves(&self, player: PlayerIndex<2>, _state: &()) -> PossibleMoves<'_, Move> {
+        self.as_normal().possible_moves_for_player(player)
+    }
+}
+
 // Strategies
 
 /// A player in a repeated social dilemma game.
@@ -446,14 +453,7 @@ pub fn random_ccd() -> DilemmaPlayer {
 /// opponent defects.
 pub fn tit_for_tat() -> DilemmaPlayer {
     Player::new("Tit-for-Tat".to_string(), || {
-        Strategy::new(|context: &DilemmaContext| {
-            context
-                .state_view()
-                .history()
-                .moves_for_player(context.their_index())
-                .last()
-                .unwrap_or(C)
-        })
+        Strategy::new(|context: &DilemmaContext| context.their_last_move().unwrap_or(C))
     })
 }
 
@@ -462,14 +462,7 @@ pub fn tit_for_tat() -> DilemmaPlayer {
 /// Like [Tit-for-Tat](tit_for_tat) but defects on the first move.
 pub fn suspicious_tit_for_tat() -> DilemmaPlayer {
     Player::new("Suspicious Tit-for-Tat".to_string(), || {
-        Strategy::new(|context: &DilemmaContext| {
-            context
-                .state_view()
-                .history()
-                .moves_for_player(context.their_index())
-                .last()
-                .unwrap_or(D)
-        })
+        Strategy::new(|context: &DilemmaContext| context.their_last_move().unwrap_or(D))
     })
 }
 
@@ -538,14 +531,7 @@ pub fn probabilistic_tit_for_tat(
         format!("Probabilistic Tit-for-Tat {}", name_suffix),
         move || {
             Strategy::conditional(
-                |context: &DilemmaContext| {
-                    context
-                        .state_view()
-                        .history()
-                        .moves_for_player(context.their_index())
-                        .last()
-                        .map_or(false, |m| m == D)
-                },
+                |context: &DilemmaContext| context.their_last_move().is_some_and(|m| m == D),
                 Strategy::mixed(on_defect.clone()),
                 Strategy::mixed(on_cooperate.clone()),
             )
@@ -573,6 +559,62 @@ pub fn probing_tit_for_tat() -> DilemmaPlayer {
     )
 }
 
+/// A player that plays a [zero-determinant](https://en.wikipedia.org/wiki/Prisoner%27s_dilemma#Zero-determinant_strategies)
+/// extortion strategy, as described by
+/// [Press and Dyson (2012)](https://doi.org/10.1073/pnas.1206569109).
+///
+/// The player defects on the first move. Thereafter, it cooperates with a probability determined
+/// by the previous round's profile (CC, CD, DC, or DD, from its own perspective). The probabilities
+/// are chosen to enforce, over a long repeated game, the linear relationship
+/// `s_x - P = factor * (s_y - P)` between its average utility `s_x` and the opponent's average
+/// utility `s_y`, where `P` is the mutual defection utility. An opponent that tries to increase
+/// their own utility therefore increases the extortioner's utility even more.
+///
+/// The probabilities are computed for the utility values of the
+/// [canonical prisoner's dilemma](Dilemma::prisoners_dilemma), using half of the largest scaling
+/// constant for which all four values are valid probabilities.
+///
+/// # Errors
+///
+/// Logs an error and returns `None` if `factor` is less than 1 or is not finite.
+pub fn zero_determinant_extortion(factor: f64) -> Option<DilemmaPlayer> {
+    if !factor.is_finite() || factor < 1.0 {
+        log::error!(
+            "zero_determinant_extortion: extortion factor must be finite and at least 1, got {}",
+            factor
+        );
+        return None;
+    }
+
+    let [r, s, t, p] = Dilemma::prisoners_dilemma().utils.map(|u| u as f64);
+    let phi = 0.5 / ((p - s) + factor * (t - p)).max((t - p) + factor * (p - s));
+    let cooperate_with = |prob: f64| Distribution::new(vec![(C, prob), (D, 1.0 - prob)]).unwrap();
+    let after_cc = cooperate_with(1.0 - phi * (factor - 1.0) * (r - p));
+    let after_cd = cooperate_with(1.0 - phi * ((p - s) + factor * (t - p)));
+    let after_dc = cooperate_with(phi * ((t - p) + factor * (p - s)));
+
+    Some(Player::new(
+        format!("ZD Extortion (factor {})", factor),
+        move || {
+            let [after_cc, after_cd, after_dc] =
+                [&after_cc, &after_cd, &after_dc].map(|dist| dist.clone());
+            Strategy::new(move |context: &DilemmaContext| {
+                match context.state_view().history().profiles().last() {
+                    None => D,
+                    Some(profile) => {
+                        match (profile[context.my_index()], profile[context.their_index()]) {
+                            (Move::Cooperate, Move::Cooperate) => *after_cc.sample(),
+                            (Move::Cooperate, Move::Defect) => *after_cd.sample(),
+                            (Move::Defect, Move::Cooperate) => *after_dc.sample(),
+                            (Move::Defect, Move::Defect) => D,
+                        }
+                    }
+                }
+            })
+        },
+    ))
+}
+
 /// A player that cooperates unless it was the "sucker" (it cooperated but the opponent defected)
 /// in the previous game.
 ///
@@ -617,24 +659,217 @@ pub fn pavlov() -> DilemmaPlayer {
     })
 }
 
+/// A general [memory-one](https://en.wikipedia.org/wiki/Prisoner%27s_dilemma#Zero-determinant_strategies)
+/// player, which plays the `initial` move first and thereafter cooperates with a probability
+/// determined by the previous round's profile.
+///
+/// The probabilities `p_cc`, `p_cd`, `p_dc`, and `p_dd` are the chances of cooperating after the
+/// previous round's profile was CC, CD, DC, or DD, respectively, from the player's own perspective.
+/// Probabilities outside the range `[0, 1]` are clamped to that range, and NaN is treated as `0`.
+///
+/// Many strategies are special cases of this one. For example, `memory_one(C, 1.0, 0.0, 1.0, 0.0)`
+/// is [Tit-for-Tat](tit_for_tat) and `memory_one(C, 1.0, 0.0, 0.0, 1.0)` is [Pavlov](pavlov).
+pub fn memory_one(initial: Move, p_cc: f64, p_cd: f64, p_dc: f64, p_dd: f64) -> DilemmaPlayer {
+    let cooperate_with = |prob: f64| {
+        let prob = if prob.is_nan() {
+            0.0
+        } else {
+            prob.clamp(0.0, 1.0)
+        };
+        Distribution::new(vec![(C, prob), (D, 1.0 - prob)]).unwrap()
+    };
+    let after = [p_cc, p_cd, p_dc, p_dd].map(cooperate_with);
+
+    Player::new(
+        format!(
+            "Memory-One {} ({}, {}, {}, {})",
+            initial.to_char(),
+            p_cc,
+            p_cd,
+            p_dc,
+            p_dd
+        ),
+        move || {
+            let [after_cc, after_cd, after_dc, after_dd] = after.clone();
+            Strategy::new(move |context: &DilemmaContext| {
+                match (context.my_last_move(), context.their_last_move()) {
+                    (Some(Move::Cooperate), Some(Move::Cooperate)) => *after_cc.sample(),
+                    (Some(Move::Cooperate), Some(Move::Defect)) => *after_cd.sample(),
+                    (Some(Move::Defect), Some(Move::Cooperate)) => *after_dc.sample(),
+                    (Some(Move::Defect), Some(Move::Defect)) => *after_dd.sample(),
+                    _ => initial,
+                }
+            })
+        },
+    )
+}
+
 /// A player that cooperates until the opponent defects once, then defects forever after.
 pub fn grim_trigger() -> DilemmaPlayer {
     Player::new("Grim Trigger".to_string(), || {
         Strategy::trigger(
-            |context: &DilemmaContext| {
-                context
-                    .state_view()
-                    .history()
-                    .moves_for_player(context.their_index())
-                    .last()
-                    .map_or(false, |m| m == D)
-            },
+            |context: &DilemmaContext| context.their_last_move().is_some_and(|m| m == D),
             Strategy::pure(C),
             Strategy::pure(D),
         )
     })
 }
 
+/// A player that cooperates until the opponent defects, then punishes each defection in
+/// proportion to the opponent's total number of defections so far.
+///
+/// After the opponent's `n`th defection, Gradual defects `n` times in a row, then cooperates twice
+/// to offer reconciliation, before returning to cooperation. Defections by the opponent while
+/// Gradual is punishing or reconciling are counted toward `n`, but only a defection in the last
+/// game before Gradual returns to cooperation triggers a new round of punishment.
+///
+/// Like [Grim Trigger](grim_trigger), Gradual escalates against repeated defection, but like
+/// [Tit-for-Tat](tit_for_tat), it forgives an opponent that resumes cooperating.
+pub fn gradual() -> DilemmaPlayer {
+    Player::new("Gradual".to_string(), || {
+        let mut planned: VecDeque<Move> = VecDeque::new();
+        Strategy::new(move |context: &DilemmaContext| {
+            if planned.is_empty() && context.their_last_move() == Some(D) {
+                let defections = context
+                    .state_view()
+                    .history()
+                    .moves_for_player(context.their_index())
+                    .filter(|m| *m == D)
+                    .count();
+                planned.resize(planned.len() + defections, D);
+                planned.extend([C, C]);
+            }
+            planned.pop_front().unwrap_or(C)
+        })
+    })
+}
+
+/// A player that plays [Tit-for-Tat](tit_for_tat) but defects in each of the last `horizon`
+/// rounds of the game.
+///
+/// Defecting in the final round of a finitely repeated prisoner's dilemma cannot be punished, and
+/// reasoning backward from the end of the game suggests defecting earlier still. This player
+/// follows that [backward induction](https://en.wikipedia.org/wiki/Backward_induction) only
+/// `horizon` rounds deep, using the total number of rounds revealed by
+/// [`Context::rounds_remaining`]. If the length of the game is unknown, it plays Tit-for-Tat
+/// throughout.
+pub fn backward_induction_defector(horizon: usize) -> DilemmaPlayer {
+    Player::new(
+        format!("Backward Induction Defector ({})", horizon),
+        move || {
+            Strategy::new(move |context: &DilemmaContext| {
+                if context
+                    .rounds_remaining()
+                    .is_some_and(|remaining| remaining <= horizon)
+                {
+                    D
+                } else {
+                    context.their_last_move().unwrap_or(C)
+                }
+            })
+        },
+    )
+}
+
+/// The moves played by an [Adaptive Pavlov](adaptive_pavlov) player before it begins classifying
+/// its opponent. The defection in the second round probes whether the opponent retaliates.
+const ADAPTIVE_PAVLOV_OPENING: [Move; 4] = [C, D, C, C];
+
+/// The number of most recent games an [Adaptive Pavlov](adaptive_pavlov) player considers when
+/// classifying its opponent.
+const ADAPTIVE_PAVLOV_WINDOW: usize = 10;
+
+/// How an [Adaptive Pavlov](adaptive_pavlov) player has classified its opponent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OpponentType {
+    /// Not enough evidence to classify the opponent.
+    Unknown,
+    /// Cooperates even after being defected against.
+    Exploitable,
+    /// Defects in response to defection, but otherwise cooperates.
+    Retaliatory,
+    /// Defects regardless of how it was treated, e.g. a random or aggressive opponent.
+    Uncooperative,
+}
+
+/// Classify an opponent from a window of recent games, given as pairs of the player's own move
+/// and the opponent's move. Returns `None` if the window contains no evidence of how the opponent
+/// responds to defection or cooperation.
+fn classify_opponent(recent: &[(Move, Move)]) -> Option<OpponentType> {
+    // The opponent's responses to each of our moves, as (defections, total) counts.
+    let mut after_defect = (0, 0);
+    let mut after_cooperate = (0, 0);
+    for pair in recent.windows(2) {
+        let (mine, _) = pair[0];
+        let (_, response) = pair[1];
+        let counts = match mine {
+            Move::Defect => &mut after_defect,
+            Move::Cooperate => &mut after_cooperate,
+        };
+        counts.1 += 1;
+        if response == D {
+            counts.0 += 1;
+        }
+    }
+
+    let rate = |(defections, total): (usize, usize)| defections as f64 / total as f64;
+    if after_cooperate.1 > 0 && rate(after_cooperate) > 0.25 {
+        Some(OpponentType::Uncooperative)
+    } else if after_defect.1 == 0 {
+        None
+    } else if after_defect.0 == 0 {
+        Some(OpponentType::Exploitable)
+    } else if rate(after_defect) >= 0.5 {
+        Some(OpponentType::Retaliatory)
+    } else {
+        Some(OpponentType::Uncooperative)
+    }
+}
+
+/// A player that classifies its opponent from their recent behavior and switches its strategy
+/// accordingly.
+///
+/// The player opens with the moves `C, D, C, C`, probing whether the opponent retaliates against
+/// a defection. Thereafter, it classifies the opponent based on how they responded to its own
+/// moves over the last ten games:
+/// - An opponent that defects after being cooperated with, such as a random or aggressive player,
+///   is met with defection.
+/// - An opponent that never defects after being defected against is exploited by defecting.
+/// - An opponent that retaliates against defection but otherwise cooperates is played with
+///   [Tit-for-Tat](tit_for_tat), sustaining mutual cooperation.
+///
+/// When the recent games contain no evidence of how the opponent responds, for example because
+/// both players have been cooperating, the previous classification is kept. An opponent that has
+/// not yet been classified is played with Tit-for-Tat.
+pub fn adaptive_pavlov() -> DilemmaPlayer {
+    Player::new("Adaptive Pavlov".to_string(), || {
+        let mut opponent_type = OpponentType::Unknown;
+        Strategy::new(move |context: &DilemmaContext| {
+            let history = context.state_view().history();
+            let round = history.num_games();
+            if round < ADAPTIVE_PAVLOV_OPENING.len() {
+                return ADAPTIVE_PAVLOV_OPENING[round];
+            }
+
+            let recent: Vec<(Move, Move)> = history
+                .profiles()
+                .skip(round.saturating_sub(ADAPTIVE_PAVLOV_WINDOW))
+                .map(|profile| (profile[context.my_index()], profile[context.their_index()]))
+                .collect();
+            if let Some(classified) = classify_opponent(&recent) {
+                opponent_type = classified;
+            }
+
+            match opponent_type {
+                OpponentType::Exploitable | OpponentType::Uncooperative => D,
+                OpponentType::Retaliatory | OpponentType::Unknown => {
+                    context.their_last_move().unwrap_or(C)
+                }
+            }
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -656,6 +891,110 @@ mod tests {
         assert_eq!(history.score(), &Payoff::from([102, 99]));
     }
 
+    #[test]
+    fn tit_for_tat_vs_defector_player_record() {
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 5);
+        let matchup = Matchup::from_players([defector(), tit_for_tat()]);
+        let history = g.play(&matchup).unwrap();
+
+        let record = history.for_player(for2::P1);
+        assert_eq!(record.num_games(), 5);
+        assert_eq!(record.moves(), &[C, D, D, D, D]);
+        assert_eq!(record.utilities(), &[0, 1, 1, 1, 1]);
+        assert_eq!(record.ranks(), &[2, 1, 1, 1, 1]);
+        assert_eq!(record.score(), history.score()[for2::P1]);
+    }
+
+    #[test]
+    fn backward_induction_defector_vs_tit_for_tat() {
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 10);
+        let matchup = Matchup::from_players([backward_induction_defector(3), tit_for_tat()]);
+        let history = g.play(&matchup).unwrap();
+
+        let mine: Vec<Move> = history.moves_for_player(for2::P0).collect();
+        let theirs: Vec<Move> = history.moves_for_player(for2::P1).collect();
+        assert_eq!(mine, [C, C, C, C, C, C, C, D, D, D]);
+        assert_eq!(theirs, [C, C, C, C, C, C, C, C, D, D]);
+
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 2);
+        let history = g.play(&matchup).unwrap();
+        assert!(history.moves_for_player(for2::P0).all(|m| m == D));
+    }
+
+    #[test]
+    fn adaptive_pavlov_exploits_cooperator_and_cooperates_with_tit_for_tat() {
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 50);
+
+        let matchup = Matchup::from_players([adaptive_pavlov(), cooperator()]);
+        let history = g.play(&matchup).unwrap();
+        let mine: Vec<Move> = history.moves_for_player(for2::P0).collect();
+        assert_eq!(mine[..4], ADAPTIVE_PAVLOV_OPENING);
+        assert!(mine[4..].iter().all(|m| *m == D));
+
+        let matchup = Matchup::from_players([adaptive_pavlov(), tit_for_tat()]);
+        let history = g.play(&matchup).unwrap();
+        let mine: Vec<Move> = history.moves_for_player(for2::P0).collect();
+        let theirs: Vec<Move> = history.moves_for_player(for2::P1).collect();
+        assert_eq!(theirs[..4], [C, C, D, C]);
+        assert!(mine[4..].iter().all(|m| *m == C));
+        assert!(theirs[4..].iter().all(|m| *m == C));
+
+        let matchup = Matchup::from_players([adaptive_pavlov(), defector()]);
+        let history = g.play(&matchup).unwrap();
+        assert!(history.moves_for_player(for2::P0).skip(4).all(|m| m == D));
+    }
+
+    #[test]
+    fn gradual_escalates_punishment_then_reconciles() {
+        let occasional = Player::new("Occasional Defector".to_string(), || {
+            Strategy::new(|context: &DilemmaContext| {
+                match context.state_view().history().num_games() {
+                    2 | 12 | 14 | 16 => D,
+                    _ => C,
+                }
+            })
+        });
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 25);
+        let history = g
+            .play(&Matchup::from_players([gradual(), occasional]))
+            .unwrap();
+        let mine: Vec<Move> = history.moves_for_player(for2::P0).collect();
+
+        // The first defection is punished once and the second twice. The third defection occurs
+        // during that punishment, so it only counts toward the fourth defection, which occurs
+        // while reconciling and is punished four times.
+        let mut expected = vec![C; 25];
+        expected[3] = D;
+        expected[13..15].fill(D);
+        expected[17..21].fill(D);
+        assert_eq!(mine, expected);
+    }
+
+    #[test]
+    fn gradual_sustains_cooperation_with_tit_for_tat() {
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 100);
+        let matchup = Matchup::from_players([gradual(), tit_for_tat()]);
+        let history = g.play(&matchup).unwrap();
+        assert_eq!(history.score(), &Payoff::from([200, 200]));
+    }
+
+    #[test]
+    fn classify_opponent_from_recent_games() {
+        assert_eq!(classify_opponent(&[(C, C), (C, C), (C, C)]), None);
+        assert_eq!(
+            classify_opponent(&[(C, C), (D, C), (D, C), (C, C)]),
+            Some(OpponentType::Exploitable)
+        );
+        assert_eq!(
+            classify_opponent(&[(C, C), (D, C), (C, D), (C, C)]),
+            Some(OpponentType::Retaliatory)
+        );
+        assert_eq!(
+            classify_opponent(&[(C, D), (C, D), (D, C), (C, D)]),
+            Some(OpponentType::Uncooperative)
+        );
+    }
+
     #[test]
     fn tit_for_tat_vs_tit_for_tat() {
         let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 100);
@@ -664,6 +1003,37 @@ mod tests {
         assert_eq!(history.score(), &Payoff::from([200, 200]));
     }
 
+    #[test]
+    fn generous_tit_for_tat_outperforms_tit_for_tat_under_noise() {
+        let g =
+            Repeated::with_observation_noise(Arc::new(Dilemma::prisoners_dilemma()), 2000, 0.05)
+                .unwrap();
+        let self_play = |player: fn() -> DilemmaPlayer| {
+            let matchup = Matchup::from_players([player(), player()]);
+            let history = with_seed(3, || g.play(&matchup)).unwrap();
+            history.score()[for2::P0] + history.score()[for2::P1]
+        };
+
+        // Without noise, both cooperate throughout.
+        let noiseless = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 2000);
+        let matchup = Matchup::from_players([tit_for_tat(), tit_for_tat()]);
+        assert_eq!(
+            noiseless.play(&matchup).unwrap().score(),
+            &Payoff::from([4000, 4000])
+        );
+
+        // With noise, misperceived defections set off cycles of retaliation that generosity breaks.
+        let tit_for_tat_score = self_play(tit_for_tat);
+        let generous_score = self_play(generous_tit_for_tat);
+        assert!(
+            generous_score > tit_for_tat_score,
+            "generous: {}, tit-for-tat: {}",
+            generous_score,
+            tit_for_tat_score
+        );
+        assert!(tit_for_tat_score < 8000);
+    }
+
     #[test]
     fn tit_for_tat_vs_suspicious_tit_for_tat() {
         let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 100);
@@ -672,6 +1042,92 @@ mod tests {
         assert_eq!(history.score(), &Payoff::from([150, 150]));
     }
 
+    #[test]
+    fn zero_determinant_extortion_outscores_opponents() {
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 1000);
+        for factor in [1.0, 2.0, 3.0, 5.0] {
+            for opponent in [cooperator(), defector(), tit_for_tat(), tit_for_n_tats(2)] {
+                let extortioner = zero_determinant_extortion(factor).unwrap();
+                let name = opponent.name().to_string();
+                let matchup = Matchup::from_players([extortioner, opponent]);
+                let score = *g.play(&matchup).unwrap().score();
+                assert!(
+                    score[for2::P0] >= score[for2::P1],
+                    "factor {} vs {}: {:?}",
+                    factor,
+                    name,
+                    score,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_determinant_extortion_rejects_small_factor() {
+        assert!(zero_determinant_extortion(0.5).is_none());
+        assert!(zero_determinant_extortion(f64::NAN).is_none());
+        assert!(zero_determinant_extortion(f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn pavlov_with_last_move_helpers_matches_pavlov() {
+        let pavlov_with_helpers = Player::new("Pavlov".to_string(), || {
+            Strategy::new(|context: &DilemmaContext| {
+                match (context.my_last_move(), context.their_last_move()) {
+                    (Some(my_move), Some(Move::Cooperate)) => my_move,
+                    (Some(my_move), Some(Move::Defect)) => my_move.opposite(),
+                    _ => C,
+                }
+            })
+        });
+
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 20);
+        let opponents = [
+            cooperator(),
+            defector(),
+            tit_for_tat(),
+            suspicious_tit_for_tat(),
+            periodic(vec![C, D, D]),
+        ];
+        for opponent in opponents {
+            let expected = g
+                .play(&Matchup::from_players([pavlov(), opponent.clone()]))
+                .unwrap();
+            let actual = g
+                .play(&Matchup::from_players([
+                    pavlov_with_helpers.clone(),
+                    opponent,
+                ]))
+                .unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn memory_one_reproduces_tit_for_tat() {
+        let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 30);
+        let opponents = [
+            cooperator(),
+            defector(),
+            tit_for_tat(),
+            suspicious_tit_for_tat(),
+            pavlov(),
+            periodic(vec![C, D, D, C]),
+        ];
+        for opponent in opponents {
+            let expected = g
+                .play(&Matchup::from_players([tit_for_tat(), opponent.clone()]))
+                .unwrap();
+            let actual = g
+                .play(&Matchup::from_players([
+                    memory_one(C, 1.0, 0.0, 1.0, 0.0),
+                    opponent,
+                ]))
+                .unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+
     #[test]
     fn tit_for_two_tats_vs_suspicious_tit_for_tat() {
         let g = Repeated::new(Arc::new(Dilemma::prisoners_dilemma()), 100);
@@ -6,6 +6,11 @@
 //! The games are organized into modules, which define several related games and strategies for
 //! playing them. The top-level documentation for each module provides a more detailed overview.
 //!
+//! # Connect Four ([connect_four])
+//!
+//! This module defines the sequential [Connect Four][connect-four] game, including its board
+//! representation and win detection.
+//!
 //! # Dilemma games ([dilemma])
 //!
 //! This module includes a collection of 2x2 symmetric normal-form games, where each player may
@@ -25,6 +30,7 @@
 //! involving either more moves or more players.
 //!
 //! [t4t]: https://crates.io/crates/t4t
+//! [connect-four]: https://en.wikipedia.org/wiki/Connect_Four
 //! [prisoner]: https://en.wikipedia.org/wiki/Prisoner%27s_dilemma
 //! [stag-hunt]: https://en.wikipedia.org/wiki/Stag_hunt
 //! [chicken]: https://en.wikipedia.org/wiki/Chicken_(game)
@@ -33,5 +39,6 @@
 //! [tft-strategy]: https://en.wikipedia.org/wiki/Tit_for_tat
 //! [rps-game]: https://en.wikipedia.org/wiki/Rock_paper_scissors
 
+pub mod connect_four;
 pub mod dilemma;
 pub mod rps;
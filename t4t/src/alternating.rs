@@ -0,0 +1,405 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::repeated::{
+    lift_node, lift_simultaneous_turns, LiftedTree, RepeatingState, StageNext, StageTree,
+};
+use crate::{
+    ErrorKind, FiniteGame, Game, GameTree, History, PlayerIndex, PossibleMoves, Transcript,
+};
+
+/// A finitely repeated version of game `G`, where the players move in turn rather than
+/// simultaneously within each repetition.
+///
+/// Whenever several players of the stage game would move simultaneously, each player instead
+/// moves one at a time, in the order the stage game lists them (typically in order of player
+/// index). Each player sees the moves already played by earlier players in the same repetition via
+/// [`AlternatingState::current_round`]. This changes the strategic structure of the game, since
+/// later movers can condition on the current moves of earlier movers.
+///
+/// Since each move is played before the stage game sees the other moves of its turn, the stage
+/// game must be [finite](FiniteGame) so that each move can be checked against the stage game's
+/// possible moves as soon as it is played.
+///
+/// Like [`Repeated`](crate::Repeated), the payoffs of each repetition are accumulated and the
+/// outcome of the game is the [history](History) of completed repetitions.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use t4t::*;
+///
+/// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+/// let game = AlternatingRepeated::new(Arc::new(pd), 4);
+///
+/// // The first mover alternates between cooperating and defecting, while the second mover
+/// // copies the first mover's move in the current round.
+/// let alternator = Player::new("Alternator".to_string(), || Strategy::periodic_pure(vec!['C', 'D']));
+/// let copycat = Player::new("Copycat".to_string(), || {
+///     Strategy::new(|context: &Context<AlternatingState<Normal<char, i32, 2>, 2>, 2>| {
+///         context
+///             .state_view()
+///             .current_round()
+///             .last_move_by_player(for2::P0)
+///             .unwrap_or('C')
+///     })
+/// });
+///
+/// let history = game.play(&Matchup::from_players([alternator, copycat])).unwrap();
+/// assert_eq!(
+///     history.moves_for_player(for2::P1).collect::<Vec<_>>(),
+///     vec!['C', 'D', 'C', 'D'],
+/// );
+/// assert_eq!(*history.score(), Payoff::from([6, 6]));
+/// ```
+#[derive(Clone)]
+pub struct AlternatingRepeated<G: Game<P>, const P: usize> {
+    stage_game: Arc<G>,
+    repetitions: usize,
+}
+
+/// The intermediate state of an alternating repeated game.
+#[derive(Clone)]
+pub struct AlternatingState<G: Game<P>, const P: usize> {
+    stage_game: Arc<G>,
+    stage_state: Arc<G::State>,
+    current_round: Transcript<G::Move, P>,
+    completed: History<G, P>,
+    remaining: usize,
+}
+
+impl<G: Game<P> + 'static, const P: usize> AlternatingRepeated<G, P> {
+    /// Construct an alternating repeated game that plays the stage game the given number of
+    /// repetitions.
+    pub fn new(stage_game: Arc<G>, repetitions: usize) -> Self {
+        AlternatingRepeated {
+            stage_game,
+            repetitions,
+        }
+    }
+
+    /// Get the stage game for this repeated game.
+    pub fn stage_game(&self) -> &Arc<G> {
+        &self.stage_game
+    }
+
+    /// Get the number of repetitions the stage game will be played.
+    pub fn repetitions(&self) -> usize {
+        self.repetitions
+    }
+}
+
+impl<G: Game<P>, const P: usize> AlternatingState<G, P> {
+    /// Construct a new alternating repeated game state.
+    pub fn new(stage_game: Arc<G>, remaining: usize) -> Self {
+        let stage_state = stage_game.game_tree().state.clone();
+        AlternatingState::with_stage_state(stage_game, stage_state, remaining)
+    }
+
+    /// Construct a new alternating repeated game state whose stage game begins in the given state.
+    fn with_stage_state(stage_game: Arc<G>, stage_state: Arc<G::State>, remaining: usize) -> Self {
+        AlternatingState {
+            stage_game,
+            stage_state,
+            current_round: Transcript::new(),
+            completed: History::empty(),
+            remaining,
+        }
+    }
+
+    /// Get the stage game being repeated.
+    pub fn stage_game(&self) -> &Arc<G> {
+        &self.stage_game
+    }
+
+    /// Get the view of the stage game's current intermediate state for the given player.
+    pub fn state_view(&self, player: PlayerIndex<P>) -> G::View {
+        self.stage_game
+            .state_view(self.stage_state.as_ref(), player)
+    }
+
+    /// A transcript of the moves played so far in the current repetition of the stage game, in the
+    /// order they were played.
+    pub fn current_round(&self) -> &Transcript<G::Move, P> {
+        &self.current_round
+    }
+
+    /// The current history of all completed repetitions of the stage game so far.
+    pub fn history(&self) -> &History<G, P> {
+        &self.completed
+    }
+
+    /// The number of remaining repetitions of the stage game to play, not including the current
+    /// one.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+/// Ask the player at index `moves.len()` of `to_move` for a move, then continue with the next
+/// player in turn, or with the stage game's next node once all players have moved.
+///
+/// Each move is checked against the stage game's possible moves as soon as it is played, so that
+/// an invalid move is attributed to the player who played it.
+fn alternate_turns<G: FiniteGame<P> + 'static, const P: usize>(
+    stage_root: StageTree<G, P>,
+    state: Arc<AlternatingState<G, P>>,
+    to_move: Arc<Vec<PlayerIndex<P>>>,
+    moves: Vec<G::Move>,
+    next: StageNext<G, P>,
+) -> LiftedTree<AlternatingState<G, P>, G, P> {
+    let player = to_move[moves.len()];
+    GameTree::player(
+        state,
+        player,
+        move |alternating_state: Arc<AlternatingState<G, P>>, the_move: G::Move| {
+            if !alternating_state
+                .stage_game
+                .possible_moves(player, &alternating_state.stage_state)
+                .any(|possible| possible == the_move)
+            {
+                return Err(ErrorKind::InvalidMove(player, the_move));
+            }
+
+            let mut next_state = (*alternating_state).clone();
+            next_state.current_round.add_player_move(player, the_move);
+
+            let mut moves = moves.clone();
+            moves.push(the_move);
+
+            if moves.len() < to_move.len() {
+                Ok(alternate_turns(
+                    stage_root.clone(),
+                    Arc::new(next_state),
+                    to_move.clone(),
+                    moves,
+                    next.clone(),
+                ))
+            } else {
+                let stage_node = next(next_state.stage_state.clone(), moves)?;
+                next_state.stage_state = stage_node.state.clone();
+                Ok(lift_node(
+                    stage_root.clone(),
+                    Arc::new(next_state),
+                    stage_node,
+                ))
+            }
+        },
+    )
+}
+
+impl<G: FiniteGame<P> + 'static, const P: usize> RepeatingState<G, P> for AlternatingState<G, P> {
+    fn stage_state(&self) -> &Arc<G::State> {
+        &self.stage_state
+    }
+
+    fn set_stage_state(&mut self, stage_state: Arc<G::State>) {
+        self.stage_state = stage_state;
+    }
+
+    fn add_chance_move(&mut self, the_move: G::Move) {
+        self.current_round.add_chance_move(the_move);
+    }
+
+    fn history(&self) -> &History<G, P> {
+        &self.completed
+    }
+
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn begin_next_repetition(&mut self, outcome: G::Outcome) {
+        self.current_round = Transcript::new();
+        self.completed.add(outcome);
+        self.remaining -= 1;
+    }
+
+    fn lift_turns(
+        stage_root: StageTree<G, P>,
+        state: Arc<Self>,
+        to_move: Vec<PlayerIndex<P>>,
+        next: StageNext<G, P>,
+    ) -> LiftedTree<Self, G, P> {
+        if to_move.is_empty() {
+            lift_simultaneous_turns(stage_root, state, to_move, next)
+        } else {
+            alternate_turns(stage_root, state, Arc::new(to_move), Vec::new(), next)
+        }
+    }
+}
+
+impl<G: FiniteGame<P> + 'static, const P: usize> Game<P> for AlternatingRepeated<G, P> {
+    type Move = G::Move;
+    type Utility = G::Utility;
+    type Outcome = History<G, P>;
+    type State = AlternatingState<G, P>;
+    type View = AlternatingState<G, P>;
+
+    fn into_game_tree(
+        self,
+    ) -> GameTree<AlternatingState<G, P>, G::Move, G::Utility, History<G, P>, P> {
+        let stage_root = self.stage_game.game_tree();
+        let init_state = Arc::new(AlternatingState::with_stage_state(
+            self.stage_game.clone(),
+            stage_root.state.clone(),
+            self.repetitions - 1,
+        ));
+
+        lift_node(stage_root.clone(), init_state, stage_root)
+    }
+
+    fn state_view(
+        &self,
+        state: &AlternatingState<G, P>,
+        _player: PlayerIndex<P>,
+    ) -> AlternatingState<G, P> {
+        state.clone()
+    }
+}
+
+impl<G: FiniteGame<P> + 'static, const P: usize> FiniteGame<P> for AlternatingRepeated<G, P> {
+    fn possible_moves(
+        &self,
+        player: PlayerIndex<P>,
+        state: &Self::State,
+    ) -> PossibleMoves<'_, Self::Move> {
+        self.stage_game
+            .possible_moves(player, state.stage_state.as_ref())
+    }
+}
+
+impl<G: Game<P>, const P: usize> fmt::Debug for AlternatingState<G, P> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("AlternatingState")
+            .field("stage_state", &self.stage_state)
+            .field("current_round", &self.current_round)
+            .field("completed", &self.completed)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<G: Game<P>, const P: usize> PartialEq for AlternatingState<G, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.stage_state == other.stage_state
+            && self.current_round == other.current_round
+            && self.completed == other.completed
+            && self.remaining == other.remaining
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{for2, Context, Matchup, Normal, Payoff, Player, Ply, Record, Strategy};
+    use impls::impls;
+    use test_log::test;
+
+    type Stage = Normal<char, i32, 2>;
+
+    #[test]
+    fn alternating_repeated_is_send_sync() {
+        assert!(impls!(AlternatingRepeated<Normal<(), u8, 2>, 2>: Send & Sync));
+    }
+
+    #[test]
+    fn second_mover_conditions_on_first_move() {
+        // Matching pennies: P0 wants to match, P1 wants to mismatch. Moving second, P1 always
+        // mismatches and wins every round.
+        let pennies: Stage = Normal::bimatrix(
+            ['H', 'T'],
+            ['H', 'T'],
+            [[1, -1], [-1, 1]],
+            [[-1, 1], [1, -1]],
+        );
+        let game = AlternatingRepeated::new(Arc::new(pennies), 5);
+
+        let first = Player::new("First".to_string(), || {
+            Strategy::periodic_pure(vec!['H', 'H', 'T'])
+        });
+        let second = Player::new("Second".to_string(), || {
+            Strategy::new(|context: &Context<AlternatingState<Stage, 2>, 2>| {
+                let round = context.state_view().current_round();
+                assert_eq!(round.plies().count(), 1);
+                match round.last_move_by_player(for2::P0) {
+                    Some('H') => 'T',
+                    _ => 'H',
+                }
+            })
+        });
+
+        let history = game.play(&Matchup::from_players([first, second])).unwrap();
+        assert_eq!(*history.score(), Payoff::from([-5, 5]));
+        assert_eq!(
+            history
+                .to_transcript()
+                .iter()
+                .take(4)
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![
+                Ply::player(for2::P0, 'H'),
+                Ply::player(for2::P1, 'T'),
+                Ply::player(for2::P0, 'H'),
+                Ply::player(for2::P1, 'T'),
+            ],
+        );
+    }
+
+    #[test]
+    fn first_mover_sees_empty_current_round() {
+        let pd: Stage = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let game = AlternatingRepeated::new(Arc::new(pd), 3);
+
+        let first = Player::new("First".to_string(), || {
+            Strategy::new(|context: &Context<AlternatingState<Stage, 2>, 2>| {
+                let state = context.state_view();
+                assert_eq!(state.current_round(), &Transcript::new());
+                assert_eq!(state.history().num_games() + state.remaining(), 2);
+                'C'
+            })
+        });
+        let second = Player::new("Second".to_string(), || Strategy::pure('D'));
+
+        let history = game.play(&Matchup::from_players([first, second])).unwrap();
+        assert_eq!(history.num_games(), 3);
+        assert_eq!(*history.score(), Payoff::from([0, 9]));
+    }
+
+    #[test]
+    fn invalid_move_is_rejected_on_its_own_turn() {
+        let pd: Stage = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let game = AlternatingRepeated::new(Arc::new(pd), 2);
+
+        // The second mover must never see the first mover's invalid move.
+        let second = || {
+            Player::new("Second".to_string(), || {
+                Strategy::new(|context: &Context<AlternatingState<Stage, 2>, 2>| {
+                    let round = context.state_view().current_round();
+                    assert_eq!(round.last_move_by_player(for2::P0), Some('C'));
+                    'D'
+                })
+            })
+        };
+
+        let broken = Player::new("Broken".to_string(), || Strategy::pure('X'));
+        let err = game
+            .play(&Matchup::from_players([broken, second()]))
+            .unwrap_err();
+        assert_eq!(
+            err.kind,
+            ErrorKind::StrategyFailedToProduceValidMove(for2::P0, 'X')
+        );
+        assert_eq!(err.state.current_round(), &Transcript::new());
+
+        // The first mover's invalid move is retried before the second mover moves.
+        let confused = Player::new("Confused".to_string(), || {
+            Strategy::periodic_pure(vec!['X', 'C'])
+        });
+        let history = game
+            .play_with_retries(&Matchup::from_players([confused, second()]), 1)
+            .unwrap();
+        assert_eq!(*history.score(), Payoff::from([0, 6]));
+    }
+}
@@ -0,0 +1,113 @@
+use itertools::Itertools;
+use std::fmt::Write;
+
+use crate::tree::escape_dot;
+use crate::{Move, PlayerIndex, Profile};
+
+/// An edge in a [best-response graph](BestResponseGraph), representing a single player deviating
+/// from one profile to another by switching to a best response.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BestResponseEdge<M, const P: usize> {
+    /// The profile before the deviation.
+    pub from: Profile<M, P>,
+    /// The profile after the deviation.
+    pub to: Profile<M, P>,
+    /// The player who deviates.
+    pub player: PlayerIndex<P>,
+}
+
+/// The best-response graph of a finite normal-form game, produced by
+/// [`Normal::best_response_graph`](crate::Normal::best_response_graph).
+///
+/// The nodes of the graph are the game's profiles. There is an edge from one profile to another if
+/// a single player can strictly improve their utility by switching to a best response to the other
+/// players' moves, yielding the other profile. The sinks of the graph, profiles with no outgoing
+/// edges, are exactly the pure Nash equilibria of the game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestResponseGraph<M, const P: usize> {
+    nodes: Vec<Profile<M, P>>,
+    edges: Vec<BestResponseEdge<M, P>>,
+}
+
+impl<M: Move, const P: usize> BestResponseGraph<M, P> {
+    /// Construct a new best-response graph from its nodes and edges.
+    pub fn new(nodes: Vec<Profile<M, P>>, edges: Vec<BestResponseEdge<M, P>>) -> Self {
+        BestResponseGraph { nodes, edges }
+    }
+
+    /// The nodes of the graph, that is, every profile of the game.
+    pub fn nodes(&self) -> &[Profile<M, P>] {
+        &self.nodes
+    }
+
+    /// The edges of the graph.
+    pub fn edges(&self) -> &[BestResponseEdge<M, P>] {
+        &self.edges
+    }
+
+    /// Get the edges leaving the given profile.
+    pub fn edges_from(
+        &self,
+        profile: Profile<M, P>,
+    ) -> impl Iterator<Item = &BestResponseEdge<M, P>> {
+        self.edges.iter().filter(move |edge| edge.from == profile)
+    }
+
+    /// Get the sinks of the graph, that is, the profiles with no outgoing edges. These are the
+    /// pure Nash equilibria of the game.
+    pub fn sinks(&self) -> Vec<Profile<M, P>> {
+        self.nodes
+            .iter()
+            .copied()
+            .filter(|profile| self.edges_from(*profile).next().is_none())
+            .collect()
+    }
+
+    /// Render the graph in the [DOT](https://graphviz.org/doc/info/lang.html) language for
+    /// visualization with Graphviz.
+    ///
+    /// Each node is labeled by its profile, and sinks are drawn with a double border. Each edge is
+    /// labeled by the player who deviates.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let dot = pd.best_response_graph().to_dot();
+    ///
+    /// assert!(dot.starts_with("digraph {"));
+    /// assert!(dot.contains("n3 [label=\"'D', 'D'\", shape=doublecircle];"));
+    /// assert!(dot.contains("n0 -> n2 [label=\"P0\"];"));
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for (id, profile) in self.nodes.iter().enumerate() {
+            let label = PlayerIndex::all()
+                .map(|player| format!("{:?}", profile[player]))
+                .join(", ");
+            let shape = if self.edges_from(*profile).next().is_none() {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            writeln!(
+                dot,
+                "  n{} [label=\"{}\", shape={}];",
+                id,
+                escape_dot(&label),
+                shape
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let from = self.nodes.iter().position(|p| *p == edge.from);
+            let to = self.nodes.iter().position(|p| *p == edge.to);
+            if let (Some(from), Some(to)) = (from, to) {
+                writeln!(dot, "  n{} -> n{} [label=\"{}\"];", from, to, edge.player).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
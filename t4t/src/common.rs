@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use crate::{FiniteGame, Game, GameTree, PlayerIndex, PossibleMoves, State};
+
+/// A version of game `G` in which every player can observe a shared, immutable payload of type
+/// `C`, such as a public signal or an announced rule.
+///
+/// The payload is [common knowledge](https://en.wikipedia.org/wiki/Common_knowledge_(logic)):
+/// every player sees the same value, and strategies can access it from their context via
+/// [`Context::common`](crate::Context::common). Otherwise, the game is played exactly as `G`.
+///
+/// # Examples
+/// ```
+/// use t4t::*;
+///
+/// let coordination: Normal<char, i32, 2> =
+///     Normal::symmetric(vec!['A', 'B'], vec![1, 0, 0, 1]).unwrap();
+/// let signaled = CommonKnowledge::new(coordination, 'B');
+///
+/// let follower = || {
+///     Player::new("Follower".to_string(), || {
+///         Strategy::new(|context: &Context<CommonView<(), char>, 2>| *context.common())
+///     })
+/// };
+///
+/// let outcome = signaled.play(&Matchup::from_players([follower(), follower()])).unwrap();
+/// assert_eq!(*outcome.profile(), Profile::new(['B', 'B']));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommonKnowledge<G, C> {
+    game: G,
+    common: Arc<C>,
+}
+
+/// A player's view of the state of a [`CommonKnowledge`] game, which combines the player's view
+/// of the underlying game's state with the payload shared by all players.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommonView<V, C> {
+    view: V,
+    common: Arc<C>,
+}
+
+impl<G, C> CommonKnowledge<G, C> {
+    /// Construct a version of the given game in which the given payload is common knowledge.
+    pub fn new(game: G, common: C) -> Self {
+        CommonKnowledge {
+            game,
+            common: Arc::new(common),
+        }
+    }
+
+    /// Get the underlying game.
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    /// Get the payload shared by all players.
+    pub fn common(&self) -> &C {
+        &self.common
+    }
+}
+
+impl<V, C> CommonView<V, C> {
+    /// Get the player's view of the underlying game's state.
+    pub fn view(&self) -> &V {
+        &self.view
+    }
+
+    /// Get the payload shared by all players.
+    pub fn common(&self) -> &C {
+        &self.common
+    }
+}
+
+impl<G: Game<P>, C: State, const P: usize> Game<P> for CommonKnowledge<G, C> {
+    type Move = G::Move;
+    type Utility = G::Utility;
+    type Outcome = G::Outcome;
+    type State = G::State;
+    type View = CommonView<G::View, C>;
+
+    fn into_game_tree(self) -> GameTree<G::State, G::Move, G::Utility, G::Outcome, P> {
+        self.game.into_game_tree()
+    }
+
+    fn state_view(&self, state: &G::State, player: PlayerIndex<P>) -> CommonView<G::View, C> {
+        CommonView {
+            view: self.game.state_view(state, player),
+            common: self.common.clone(),
+        }
+    }
+
+    fn num_players(&self) -> usize {
+        self.game.num_players()
+    }
+}
+
+impl<G: FiniteGame<P>, C: State, const P: usize> FiniteGame<P> for CommonKnowledge<G, C> {
+    fn possible_moves(
+        &self,
+        player: PlayerIndex<P>,
+        state: &Self::State,
+    ) -> PossibleMoves<'_, Self::Move> {
+        self.game.possible_moves(player, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{for2, Context, Matchup, Normal, Outcome, Payoff, Player, Profile, Strategy};
+    use test_log::test;
+
+    type Signaled = CommonKnowledge<Normal<char, i32, 2>, char>;
+
+    fn follower() -> Player<Signaled, 2> {
+        Player::new("Follower".to_string(), || {
+            Strategy::new(|context: &Context<CommonView<(), char>, 2>| *context.common())
+        })
+    }
+
+    #[test]
+    fn public_signal_coordinates_players() {
+        // A coordination game with two pure equilibria, where coordinating on 'A' is better.
+        let coordination: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['A', 'B'], vec![2, 0, 0, 1]).unwrap();
+        let matchup = Matchup::from_players([follower(), follower()]);
+
+        for signal in ['A', 'B'] {
+            let game = CommonKnowledge::new(coordination.clone(), signal);
+            let outcome = game.play(&matchup).unwrap();
+            assert_eq!(*outcome.profile(), Profile::new([signal, signal]));
+            assert!(coordination
+                .pure_nash_equilibria()
+                .contains(outcome.profile()));
+        }
+
+        let game = CommonKnowledge::new(coordination, 'A');
+        assert_eq!(*game.play(&matchup).unwrap().payoff(), Payoff::from([2, 2]));
+        assert_eq!(
+            game.possible_moves(for2::P1, &()).collect::<Vec<_>>(),
+            vec!['A', 'B']
+        );
+    }
+}
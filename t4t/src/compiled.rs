@@ -0,0 +1,72 @@
+use crate::game::{play_game_tree, sample_chance};
+use crate::{Game, GameTree, Matchup, PlayResult};
+
+/// A game whose [game tree](GameTree) has been built once, so that it can be played many times
+/// without translating the game into a game tree again. Produced by [`Game::compile`].
+///
+/// Only the root of the game tree is built up front. The rest of the tree is produced by the
+/// root's `next` functions while the game is played, as usual.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use t4t::*;
+///
+/// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+/// let rpd = Repeated::new(Arc::new(pd), 10);
+/// let compiled = rpd.compile();
+///
+/// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+/// let mean = Player::new("Mean".to_string(), || Strategy::pure('D'));
+/// let matchup = Matchup::from_players([nice, mean]);
+///
+/// assert_eq!(
+///     compiled.play(&matchup).unwrap().score(),
+///     rpd.play(&matchup).unwrap().score(),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct CompiledGame<G: Game<P>, const P: usize> {
+    game: G,
+    tree: GameTree<G::State, G::Move, G::Utility, G::Outcome, P>,
+}
+
+impl<G: Game<P>, const P: usize> CompiledGame<G, P> {
+    /// Compile the given game by building its game tree.
+    pub fn new(game: G) -> Self {
+        let tree = game.game_tree();
+        CompiledGame { game, tree }
+    }
+
+    /// Get the underlying game.
+    pub fn game(&self) -> &G {
+        &self.game
+    }
+
+    /// Get the game tree built for this game.
+    pub fn game_tree(&self) -> &GameTree<G::State, G::Move, G::Utility, G::Outcome, P> {
+        &self.tree
+    }
+
+    /// Play the game with the given players by executing the prebuilt game tree.
+    ///
+    /// Equivalent to [`Game::play`] on the underlying game.
+    pub fn play(&self, matchup: &Matchup<G, P>) -> PlayResult<G, P> {
+        self.play_with_retries(matchup, 0)
+    }
+
+    /// Play the game with the given players by executing the prebuilt game tree, requesting a new
+    /// move up to `retries` times from a strategy that plays an invalid move.
+    ///
+    /// Equivalent to [`Game::play_with_retries`] on the underlying game.
+    pub fn play_with_retries(&self, matchup: &Matchup<G, P>, retries: usize) -> PlayResult<G, P> {
+        play_game_tree(
+            &self.game,
+            self.tree.clone(),
+            matchup,
+            retries,
+            &mut |_| {},
+            &mut sample_chance,
+        )
+    }
+}
@@ -1,4 +1,10 @@
+use itertools::Itertools;
 use rand_distr::WeightedAliasIndex;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::random::with_rng;
+use crate::{Move, PerPlayer, PlayerIndex, Profile};
 
 /// A weighted probability distribution over a set of discrete elements, such as moves.
 ///
@@ -31,6 +37,7 @@ use rand_distr::WeightedAliasIndex;
 #[derive(Clone, Debug)]
 pub struct Distribution<T> {
     elements: Vec<T>,
+    probabilities: Vec<f64>,
     dist: WeightedAliasIndex<f64>,
 }
 
@@ -46,9 +53,17 @@ impl<T> Distribution<T> {
     ///   where `max = f64::MAX / weighted_elements.len()`.
     /// - The sum of the weights is zero.
     pub fn new(weighted_elements: Vec<(T, f64)>) -> Option<Self> {
-        let (elements, weights) = weighted_elements.into_iter().unzip();
-        match WeightedAliasIndex::new(weights) {
-            Ok(dist) => Some(Distribution { elements, dist }),
+        let (elements, weights): (Vec<T>, Vec<f64>) = weighted_elements.into_iter().unzip();
+        match WeightedAliasIndex::new(weights.clone()) {
+            Ok(dist) => {
+                let total: f64 = weights.iter().sum();
+                let probabilities = weights.into_iter().map(|w| w / total).collect();
+                Some(Distribution {
+                    elements,
+                    probabilities,
+                    dist,
+                })
+            }
             Err(err) => {
                 log::error!(
                     "Distribution::new: Error creating weighted probability distribution: {:?}",
@@ -75,6 +90,64 @@ impl<T> Distribution<T> {
         Distribution::new(vec![(element, 1.0)]).unwrap()
     }
 
+    /// Get an iterator over the elements of the distribution paired with their probabilities.
+    ///
+    /// The probabilities are the weights provided on construction, normalized to sum to one.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::Distribution;
+    ///
+    /// let dist = Distribution::new(vec![('A', 2.5), ('B', 1.0), ('C', 1.5)]).unwrap();
+    /// assert_eq!(
+    ///     dist.probabilities().collect::<Vec<_>>(),
+    ///     vec![(&'A', 0.5), (&'B', 0.2), (&'C', 0.3)],
+    /// );
+    /// ```
+    pub fn probabilities(&self) -> impl Iterator<Item = (&T, f64)> {
+        self.elements.iter().zip(self.probabilities.iter().copied())
+    }
+
+    /// Get the element with the highest probability in the distribution.
+    ///
+    /// If several elements share the highest probability, the one provided first on construction
+    /// is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::Distribution;
+    ///
+    /// let dist = Distribution::new(vec![('A', 1.0), ('B', 3.0), ('C', 3.0)]).unwrap();
+    /// assert_eq!(dist.most_likely(), &'B');
+    ///
+    /// let coin = Distribution::flat(vec!["heads", "tails"]).unwrap();
+    /// assert_eq!(coin.most_likely(), &"heads");
+    /// ```
+    pub fn most_likely(&self) -> &T {
+        let mut best = 0;
+        for (index, p) in self.probabilities.iter().enumerate() {
+            if *p > self.probabilities[best] {
+                best = index;
+            }
+        }
+        &self.elements[best]
+    }
+
+    /// Get an iterator over the elements of the distribution that have a nonzero probability.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::Distribution;
+    ///
+    /// let dist = Distribution::new(vec![('A', 2.0), ('B', 0.0), ('C', 1.0)]).unwrap();
+    /// assert_eq!(dist.support().collect::<Vec<_>>(), vec![&'A', &'C']);
+    /// ```
+    pub fn support(&self) -> impl Iterator<Item = &T> {
+        self.probabilities()
+            .filter(|(_, p)| *p > 0.0)
+            .map(|(element, _)| element)
+    }
+
     /// Sample a random value from the distribution using `rng` as the source of randomness.
     pub fn sample_using<R: rand::Rng>(&self, rng: &mut R) -> &T {
         let index = self.weighted_index(rng);
@@ -88,20 +161,160 @@ impl<T> Distribution<T> {
         &mut self.elements[index]
     }
 
-    /// Sample a random value from the distribution using `rand::thread_rng()` as the source of
-    /// randomness.
+    /// Sample a random value from the distribution using the library's source of randomness,
+    /// which is `rand::thread_rng()` unless [seeded](crate::with_seed).
     pub fn sample(&self) -> &T {
-        self.sample_using(&mut rand::thread_rng())
+        let index = with_rng(|rng| self.weighted_index(rng));
+        &self.elements[index]
     }
 
-    /// Sample a random value from the distribution using `rand::thread_rng()` as the source of
-    /// randomness, returning a mutable reference to the sampled element.
+    /// Sample a random value from the distribution using the library's source of randomness,
+    /// which is `rand::thread_rng()` unless [seeded](crate::with_seed), returning a mutable
+    /// reference to the sampled element.
     pub fn sample_mut(&mut self) -> &mut T {
-        self.sample_using_mut(&mut rand::thread_rng())
+        let index = with_rng(|rng| self.weighted_index(rng));
+        &mut self.elements[index]
+    }
+
+    /// Get a mutable reference to the element at the given index in the element list.
+    pub(crate) fn element_mut(&mut self, index: usize) -> &mut T {
+        &mut self.elements[index]
     }
 
     /// Get an index into the element list according to the probability distribution.
-    fn weighted_index<R: rand::Rng>(&self, rng: &mut R) -> usize {
+    pub(crate) fn weighted_index<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> usize {
         <WeightedAliasIndex<f64> as rand_distr::Distribution<usize>>::sample(&self.dist, rng)
     }
 }
+
+impl<T: Eq + Hash> Distribution<T> {
+    /// Compute the [Kullback-Leibler divergence](https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence)
+    /// of this distribution from another, in nats.
+    ///
+    /// The divergence measures how much information is lost when `other` is used to approximate
+    /// this distribution. It is zero for identical distributions, but is not symmetric.
+    ///
+    /// Elements that appear more than once in a distribution are treated as a single element
+    /// whose probability is the sum of their probabilities. Following the usual conventions,
+    /// elements that are not in the support of this distribution contribute nothing to the
+    /// divergence (since `0 * ln(0 / q) = 0`), while if any element in the support of this
+    /// distribution is not in the support of `other`, the divergence is infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::Distribution;
+    ///
+    /// let p = Distribution::new(vec![('A', 0.5), ('B', 0.5)]).unwrap();
+    /// let q = Distribution::new(vec![('A', 0.25), ('B', 0.25), ('C', 0.5)]).unwrap();
+    ///
+    /// assert_eq!(p.kl_divergence(&p), 0.0);
+    /// assert_eq!(p.kl_divergence(&q), 2.0_f64.ln());
+    /// assert_eq!(q.kl_divergence(&p), f64::INFINITY);
+    /// ```
+    pub fn kl_divergence(&self, other: &Distribution<T>) -> f64 {
+        let other_probabilities = other.probability_map();
+        self.probability_map()
+            .into_iter()
+            .filter(|(_, p)| *p > 0.0)
+            .map(
+                |(element, p)| match other_probabilities.get(element).copied() {
+                    Some(q) if q > 0.0 => p * (p / q).ln(),
+                    _ => f64::INFINITY,
+                },
+            )
+            .sum()
+    }
+
+    /// Compute the [total variation distance](https://en.wikipedia.org/wiki/Total_variation_distance_of_probability_measures)
+    /// between this distribution and another.
+    ///
+    /// This is the largest difference between the probabilities that the two distributions
+    /// assign to the same event, which is half the sum of the absolute differences between the
+    /// probabilities of each element. It ranges from zero for identical distributions to one for
+    /// distributions with disjoint supports. Elements that appear more than once in a
+    /// distribution are treated as a single element whose probability is the sum of their
+    /// probabilities.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::Distribution;
+    ///
+    /// let p = Distribution::new(vec![('A', 0.5), ('B', 0.5)]).unwrap();
+    /// let q = Distribution::new(vec![('A', 0.25), ('B', 0.25), ('C', 0.5)]).unwrap();
+    /// let r = Distribution::flat(vec!['C', 'D']).unwrap();
+    ///
+    /// assert_eq!(p.total_variation_distance(&p), 0.0);
+    /// assert_eq!(p.total_variation_distance(&q), 0.5);
+    /// assert_eq!(p.total_variation_distance(&r), 1.0);
+    /// ```
+    pub fn total_variation_distance(&self, other: &Distribution<T>) -> f64 {
+        let mut differences = self.probability_map();
+        for (element, q) in other.probability_map() {
+            *differences.entry(element).or_insert(0.0) -= q;
+        }
+        differences.values().map(|d| d.abs()).sum::<f64>() / 2.0
+    }
+
+    /// Get a map from each element of the distribution to its total probability.
+    fn probability_map(&self) -> HashMap<&T, f64> {
+        let mut map = HashMap::new();
+        for (element, p) in self.probabilities() {
+            *map.entry(element).or_insert(0.0) += p;
+        }
+        map
+    }
+}
+
+impl<M: Move, const P: usize> Distribution<Profile<M, P>> {
+    /// Create the joint distribution over profiles formed by each player independently sampling a
+    /// move from their own distribution.
+    ///
+    /// The probability of each profile is the product of the probabilities of its moves. Profiles
+    /// are ordered lexicographically by the order of each player's elements, with player `P0`
+    /// varying slowest.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the number of profiles is larger than u32::MAX.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let row = Distribution::new(vec![('U', 0.25), ('D', 0.75)]).unwrap();
+    /// let col = Distribution::new(vec![('L', 0.5), ('R', 0.5)]).unwrap();
+    ///
+    /// let joint = Distribution::product(PerPlayer::new([row, col])).unwrap();
+    /// assert_eq!(
+    ///     joint.probabilities().collect::<Vec<_>>(),
+    ///     vec![
+    ///         (&Profile::new(['U', 'L']), 0.125),
+    ///         (&Profile::new(['U', 'R']), 0.125),
+    ///         (&Profile::new(['D', 'L']), 0.375),
+    ///         (&Profile::new(['D', 'R']), 0.375),
+    ///     ],
+    /// );
+    /// ```
+    pub fn product(per_player: PerPlayer<Distribution<M>, P>) -> Option<Self> {
+        let weighted_moves = PlayerIndex::all().map(|player| {
+            per_player[player]
+                .probabilities()
+                .map(|(m, prob)| (*m, prob))
+                .collect::<Vec<_>>()
+        });
+        let weighted_profiles = weighted_moves
+            .multi_cartesian_product()
+            .map(|weighted_profile| {
+                let prob = weighted_profile.iter().map(|(_, prob)| prob).product();
+                let moves: [M; P] = weighted_profile
+                    .into_iter()
+                    .map(|(m, _)| m)
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+                (Profile::new(moves), prob)
+            })
+            .collect();
+        Distribution::new(weighted_profiles)
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt;
+
+use crate::{for2, MixedProfile, Move, PerPlayer, PlayerIndex, Profile};
+
+/// A summary of the solution concepts for a two-player normal-form game, produced by
+/// [`Normal::equilibrium_report`](crate::Normal::equilibrium_report).
+///
+/// The [`Display`](fmt::Display) implementation renders the report as a readable summary.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquilibriumReport<M> {
+    pure_nash: Vec<Profile<M, 2>>,
+    mixed_nash: Vec<MixedProfile<M, 2>>,
+    dominant_moves: PerPlayer<Option<M>, 2>,
+    pareto_optimal: Vec<Profile<M, 2>>,
+}
+
+impl<M: Move> EquilibriumReport<M> {
+    /// Construct a new equilibrium report from its components.
+    pub fn new(
+        pure_nash: Vec<Profile<M, 2>>,
+        mixed_nash: Vec<MixedProfile<M, 2>>,
+        dominant_moves: PerPlayer<Option<M>, 2>,
+        pareto_optimal: Vec<Profile<M, 2>>,
+    ) -> Self {
+        EquilibriumReport {
+            pure_nash,
+            mixed_nash,
+            dominant_moves,
+            pareto_optimal,
+        }
+    }
+
+    /// The pure strategy Nash equilibria of the game.
+    pub fn pure_nash_equilibria(&self) -> &[Profile<M, 2>] {
+        &self.pure_nash
+    }
+
+    /// The Nash equilibria of the game in which at least one player plays a (non-degenerate)
+    /// mixed strategy. Pure equilibria are not repeated here.
+    pub fn mixed_nash_equilibria(&self) -> &[MixedProfile<M, 2>] {
+        &self.mixed_nash
+    }
+
+    /// The strictly dominant move for each player, if any.
+    pub fn dominant_moves(&self) -> &PerPlayer<Option<M>, 2> {
+        &self.dominant_moves
+    }
+
+    /// The Pareto optimal pure strategy profiles of the game.
+    pub fn pareto_optimal_solutions(&self) -> &[Profile<M, 2>] {
+        &self.pareto_optimal
+    }
+}
+
+fn fmt_profiles<M: Move>(
+    fmt: &mut fmt::Formatter<'_>,
+    heading: &str,
+    profiles: &[Profile<M, 2>],
+) -> fmt::Result {
+    writeln!(fmt, "{}:", heading)?;
+    if profiles.is_empty() {
+        writeln!(fmt, "  none")?;
+    }
+    for profile in profiles {
+        writeln!(fmt, "  ({:?}, {:?})", profile[for2::P0], profile[for2::P1])?;
+    }
+    Ok(())
+}
+
+impl<M: Move> fmt::Display for EquilibriumReport<M> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_profiles(fmt, "Pure Nash equilibria", &self.pure_nash)?;
+
+        writeln!(fmt, "Mixed Nash equilibria:")?;
+        if self.mixed_nash.is_empty() {
+            writeln!(fmt, "  none")?;
+        }
+        for mixed in &self.mixed_nash {
+            let strategies = PlayerIndex::<2>::all()
+                .map(|player| {
+                    let moves = mixed
+                        .for_player(player)
+                        .iter()
+                        .map(|(m, prob)| format!("{:?}: {:.3}", m, prob))
+                        .collect::<Vec<_>>();
+                    format!("{} plays {{{}}}", player, moves.join(", "))
+                })
+                .collect::<Vec<_>>();
+            writeln!(fmt, "  {}", strategies.join("; "))?;
+        }
+
+        writeln!(fmt, "Dominant moves:")?;
+        for player in PlayerIndex::<2>::all() {
+            match self.dominant_moves[player] {
+                Some(m) => writeln!(fmt, "  {}: {:?}", player, m)?,
+                None => writeln!(fmt, "  {}: none", player)?,
+            }
+        }
+
+        fmt_profiles(fmt, "Pareto optimal profiles", &self.pareto_optimal)
+    }
+}
@@ -25,20 +25,44 @@ pub enum ErrorKind<M, const P: usize> {
     /// A player played an invalid move.
     InvalidMove(PlayerIndex<P>, M),
 
+    /// A player's strategy repeatedly produced invalid moves and ran out of retries. Includes the
+    /// last invalid move produced. See
+    /// [`Game::play_with_retries`](crate::Game::play_with_retries).
+    StrategyFailedToProduceValidMove(PlayerIndex<P>, M),
+
     /// An apparently valid move did not produce the next intermediate state the game. This is
     /// likely an error in the construction of the game tree.
     NoNextState(M),
+
+    /// A move of chance offered different branches than on an earlier path through the game, so
+    /// the paths could not be enumerated consistently. This is likely due to a randomized
+    /// strategy. See [`Game::expected_payoff`](crate::Game::expected_payoff).
+    InconsistentChance,
+
+    /// The matchup playing the game is inconsistent with the game. See
+    /// [`Matchup::validate`](crate::Matchup::validate).
+    InvalidMatchup(MatchupError),
 }
 
 impl<S, M: Move, const P: usize> Display for Error<S, M, P> {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = match self.kind {
+        let msg = match &self.kind {
             ErrorKind::InvalidMove(player, the_move) => {
                 format!("player {} played an invalid move: {:?}", player, the_move)
             }
+            ErrorKind::StrategyFailedToProduceValidMove(player, the_move) => {
+                format!(
+                    "strategy for player {} failed to produce a valid move (last invalid move: {:?})",
+                    player, the_move
+                )
+            }
             ErrorKind::NoNextState(the_move) => {
                 format!("no next state for apparently valid move: {:?}", the_move)
             }
+            ErrorKind::InconsistentChance => {
+                "the moves of chance reached changed between paths through the game".to_string()
+            }
+            ErrorKind::InvalidMatchup(err) => format!("invalid matchup: {}", err),
         };
         write!(fmt, "{}", msg)
     }
@@ -46,6 +70,124 @@ impl<S, M: Move, const P: usize> Display for Error<S, M, P> {
 
 impl<S: State, M: Move, const P: usize> std::error::Error for Error<S, M, P> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        match &self.kind {
+            ErrorKind::InvalidMatchup(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// An error indicating that a [matchup](crate::Matchup) is inconsistent with the game it is meant
+/// to play, produced by [`Matchup::validate`](crate::Matchup::validate).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MatchupError {
+    /// The matchup has a different number of players than the game is for.
+    WrongNumberOfPlayers {
+        /// The number of players the game is for.
+        expected: usize,
+        /// The number of players in the matchup.
+        found: usize,
+    },
+}
+
+impl Display for MatchupError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchupError::WrongNumberOfPlayers { expected, found } => write!(
+                fmt,
+                "the game is for {} players, but the matchup has {} players",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatchupError {}
+
+/// An error that occurred while loading a game from CSV data.
+///
+/// Line numbers are one-based and count all lines in the input, including empty lines.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The input could not be read.
+    Io(std::io::Error),
+
+    /// The input did not contain a header row.
+    MissingHeader,
+
+    /// A row contained the wrong number of cells.
+    RowLength {
+        /// The line on which the row appears.
+        line: usize,
+        /// The expected number of cells.
+        expected: usize,
+        /// The actual number of cells.
+        found: usize,
+    },
+
+    /// A move label could not be parsed.
+    InvalidMove {
+        /// The line on which the move label appears.
+        line: usize,
+        /// The unparsable move label.
+        label: String,
+    },
+
+    /// A payoff cell was not of the form `u0;u1` or a utility value could not be parsed.
+    InvalidPayoff {
+        /// The line on which the cell appears.
+        line: usize,
+        /// The contents of the unparsable cell.
+        cell: String,
+    },
+
+    /// A move label was parsed to the same move as an earlier label for the same player.
+    DuplicateMove {
+        /// The line on which the duplicate move label appears.
+        line: usize,
+        /// The duplicate move label.
+        label: String,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(fmt, "error reading CSV input: {}", err),
+            CsvError::MissingHeader => write!(fmt, "CSV input is missing a header row"),
+            CsvError::RowLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                fmt,
+                "line {}: expected {} cells, found {}",
+                line, expected, found
+            ),
+            CsvError::InvalidMove { line, label } => {
+                write!(fmt, "line {}: invalid move label: {:?}", line, label)
+            }
+            CsvError::InvalidPayoff { line, cell } => {
+                write!(fmt, "line {}: invalid payoff: {:?}", line, cell)
+            }
+            CsvError::DuplicateMove { line, label } => {
+                write!(fmt, "line {}: duplicate move label: {:?}", line, label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CsvError {
+    fn from(err: std::io::Error) -> Self {
+        CsvError::Io(err)
     }
 }
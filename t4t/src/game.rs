@@ -1,6 +1,11 @@
 use std::fmt::Debug;
 
-use crate::{Action, Context, Error, GameTree, Matchup, Move, Outcome, PlayerIndex, Utility};
+use num::ToPrimitive;
+
+use crate::{
+    Action, CompiledGame, Context, Distribution, Error, ErrorKind, GameTree, Matchup, Move,
+    Outcome, Payoff, PlayerIndex, Ply, TraceEvent, Transcript, Utility,
+};
 
 /// A trait that collects the trait requirements of a game state.
 ///
@@ -50,6 +55,15 @@ pub trait Game<const P: usize>: Clone + Sized + Send + Sync {
         self.clone().into_game_tree()
     }
 
+    /// Build the game tree for this game once, producing a [compiled game](CompiledGame) that
+    /// reuses it each time the game is played.
+    ///
+    /// This amortizes the cost of translating the game into a game tree when playing many
+    /// matchups, as in a [tournament](crate::Tournament).
+    fn compile(&self) -> CompiledGame<Self, P> {
+        CompiledGame::new(self.clone())
+    }
+
     /// Produce a view of the game state for the given player.
     fn state_view(&self, state: &Self::State, player: PlayerIndex<P>) -> Self::View;
 
@@ -61,43 +75,653 @@ pub trait Game<const P: usize>: Clone + Sized + Send + Sync {
     /// Play this game with the given players by executing the game tree.
     ///
     /// Produces a value of the game's outcome type on success, otherwise an error.
+    ///
+    /// Each player gets a fresh [strategy](crate::Strategy) for the game, which is
+    /// [reset](crate::Strategy::reset) before the game begins.
+    ///
+    /// If a player's strategy plays an invalid move, the move is logged as a warning and the game
+    /// ends with a
+    /// [`StrategyFailedToProduceValidMove`](ErrorKind::StrategyFailedToProduceValidMove) error.
+    /// Use [`play_with_retries`](Game::play_with_retries) to give strategies more chances.
     fn play(&self, matchup: &Matchup<Self, P>) -> PlayResult<Self, P> {
-        let mut node = self.game_tree();
-        let mut strategies = matchup.strategies();
+        self.play_with_retries(matchup, 0)
+    }
+
+    /// Play this game with the given players by executing the game tree, requesting a new move
+    /// up to `retries` times from a strategy that plays an invalid move.
+    ///
+    /// Each invalid move is logged as a warning. The retry count applies separately to each turn.
+    /// If a strategy still plays an invalid move after all retries, the game ends with a
+    /// [`StrategyFailedToProduceValidMove`](ErrorKind::StrategyFailedToProduceValidMove) error.
+    ///
+    /// Note that this cannot guard against a strategy that never returns.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    /// let confused = Player::new("Confused".to_string(), || Strategy::periodic_pure(vec!['X', 'D']));
+    /// let matchup = Matchup::from_players([nice, confused]);
+    ///
+    /// assert_eq!(
+    ///     pd.play(&matchup).unwrap_err().kind,
+    ///     ErrorKind::StrategyFailedToProduceValidMove(for2::P1, 'X'),
+    /// );
+    /// assert_eq!(
+    ///     pd.play_with_retries(&matchup, 1),
+    ///     Ok(SimultaneousOutcome::new(Profile::new(['C', 'D']), Payoff::from([0, 3]))),
+    /// );
+    /// ```
+    fn play_with_retries(&self, matchup: &Matchup<Self, P>, retries: usize) -> PlayResult<Self, P> {
+        play_game_tree(
+            self,
+            self.game_tree(),
+            matchup,
+            retries,
+            &mut |_| {},
+            &mut sample_chance,
+        )
+    }
+
+    /// Play this game with the given players, like [`play`](Game::play), while calling
+    /// `observer` with a [`TraceEvent`] at each step of the game's execution.
+    ///
+    /// The observer is notified before each turn and move of chance, after each move is played,
+    /// and when the game ends with a payoff. This is useful for debugging complex games or for
+    /// understanding why a game produced a given outcome.
+    ///
+    /// Each move event carries the annotation that
+    /// [`play_with_transcript`](Game::play_with_transcript) would record for that move, so an
+    /// observer can build an annotated transcript.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    /// let mean = Player::new("Mean".to_string(), || Strategy::pure('D'));
+    ///
+    /// let mut log = Vec::new();
+    /// let outcome = pd
+    ///     .play_with_observer(&Matchup::from_players([nice, mean]), |event| match event {
+    ///         TraceEvent::Turn { to_move, .. } => log.push(format!("turn: {:?}", to_move)),
+    ///         TraceEvent::Move(ply, _) => log.push(format!("move: {:?}", ply.the_move)),
+    ///         TraceEvent::End { payoff, .. } => log.push(format!("end: {:?}", payoff)),
+    ///         TraceEvent::Chance { .. } => log.push("chance".to_string()),
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(outcome.payoff(), &Payoff::from([0, 3]));
+    /// assert_eq!(log.len(), 4);
+    /// assert_eq!(log[1], "move: 'C'");
+    /// assert_eq!(log[2], "move: 'D'");
+    /// ```
+    fn play_with_observer(
+        &self,
+        matchup: &Matchup<Self, P>,
+        mut observer: impl FnMut(&TraceEvent<Self::State, Self::Move, Self::Utility, P>),
+    ) -> PlayResult<Self, P> {
+        play_game_tree(
+            self,
+            self.game_tree(),
+            matchup,
+            0,
+            &mut observer,
+            &mut sample_chance,
+        )
+    }
+
+    /// Play this game with the given players, like [`play`](Game::play), while recording a
+    /// transcript of every move played, including moves of chance, in the order they were played.
+    ///
+    /// Each move played by a player is [annotated](Transcript::annotation) with the name of the
+    /// [strategy that chose it](crate::Strategy::acting_name), if the strategy is named. This
+    /// documents the provenance of each move, which helps to debug strategies built from other
+    /// strategies.
+    ///
+    /// The transcript is returned even if the game ends with an error, in which case it contains
+    /// the moves played up to the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 2);
+    ///
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C').with_name("always C"));
+    /// let mean = Player::new("Mean".to_string(), || Strategy::pure('D'));
+    ///
+    /// let (outcome, transcript) = rpd.play_with_transcript(&Matchup::from_players([nice, mean]));
+    /// assert_eq!(*outcome.unwrap().score(), Payoff::from([0, 6]));
+    /// assert_eq!(transcript.moves_by_player(for2::P0), vec!['C', 'C']);
+    /// assert_eq!(transcript.annotation(0), Some("always C"));
+    /// assert_eq!(transcript.annotation(1), None);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn play_with_transcript(
+        &self,
+        matchup: &Matchup<Self, P>,
+    ) -> (PlayResult<Self, P>, Transcript<Self::Move, P>) {
+        let mut transcript = Transcript::new();
+        let result = play_game_tree(
+            self,
+            self.game_tree(),
+            matchup,
+            0,
+            &mut |event| {
+                if let TraceEvent::Move(ply, annotation) = event {
+                    match annotation {
+                        Some(name) => transcript.add_annotated(*ply, *name),
+                        None => transcript.add(*ply),
+                    }
+                }
+            },
+            &mut sample_chance,
+        );
+        (result, transcript)
+    }
+
+    /// Play this game `n` times with the given players and return the sum of the payoffs.
+    ///
+    /// Each game is played independently with fresh copies of each player's strategy. This is
+    /// useful for estimating the expected payoff of a matchup involving stochastic strategies.
+    /// Contrast with [`Repeated`](crate::Repeated), where the games are played in sequence and
+    /// players may observe the outcomes of previous games.
+    ///
+    /// Returns the first error encountered, if any.
+    #[allow(clippy::type_complexity)]
+    fn play_n_times(
+        &self,
+        matchup: &Matchup<Self, P>,
+        n: usize,
+    ) -> Result<Payoff<Self::Utility, P>, Error<Self::State, Self::Move, P>> {
+        let mut total = Payoff::zeros();
+        for _ in 0..n {
+            let outcome = self.play(matchup)?;
+            total = total + *outcome.payoff();
+        }
+        Ok(total)
+    }
 
+    /// Compute the exact expected payoff of playing this game with the given players, by
+    /// expanding every branch of each move of chance rather than sampling one.
+    ///
+    /// Each possible sequence of chance moves is played out with fresh copies of each player's
+    /// strategy, and the resulting payoffs are weighted by the probability of that sequence. This
+    /// is useful for games with chance nodes, such as Bayesian games, whose expected payoffs
+    /// could otherwise only be estimated by [playing many times](Game::play_n_times).
+    ///
+    /// Only chance nodes are expanded, so the result is exact only for deterministic strategies.
+    /// A randomized strategy makes a single random choice on each path through the game. Since
+    /// every path is played out, the game must have a finite number of possible chance outcomes.
+    ///
+    /// Returns the first error encountered, if any.
+    ///
+    /// If a randomized strategy's choice changes which chance nodes are reached on a path, so that
+    /// the paths cannot be enumerated consistently, returns an [`ErrorKind::InconsistentChance`]
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    /// let mean = Player::new("Mean".to_string(), || Strategy::pure('D'));
+    ///
+    /// // A game without chance nodes has only one possible path.
+    /// assert_eq!(
+    ///     pd.expected_payoff(&Matchup::from_players([nice, mean])),
+    ///     Ok(Payoff::from([0.0, 3.0])),
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn expected_payoff(
+        &self,
+        matchup: &Matchup<Self, P>,
+    ) -> Result<Payoff<f64, P>, Error<Self::State, Self::Move, P>>
+    where
+        Self::Utility: ToPrimitive,
+    {
+        let mut expected = Payoff::zeros();
+        let root = self.game_tree();
+        // The index of the branch taken at each chance node on the current path.
+        let mut choices: Vec<usize> = Vec::new();
+        // The branches of each chance node on the previous path. The current path shares the
+        // prefix of the previous path that is covered by `choices`, so each chance node in that
+        // prefix must offer the same branches.
+        let mut previous: Vec<Vec<(Self::Move, f64)>> = Vec::new();
         loop {
-            match node.action {
-                Action::Turns { to_move, next } => {
-                    let moves = to_move
-                        .iter()
-                        .map(|&index| {
-                            let view = self.state_view(&node.state, index);
-                            let context = Context::new(index, view);
-                            strategies[index].next_move(&context)
-                        })
+            // The branches of each chance node on the current path.
+            let mut branches: Vec<Vec<(Self::Move, f64)>> = Vec::new();
+            let outcome = play_game_tree(
+                self,
+                root.clone(),
+                matchup,
+                0,
+                &mut |_| {},
+                &mut |distribution| {
+                    let depth = branches.len();
+                    let branch: Vec<(Self::Move, f64)> = distribution
+                        .probabilities()
+                        .map(|(the_move, p)| (*the_move, p))
                         .collect();
+                    if depth < choices.len() && previous.get(depth) != Some(&branch) {
+                        return Err(ErrorKind::InconsistentChance);
+                    }
+                    let index = choices.get(depth).copied().unwrap_or(0);
+                    let the_move = branch[index].0;
+                    branches.push(branch);
+                    Ok(the_move)
+                },
+            )?;
+
+            if branches.len() < choices.len() {
+                return Err(Error::new(root.state, ErrorKind::InconsistentChance));
+            }
+
+            choices.resize(branches.len(), 0);
+            let weight: f64 = branches
+                .iter()
+                .zip(&choices)
+                .map(|(branch, &index)| branch[index].1)
+                .product();
+            expected = expected
+                + outcome
+                    .payoff()
+                    .map(|u| u.to_f64().unwrap_or(f64::NAN) * weight);
 
-                    match next(node.state.clone(), moves) {
-                        Ok(next_node) => node = next_node,
+            // Advance to the next path, taking the next branch at the deepest chance node that
+            // has one left.
+            previous = branches;
+            match (0..choices.len())
+                .rev()
+                .find(|&i| choices[i] + 1 < previous[i].len())
+            {
+                Some(i) => {
+                    choices.truncate(i + 1);
+                    choices[i] += 1;
+                }
+                None => return Ok(expected),
+            }
+        }
+    }
+}
+
+/// Resolve a move of chance by sampling from its distribution.
+pub(crate) fn sample_chance<M: Move, const P: usize>(
+    distribution: &Distribution<M>,
+) -> Result<M, ErrorKind<M, P>> {
+    Ok(*distribution.sample())
+}
+
+/// Execute the game tree of the given game from the given root node with the given players,
+/// notifying `observer` of each step and resolving moves of chance with `chance`. See
+/// [`Game::play_with_retries`], [`Game::play_with_observer`], and [`Game::expected_payoff`].
+pub(crate) fn play_game_tree<G: Game<P>, const P: usize>(
+    game: &G,
+    root: GameTree<G::State, G::Move, G::Utility, G::Outcome, P>,
+    matchup: &Matchup<G, P>,
+    retries: usize,
+    observer: &mut impl FnMut(&TraceEvent<G::State, G::Move, G::Utility, P>),
+    chance: &mut impl FnMut(&Distribution<G::Move>) -> Result<G::Move, ErrorKind<G::Move, P>>,
+) -> PlayResult<G, P> {
+    if let Err(err) = matchup.validate(game) {
+        return Err(Error::new(root.state, ErrorKind::InvalidMatchup(err)));
+    }
+
+    let mut node = root;
+    let mut strategies = matchup.strategies();
+    for strategy in strategies.iter_mut() {
+        strategy.reset();
+    }
+
+    loop {
+        match node.action {
+            Action::Turns { to_move, next } => {
+                observer(&TraceEvent::Turn {
+                    state: &node.state,
+                    to_move: &to_move,
+                });
+
+                let mut next_move = |index: PlayerIndex<P>| {
+                    let view = game.state_view(&node.state, index);
+                    let context = Context::new(index, view);
+                    strategies[index].next_move(&context)
+                };
+                let mut moves: Vec<G::Move> =
+                    to_move.iter().map(|&index| next_move(index)).collect();
+
+                let mut retries_left = retries;
+                loop {
+                    match next(node.state.clone(), moves.clone()) {
+                        Ok(next_node) => {
+                            for (&player, &the_move) in to_move.iter().zip(&moves) {
+                                observer(&TraceEvent::Move(
+                                    Ply::player(player, the_move),
+                                    strategies[player].acting_name(),
+                                ));
+                            }
+                            node = next_node;
+                            break;
+                        }
+                        Err(ErrorKind::InvalidMove(player, the_move)) => {
+                            let Some(position) = to_move.iter().position(|&p| p == player) else {
+                                let kind = ErrorKind::InvalidMove(player, the_move);
+                                return Err(Error::new(node.state, kind));
+                            };
+                            log::warn!(
+                                "Game::play_with_retries: player {} played an invalid move: {:?} ({} retries left)",
+                                player,
+                                the_move,
+                                retries_left,
+                            );
+                            if retries_left == 0 {
+                                let kind =
+                                    ErrorKind::StrategyFailedToProduceValidMove(player, the_move);
+                                return Err(Error::new(node.state, kind));
+                            }
+                            retries_left -= 1;
+                            moves[position] = next_move(player);
+                        }
                         Err(kind) => {
                             return Err(Error::new(node.state, kind));
                         }
                     }
                 }
+            }
 
-                Action::Chance { distribution, next } => {
-                    let the_move = distribution.sample();
+            Action::Chance { distribution, next } => {
+                observer(&TraceEvent::Chance { state: &node.state });
+                let the_move = match chance(&distribution) {
+                    Ok(the_move) => the_move,
+                    Err(kind) => return Err(Error::new(node.state, kind)),
+                };
 
-                    match next(node.state.clone(), *the_move) {
-                        Ok(next_node) => node = next_node,
-                        Err(kind) => {
-                            return Err(Error::new(node.state, kind));
-                        }
+                match next(node.state.clone(), the_move) {
+                    Ok(next_node) => {
+                        observer(&TraceEvent::Move(Ply::chance(the_move), None));
+                        node = next_node;
+                    }
+                    Err(kind) => {
+                        return Err(Error::new(node.state, kind));
                     }
                 }
+            }
+
+            Action::End { outcome, .. } => {
+                observer(&TraceEvent::End {
+                    state: &node.state,
+                    payoff: outcome.payoff(),
+                });
+                return Ok(outcome);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        for2, Normal, Player, Repeated, RepeatedState, SequentialOutcome, Strategy, Transcript,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use test_log::test;
+
+    /// Chance flips a biased coin, then player 0 guesses the result without seeing it.
+    #[derive(Clone)]
+    struct GuessTheCoin;
+
+    impl Game<2> for GuessTheCoin {
+        type Move = char;
+        type Utility = i32;
+        type Outcome = SequentialOutcome<char, i32, 2>;
+        type State = Option<char>;
+        type View = ();
+
+        fn into_game_tree(self) -> GameTree<Option<char>, char, i32, Self::Outcome, 2> {
+            let coin = Distribution::new(vec![('H', 0.25), ('T', 0.75)]).unwrap();
+            GameTree::chance(Arc::new(None), coin, |_, flip| {
+                Ok(GameTree::player(
+                    Arc::new(Some(flip)),
+                    for2::P0,
+                    move |state, guess| {
+                        if guess != 'H' && guess != 'T' {
+                            return Err(ErrorKind::InvalidMove(for2::P0, guess));
+                        }
+                        let payoff = if guess == flip {
+                            Payoff::from([4, 0])
+                        } else {
+                            Payoff::from([0, 2])
+                        };
+                        let transcript = Transcript::from_ply_vec(vec![
+                            Ply::chance(flip),
+                            Ply::player(for2::P0, guess),
+                        ]);
+                        Ok(GameTree::end(
+                            state,
+                            SequentialOutcome::new(transcript, payoff),
+                        ))
+                    },
+                ))
+            })
+        }
+
+        fn state_view(&self, _state: &Option<char>, _player: PlayerIndex<2>) {}
+    }
+
+    #[test]
+    fn expected_payoff_weights_chance_branches() {
+        let guesser =
+            |guess| Player::new(format!("Guess {}", guess), move || Strategy::pure(guess));
+        let bystander = || Player::new("Bystander".to_string(), || Strategy::pure('H'));
+
+        // Guessing heads is right with probability 0.25, paying [4, 0], and wrong otherwise,
+        // paying [0, 2].
+        let heads = Matchup::from_players([guesser('H'), bystander()]);
+        assert_eq!(
+            GuessTheCoin.expected_payoff(&heads),
+            Ok(Payoff::from([0.25 * 4.0, 0.75 * 2.0]))
+        );
+
+        // Guessing tails is right with probability 0.75.
+        let tails = Matchup::from_players([guesser('T'), bystander()]);
+        assert_eq!(
+            GuessTheCoin.expected_payoff(&tails),
+            Ok(Payoff::from([0.75 * 4.0, 0.25 * 2.0]))
+        );
+
+        let broken = Matchup::from_players([guesser('X'), bystander()]);
+        assert_eq!(
+            GuessTheCoin.expected_payoff(&broken).unwrap_err().kind,
+            ErrorKind::StrategyFailedToProduceValidMove(for2::P0, 'X')
+        );
+    }
+
+    /// Player 0 chooses how many sides a die has, then chance rolls it.
+    #[derive(Clone)]
+    struct RollTheDie;
 
-                Action::End { outcome, .. } => return Ok(outcome),
+    impl Game<2> for RollTheDie {
+        type Move = u8;
+        type Utility = i32;
+        type Outcome = SequentialOutcome<u8, i32, 2>;
+        type State = ();
+        type View = ();
+
+        fn into_game_tree(self) -> GameTree<(), u8, i32, Self::Outcome, 2> {
+            GameTree::player(Arc::new(()), for2::P0, |state, sides| {
+                let die = Distribution::flat((1..=sides).collect()).unwrap();
+                Ok(GameTree::chance(state, die, move |state, roll| {
+                    let transcript = Transcript::from_ply_vec(vec![
+                        Ply::player(for2::P0, sides),
+                        Ply::chance(roll),
+                    ]);
+                    let payoff = Payoff::from([roll as i32, 0]);
+                    Ok(GameTree::end(
+                        state,
+                        SequentialOutcome::new(transcript, payoff),
+                    ))
+                }))
+            })
+        }
+
+        fn state_view(&self, _state: &(), _player: PlayerIndex<2>) {}
+    }
+
+    #[test]
+    fn expected_payoff_rejects_inconsistent_chance_paths() {
+        let bystander = || Player::new("Bystander".to_string(), || Strategy::pure(1));
+
+        let d6 = Player::new("D6".to_string(), || Strategy::pure(6));
+        let expected = RollTheDie
+            .expected_payoff(&Matchup::from_players([d6, bystander()]))
+            .unwrap();
+        assert!((expected[for2::P0] - 3.5).abs() < 1e-9);
+
+        // Chooses a three-sided die on the first path and a different die afterward. With a
+        // one-sided die, the second path takes a branch that no longer exists. With a six-sided
+        // die, the branch exists, but would be weighted with a different distribution.
+        let fickle = |sides| {
+            let choices = Arc::new(AtomicUsize::new(0));
+            Player::new("Fickle".to_string(), move || {
+                let choices = choices.clone();
+                Strategy::new(move |_: &Context<(), 2>| {
+                    if choices.fetch_add(1, Ordering::Relaxed) == 0 {
+                        3
+                    } else {
+                        sides
+                    }
+                })
+            })
+        };
+        for sides in [1, 6] {
+            let err = RollTheDie
+                .expected_payoff(&Matchup::from_players([fickle(sides), bystander()]))
+                .unwrap_err();
+            assert_eq!(err.kind, ErrorKind::InconsistentChance);
+        }
+    }
+
+    #[test]
+    fn play_terminates_with_misbehaving_strategy() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+        let broken = Player::new("Broken".to_string(), || Strategy::pure('X'));
+        let matchup = Matchup::from_players([broken, nice]);
+
+        for retries in [0, 1, 10] {
+            let err = pd.play_with_retries(&matchup, retries).unwrap_err();
+            assert_eq!(
+                err.kind,
+                ErrorKind::StrategyFailedToProduceValidMove(for2::P0, 'X')
+            );
+            assert_eq!(
+                err.to_string(),
+                "strategy for player P0 failed to produce a valid move (last invalid move: 'X')"
+            );
+        }
+    }
+
+    #[test]
+    fn play_resets_strategies_before_each_game() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 3);
+
+        // Defects once it has played three moves, counting moves in state shared across games.
+        let moves_played = Arc::new(AtomicUsize::new(0));
+        let counter = moves_played.clone();
+        let player = Player::new("Counter".to_string(), move || {
+            let counter = counter.clone();
+            let resetter = counter.clone();
+            Strategy::new(
+                move |_: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+                    if counter.fetch_add(1, Ordering::Relaxed) < 3 {
+                        'C'
+                    } else {
+                        'D'
+                    }
+                },
+            )
+            .with_reset(move || resetter.store(0, Ordering::Relaxed))
+        });
+        let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+        let matchup = Matchup::from_players([player, nice]);
+
+        for _ in 0..3 {
+            let history = rpd.play(&matchup).unwrap();
+            assert_eq!(*history.score(), Payoff::from([6, 6]));
+            assert_eq!(moves_played.load(Ordering::Relaxed), 3);
+        }
+    }
+
+    #[test]
+    fn play_n_times_random_dilemma_near_expected() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let random = || {
+            Player::new("Random".to_string(), || {
+                Strategy::mixed_flat(vec!['C', 'D']).unwrap()
+            })
+        };
+
+        let total = pd
+            .play_n_times(&Matchup::from_players([random(), random()]), 100)
+            .unwrap();
+
+        // Each outcome is equally likely, so the expected utility is (2+0+3+1)/4 = 1.5.
+        for utility in total {
+            let average = utility as f64 / 100.0;
+            assert!(
+                (average - 1.5).abs() < 0.5,
+                "average too far from 1.5: {}",
+                average
+            );
+        }
+    }
+
+    #[test]
+    fn transcript_records_which_ensemble_member_chose_each_move() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 20);
+        let ensemble = Player::new("Ensemble".to_string(), || {
+            let members = vec![
+                Strategy::pure('C').with_name("Nice"),
+                Strategy::pure('D').with_name("Mean"),
+            ];
+            Strategy::probabilistic(Distribution::flat(members).unwrap()).with_name("Ensemble")
+        });
+        let anonymous = Player::new("Anonymous".to_string(), || Strategy::pure('C'));
+
+        let (result, transcript) = crate::with_seed(7, || {
+            rpd.play_with_transcript(&Matchup::from_players([ensemble, anonymous]))
+        });
+        let history = result.unwrap();
+        assert_eq!(transcript.iter().count(), 40);
+
+        let mut names = Vec::new();
+        for (index, ply) in transcript.iter().enumerate() {
+            match ply.player {
+                Some(for2::P0) => {
+                    let name = transcript.annotation(index).unwrap();
+                    let expected = if ply.the_move == 'C' { "Nice" } else { "Mean" };
+                    assert_eq!(name, expected);
+                    names.push(name);
+                }
+                _ => assert_eq!(transcript.annotation(index), None),
             }
         }
+        assert!(names.contains(&"Nice") && names.contains(&"Mean"));
+        assert_eq!(
+            transcript.moves_by_player(for2::P0),
+            history.moves_for_player(for2::P0).collect::<Vec<_>>()
+        );
     }
 }
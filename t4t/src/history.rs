@@ -1,15 +1,24 @@
+use num::ToPrimitive;
+use std::collections::{HashMap, VecDeque};
 use std::{fmt, hash};
 
 use crate::{
-    Game, Move, Outcome, Past, Payoff, PlayerIndex, Plies, Profile, Record, SequentialOutcome,
-    SimultaneousOutcome, Summary, Transcript, Utility,
+    Game, Move, Outcome, Past, Payoff, PlayerIndex, PlayerRecord, Plies, Profile, Record,
+    SequentialOutcome, SimultaneousOutcome, Summary, Transcript, Utility,
 };
 
 /// For repeated games, a history of previously played games.
+///
+/// A history may be *bounded*, in which case it retains only the outcomes of the most recent
+/// games, up to a fixed window size. The [score](History::score), [summary](Record::summary),
+/// and [number of games](History::num_games) of a bounded history still account for all games
+/// played, but all other queries see only the retained outcomes.
 pub struct History<G: Game<P>, const P: usize> {
-    outcomes: Vec<G::Outcome>,
+    outcomes: VecDeque<G::Outcome>,
     score: Payoff<G::Utility, P>,
     summary: Summary<P>,
+    window: Option<usize>,
+    num_dropped: usize,
 }
 
 impl<G: Game<P>, const P: usize> History<G, P> {
@@ -18,22 +27,52 @@ impl<G: Game<P>, const P: usize> History<G, P> {
         History::default()
     }
 
+    /// Construct a new, empty history that retains only the outcomes of the most recent `window`
+    /// games.
+    pub fn with_window(window: usize) -> Self {
+        History {
+            window: Some(window),
+            ..History::default()
+        }
+    }
+
     /// Update the history by adding a new game outcome. Returns a reference to the newly added
     /// outcome.
+    ///
+    /// If the history is bounded and full, the oldest outcome is dropped. A window size of zero is
+    /// treated as one, since the newly added outcome is always retained.
     pub fn add(&mut self, outcome: G::Outcome) -> &G::Outcome {
         self.score = self.score + *outcome.payoff();
         self.summary = self.summary + outcome.record().summary();
-        self.outcomes.push(outcome);
-        self.outcomes.last().unwrap()
+        self.outcomes.push_back(outcome);
+        if let Some(window) = self.window {
+            while self.outcomes.len() > window.max(1) {
+                self.outcomes.pop_front();
+                self.num_dropped += 1;
+            }
+        }
+        self.outcomes.back().unwrap()
+    }
+
+    /// Get the maximum number of outcomes retained by this history, or `None` if the history is
+    /// unbounded.
+    pub fn window(&self) -> Option<usize> {
+        self.window
+    }
+
+    /// Get the total number of games played, including games whose outcomes have been dropped
+    /// from a bounded history.
+    pub fn num_games(&self) -> usize {
+        self.outcomes.len() + self.num_dropped
     }
 
     /// Get an iterator over the outcomes of previously played games.
-    pub fn outcomes(&self) -> Past<&G::Outcome> {
+    pub fn outcomes(&self) -> Past<'_, &G::Outcome> {
         Past::from_iter(self.outcomes.len(), self.outcomes.iter())
     }
 
     /// Get an iterator over the move records of previously played games.
-    pub fn records(&self) -> Past<&<G::Outcome as Outcome<G::Move, G::Utility, P>>::Record> {
+    pub fn records(&self) -> Past<'_, &<G::Outcome as Outcome<G::Move, G::Utility, P>>::Record> {
         Past::from_iter(
             self.outcomes.len(),
             self.outcomes().map(|outcome| outcome.record()),
@@ -41,7 +80,7 @@ impl<G: Game<P>, const P: usize> History<G, P> {
     }
 
     /// Get an iterator over the payoffs of previously played games.
-    pub fn payoffs(&self) -> Past<&Payoff<G::Utility, P>> {
+    pub fn payoffs(&self) -> Past<'_, &Payoff<G::Utility, P>> {
         Past::from_iter(
             self.outcomes.len(),
             self.outcomes().map(|outcome| outcome.payoff()),
@@ -52,6 +91,122 @@ impl<G: Game<P>, const P: usize> History<G, P> {
     pub fn score(&self) -> &Payoff<G::Utility, P> {
         &self.score
     }
+
+    /// Get a record of the previously played games from the perspective of the given player,
+    /// containing their moves, their utility, and their rank in each game.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 3);
+    ///
+    /// let copycat = Player::new("Copycat".to_string(), || Strategy::lagged_mirror(1, 'C'));
+    /// let defector = Player::new("Defector".to_string(), || Strategy::pure('D'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([copycat, defector])).unwrap();
+    ///
+    /// let record = history.for_player(for2::P0);
+    /// assert_eq!(record.moves(), &['C', 'D', 'D']);
+    /// assert_eq!(record.utilities(), &[0, 1, 1]);
+    /// assert_eq!(record.ranks(), &[2, 1, 1]);
+    /// assert_eq!(record.score(), 2);
+    /// ```
+    pub fn for_player(&self, player: PlayerIndex<P>) -> PlayerRecord<G::Move, G::Utility> {
+        let mut record = PlayerRecord::new();
+        for outcome in &self.outcomes {
+            let payoff = outcome.payoff();
+            let utility = payoff[player];
+            let rank = 1 + payoff.iter().filter(|u| **u > utility).count();
+            record.add(
+                outcome.record().played_moves_by_player(player),
+                utility,
+                rank,
+            );
+        }
+        record
+    }
+
+    /// Get the variance of the per-game utilities received by the given player.
+    ///
+    /// This is the population variance of the player's utilities across the previously played
+    /// games, which distinguishes players who earn a steady utility from those whose utility is
+    /// volatile, even if their mean utility is the same. For a bounded history, only the retained
+    /// outcomes are considered. Returns zero for an empty history.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 4);
+    ///
+    /// let cooperator = Player::new("Cooperator".to_string(), || Strategy::pure('C'));
+    /// let alternator = Player::new("Alternator".to_string(), || {
+    ///     Strategy::periodic_pure(vec!['C', 'D'])
+    /// });
+    ///
+    /// let history = rpd.play(&Matchup::from_players([cooperator, alternator])).unwrap();
+    ///
+    /// // The cooperator alternates between utilities 2 and 0, the alternator between 2 and 3.
+    /// assert_eq!(history.payoff_variance(for2::P0), 1.0);
+    /// assert_eq!(history.payoff_variance(for2::P1), 0.25);
+    /// ```
+    pub fn payoff_variance(&self, player: PlayerIndex<P>) -> f64
+    where
+        G::Utility: ToPrimitive,
+    {
+        if self.outcomes.is_empty() {
+            return 0.0;
+        }
+        let utils: Vec<f64> = self
+            .outcomes
+            .iter()
+            .map(|outcome| outcome.payoff()[player].to_f64().unwrap_or(f64::NAN))
+            .collect();
+        let n = utils.len() as f64;
+        let mean = utils.iter().sum::<f64>() / n;
+        utils.iter().map(|u| (u - mean).powi(2)).sum::<f64>() / n
+    }
+
+    /// Get the mean payoff per game, that is, the [score](Self::score) divided by the
+    /// [number of games](Self::num_games).
+    ///
+    /// Unlike the score, this does not depend on the length of the game, which enables comparing
+    /// repeated games of different lengths. For a bounded history, the average covers all games
+    /// played, not just the retained games. Returns a [zero payoff](Payoff::zeros) if no games
+    /// have been played.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 10);
+    ///
+    /// let cooperator = Player::new("Cooperator".to_string(), || Strategy::pure('C'));
+    /// let alternator = Player::new("Alternator".to_string(), || {
+    ///     Strategy::periodic_pure(vec!['C', 'D'])
+    /// });
+    ///
+    /// let history = rpd.play(&Matchup::from_players([cooperator, alternator])).unwrap();
+    /// assert_eq!(*history.score(), Payoff::from([10, 25]));
+    /// assert_eq!(history.average_payoff(), Payoff::from([1.0, 2.5]));
+    /// ```
+    pub fn average_payoff(&self) -> Payoff<f64, P>
+    where
+        G::Utility: ToPrimitive,
+    {
+        if self.num_games() == 0 {
+            return Payoff::zeros();
+        }
+        let n = self.num_games() as f64;
+        self.score.map(|u| u.to_f64().unwrap_or(f64::NAN) / n)
+    }
 }
 
 impl<M, U, G, const P: usize> History<G, P>
@@ -61,7 +216,7 @@ where
     G: Game<P, Move = M, Utility = U, Outcome = SimultaneousOutcome<M, U, P>>,
 {
     /// Get an iterator over the profiles of previously played games.
-    pub fn profiles(&self) -> Past<&Profile<G::Move, P>> {
+    pub fn profiles(&self) -> Past<'_, &Profile<G::Move, P>> {
         Past::from_iter(
             self.outcomes.len(),
             self.outcomes().map(|outcome| outcome.profile()),
@@ -69,12 +224,287 @@ where
     }
 
     /// Get an iterator over all moves played by a given player.
-    pub fn moves_for_player(&self, player: PlayerIndex<P>) -> Past<G::Move> {
+    pub fn moves_for_player(&self, player: PlayerIndex<P>) -> Past<'_, G::Move> {
         Past::from_iter(
             self.outcomes.len(),
             self.profiles().map(move |profile| profile[player]),
         )
     }
+
+    /// Render the history as a table with one row per game, listing the round number, the move
+    /// played by each player, and each player's cumulative score after that round.
+    ///
+    /// The table is formatted as a [Markdown](https://www.markdownguide.org/extended-syntax/#tables)
+    /// table with aligned columns, so it is readable as plain text and can be pasted into a
+    /// notebook. For a bounded history, only the retained games are listed, but the round numbers
+    /// and cumulative scores account for all games played.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 3);
+    ///
+    /// let copycat = Player::new("Copycat".to_string(), || Strategy::lagged_mirror(1, 'C'));
+    /// let defector = Player::new("Defector".to_string(), || Strategy::pure('D'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([copycat, defector])).unwrap();
+    /// let table = history.to_table_string();
+    /// let lines: Vec<&str> = table.lines().collect();
+    ///
+    /// assert_eq!(lines[0], "| Round | P0 | P1 | P0 score | P1 score |");
+    /// assert_eq!(lines[1], "|-------|----|----|----------|----------|");
+    /// assert_eq!(lines[2], "|     1 | C  | D  |        0 |        3 |");
+    /// assert_eq!(lines[4], "|     3 | D  | D  |        2 |        5 |");
+    /// ```
+    pub fn to_table_string(&self) -> String
+    where
+        M: fmt::Display,
+        U: fmt::Display,
+    {
+        let mut header = vec!["Round".to_string()];
+        header.extend(PlayerIndex::<P>::all().map(|player| player.to_string()));
+        header.extend(PlayerIndex::<P>::all().map(|player| format!("{} score", player)));
+
+        let retained = self
+            .outcomes
+            .iter()
+            .fold(Payoff::zeros(), |total, outcome| total + *outcome.payoff());
+        let mut cumulative = self.score - retained;
+        let mut rows = Vec::new();
+        for (i, outcome) in self.outcomes.iter().enumerate() {
+            cumulative = cumulative + *outcome.payoff();
+            let mut row = vec![(self.num_dropped + i + 1).to_string()];
+            let profile = outcome.profile();
+            row.extend(PlayerIndex::<P>::all().map(|player| profile[player].to_string()));
+            row.extend(cumulative.iter().map(|u| u.to_string()));
+            rows.push(row);
+        }
+
+        // Moves are left-aligned, while round numbers and scores are right-aligned.
+        markdown_table(&header, &rows, |col| !(1..=P).contains(&col))
+    }
+
+    /// Get the length of the longest run of consecutive games in which the given player played a
+    /// move satisfying the predicate.
+    ///
+    /// For a bounded history, only the retained games are considered.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let mut history: History<Dilemma, 2> = History::empty();
+    /// for (a, b) in [('C', 'C'), ('C', 'D'), ('D', 'C'), ('C', 'C'), ('C', 'D'), ('C', 'C')] {
+    ///     history.add(SimultaneousOutcome::new(Profile::new([a, b]), Payoff::zeros()));
+    /// }
+    ///
+    /// assert_eq!(history.longest_move_streak(for2::P0, |m| *m == 'C'), 3);
+    /// assert_eq!(history.longest_move_streak(for2::P1, |m| *m == 'D'), 1);
+    /// ```
+    pub fn longest_move_streak(
+        &self,
+        player: PlayerIndex<P>,
+        predicate: impl Fn(&G::Move) -> bool,
+    ) -> usize {
+        self.moves_for_player(player)
+            .fold((0, 0), |(longest, current), m| {
+                if predicate(&m) {
+                    (longest.max(current + 1), current + 1)
+                } else {
+                    (longest, 0)
+                }
+            })
+            .0
+    }
+
+    /// Get the length of the run of consecutive games, ending with the most recent game, in which
+    /// the given player played a move satisfying the predicate.
+    ///
+    /// For a bounded history, only the retained games are considered.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let mut history: History<Dilemma, 2> = History::empty();
+    /// for (a, b) in [('C', 'D'), ('C', 'C'), ('D', 'C'), ('C', 'C')] {
+    ///     history.add(SimultaneousOutcome::new(Profile::new([a, b]), Payoff::zeros()));
+    /// }
+    ///
+    /// assert_eq!(history.current_move_streak(for2::P0, |m| *m == 'C'), 1);
+    /// assert_eq!(history.current_move_streak(for2::P1, |m| *m == 'C'), 3);
+    /// ```
+    pub fn current_move_streak(
+        &self,
+        player: PlayerIndex<P>,
+        predicate: impl Fn(&G::Move) -> bool,
+    ) -> usize {
+        self.moves_for_player(player)
+            .rev()
+            .take_while(|m| predicate(m))
+            .count()
+    }
+
+    /// Get the number of games in which the given player played a move satisfying the predicate.
+    ///
+    /// For a bounded history, only the retained games are considered.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let mut history: History<Dilemma, 2> = History::empty();
+    /// for (a, b) in [('C', 'D'), ('C', 'C'), ('D', 'C'), ('C', 'C')] {
+    ///     history.add(SimultaneousOutcome::new(Profile::new([a, b]), Payoff::zeros()));
+    /// }
+    ///
+    /// assert_eq!(history.move_count(for2::P0, |m| *m == 'C'), 3);
+    /// assert_eq!(history.move_count(for2::P1, |m| *m == 'D'), 1);
+    /// ```
+    pub fn move_count(
+        &self,
+        player: PlayerIndex<P>,
+        predicate: impl Fn(&G::Move) -> bool,
+    ) -> usize {
+        self.moves_for_player(player)
+            .filter(|m| predicate(m))
+            .count()
+    }
+
+    /// Get the round in which the given player first played a move satisfying the `is_defection`
+    /// predicate, or `None` if the player never did.
+    ///
+    /// Rounds are numbered from zero. For a bounded history, only the retained games are
+    /// considered, but rounds are still numbered from the first game played.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let mut history: History<Dilemma, 2> = History::empty();
+    /// for (a, b) in [('C', 'C'), ('C', 'D'), ('D', 'C'), ('C', 'D')] {
+    ///     history.add(SimultaneousOutcome::new(Profile::new([a, b]), Payoff::zeros()));
+    /// }
+    ///
+    /// assert_eq!(history.first_defection_round(for2::P0, |m| *m == 'D'), Some(2));
+    /// assert_eq!(history.first_defection_round(for2::P1, |m| *m == 'D'), Some(1));
+    /// assert_eq!(history.first_defection_round(for2::P1, |m| *m == 'X'), None);
+    /// ```
+    pub fn first_defection_round(
+        &self,
+        player: PlayerIndex<P>,
+        is_defection: impl Fn(&G::Move) -> bool,
+    ) -> Option<usize> {
+        self.moves_for_player(player)
+            .position(|m| is_defection(&m))
+            .map(|index| self.num_dropped + index)
+    }
+
+    /// Build an empirical model of how the given player responds to recent history.
+    ///
+    /// The model maps each window of `context_len` consecutive profiles (from oldest to most
+    /// recent) to the number of times the player played each move in the game immediately
+    /// following that window. Normalizing the counts for a window gives the empirical probability
+    /// of the player's next move in that context, which is the core of model-based strategies
+    /// such as fictitious play.
+    ///
+    /// A `context_len` of zero maps the empty window to the player's overall move counts. For a
+    /// bounded history, only the retained games are considered.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let mut history: History<Dilemma, 2> = History::empty();
+    /// for (a, b) in [('C', 'C'), ('D', 'C'), ('C', 'D'), ('C', 'C'), ('D', 'C')] {
+    ///     history.add(SimultaneousOutcome::new(Profile::new([a, b]), Payoff::zeros()));
+    /// }
+    ///
+    /// // Player 1 plays tit-for-tat, so its next move is determined by the previous profile.
+    /// let model = history.conditional_move_frequencies(for2::P1, 1);
+    /// assert_eq!(model[&vec![Profile::new(['C', 'C'])]], HashMap::from([('C', 2)]));
+    /// assert_eq!(model[&vec![Profile::new(['D', 'C'])]], HashMap::from([('D', 1)]));
+    /// assert_eq!(model[&vec![Profile::new(['C', 'D'])]], HashMap::from([('C', 1)]));
+    /// ```
+    pub fn conditional_move_frequencies(
+        &self,
+        player: PlayerIndex<P>,
+        context_len: usize,
+    ) -> HashMap<Vec<Profile<M, P>>, HashMap<M, usize>> {
+        let profiles: Vec<Profile<M, P>> = self.profiles().copied().collect();
+        let mut model: HashMap<Vec<Profile<M, P>>, HashMap<M, usize>> = HashMap::new();
+        for end in context_len..profiles.len() {
+            let context = profiles[end - context_len..end].to_vec();
+            *model
+                .entry(context)
+                .or_default()
+                .entry(profiles[end][player])
+                .or_default() += 1;
+        }
+        model
+    }
+}
+
+impl<M, U, G> History<G, 2>
+where
+    M: Move,
+    U: Utility,
+    G: Game<2, Move = M, Utility = U, Outcome = SimultaneousOutcome<M, U, 2>>,
+{
+    /// Measure how closely each player's moves track the other player's previous moves, as the
+    /// fraction of opportunities in which a player repeated the move their opponent played in the
+    /// previous game.
+    ///
+    /// A score of `1.0` indicates perfect reciprocity, as when both players play
+    /// [tit-for-tat](crate::Strategy::lagged_mirror), while a score of `0.0` indicates that
+    /// neither player ever reciprocated. Returns `0.0` if fewer than two games are retained in
+    /// the history, since there is nothing to reciprocate.
+    ///
+    /// For a bounded history, only the retained games are considered.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let mut history: History<Dilemma, 2> = History::empty();
+    /// for (a, b) in [('C', 'D'), ('D', 'D'), ('D', 'C'), ('C', 'C')] {
+    ///     history.add(SimultaneousOutcome::new(Profile::new([a, b]), Payoff::zeros()));
+    /// }
+    ///
+    /// // Player 0 always reciprocates, while player 1 never does.
+    /// assert_eq!(history.reciprocity_score(), 0.5);
+    /// ```
+    pub fn reciprocity_score(&self) -> f64 {
+        let profiles: Vec<&Profile<M, 2>> = self.profiles().collect();
+        if profiles.len() < 2 {
+            return 0.0;
+        }
+        let reciprocated: usize = profiles
+            .windows(2)
+            .map(|pair| {
+                PlayerIndex::<2>::all()
+                    .filter(|&player| pair[1][player] == pair[0][player.next()])
+                    .count()
+            })
+            .sum();
+        reciprocated as f64 / (2 * (profiles.len() - 1)) as f64
+    }
 }
 
 impl<M, U, G, const P: usize> History<G, P>
@@ -84,7 +514,7 @@ where
     G: Game<P, Move = M, Utility = U, Outcome = SequentialOutcome<M, U, P>>,
 {
     /// Get an iterator over the transcripts of previously played games.
-    pub fn transcripts(&self) -> Past<&Transcript<G::Move, P>> {
+    pub fn transcripts(&self) -> Past<'_, &Transcript<G::Move, P>> {
         Past::from_iter(
             self.outcomes.len(),
             self.outcomes().map(|outcome| outcome.transcript()),
@@ -95,15 +525,17 @@ where
 impl<G: Game<P>, const P: usize> Default for History<G, P> {
     fn default() -> Self {
         History {
-            outcomes: Vec::new(),
+            outcomes: VecDeque::new(),
             score: Payoff::zeros(),
             summary: Summary::empty(),
+            window: None,
+            num_dropped: 0,
         }
     }
 }
 
 impl<G: Game<P>, const P: usize> Record<G::Move, P> for History<G, P> {
-    fn plies(&self) -> Plies<G::Move, P> {
+    fn plies(&self) -> Plies<'_, G::Move, P> {
         Past::from_iter(
             self.outcomes.len(),
             self.outcomes
@@ -135,6 +567,8 @@ impl<G: Game<P>, const P: usize> Clone for History<G, P> {
             outcomes: self.outcomes.clone(),
             score: self.score,
             summary: self.summary,
+            window: self.window,
+            num_dropped: self.num_dropped,
         }
     }
 }
@@ -144,13 +578,17 @@ impl<G: Game<P>, const P: usize> fmt::Debug for History<G, P> {
         fmt.debug_struct("History")
             .field("outcomes", &self.outcomes)
             .field("score", &self.score)
+            .field("window", &self.window)
+            .field("num_dropped", &self.num_dropped)
             .finish()
     }
 }
 
 impl<G: Game<P>, const P: usize> PartialEq for History<G, P> {
     fn eq(&self, other: &Self) -> bool {
-        self.outcomes == other.outcomes && self.score == other.score
+        self.outcomes == other.outcomes
+            && self.score == other.score
+            && self.num_dropped == other.num_dropped
     }
 }
 
@@ -162,5 +600,234 @@ where
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.outcomes.hash(state);
         self.score.hash(state);
+        self.num_dropped.hash(state);
+    }
+}
+
+/// Render a Markdown table with the given header and rows, padding each column to the width of
+/// its widest cell. Cells in the columns for which `align_right` returns true are right-aligned,
+/// and all other cells are left-aligned.
+///
+/// Widths are measured in characters rather than bytes, so that non-ASCII cells are aligned.
+pub(crate) fn markdown_table(
+    header: &[String],
+    rows: &[Vec<String>],
+    align_right: impl Fn(usize) -> bool,
+) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_row = |row: &[String]| {
+        let cells = row.iter().enumerate().map(|(col, cell)| {
+            if align_right(col) {
+                format!("{:>width$}", cell, width = widths[col])
+            } else {
+                format!("{:<width$}", cell, width = widths[col])
+            }
+        });
+        format!("| {} |\n", cells.collect::<Vec<_>>().join(" | "))
+    };
+
+    let mut table = format_row(header);
+    let separators: Vec<String> = widths.iter().map(|w| "-".repeat(w + 2)).collect();
+    table.push_str(&format!("|{}|\n", separators.join("|")));
+    for row in rows {
+        table.push_str(&format_row(row));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{for2, Matchup, Normal, Player, Repeated, SimultaneousOutcome, Strategy};
+    use std::sync::Arc;
+    use test_log::test;
+
+    type Dilemma = Normal<char, i32, 2>;
+
+    fn history_from(moves: &[(char, char)], window: Option<usize>) -> History<Dilemma, 2> {
+        let mut history = match window {
+            Some(w) => History::with_window(w),
+            None => History::empty(),
+        };
+        for &(a, b) in moves {
+            history.add(SimultaneousOutcome::new(
+                Profile::new([a, b]),
+                Payoff::zeros(),
+            ));
+        }
+        history
+    }
+
+    #[test]
+    fn cooperation_streaks() {
+        let moves = [
+            ('C', 'C'),
+            ('C', 'D'),
+            ('D', 'C'),
+            ('C', 'C'),
+            ('C', 'C'),
+            ('C', 'D'),
+            ('C', 'C'),
+            ('D', 'C'),
+            ('C', 'C'),
+        ];
+        let cooperate = |m: &char| *m == 'C';
+
+        let history = history_from(&moves, None);
+        assert_eq!(history.longest_move_streak(for2::P0, cooperate), 4);
+        assert_eq!(history.longest_move_streak(for2::P1, cooperate), 3);
+        assert_eq!(history.current_move_streak(for2::P0, cooperate), 1);
+        assert_eq!(history.current_move_streak(for2::P1, cooperate), 3);
+        assert_eq!(history.move_count(for2::P0, cooperate), 7);
+        assert_eq!(history.move_count(for2::P1, cooperate), 7);
+
+        let bounded = history_from(&moves, Some(3));
+        assert_eq!(bounded.longest_move_streak(for2::P0, cooperate), 1);
+        assert_eq!(bounded.move_count(for2::P0, cooperate), 2);
+
+        let empty = history_from(&[], None);
+        assert_eq!(empty.longest_move_streak(for2::P0, cooperate), 0);
+        assert_eq!(empty.current_move_streak(for2::P0, cooperate), 0);
+    }
+
+    #[test]
+    fn reciprocity_of_tit_for_tat() {
+        let pd: Dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 20);
+        let defect = |m: &char| *m == 'D';
+
+        let tit_for_tat = || {
+            Player::new("Tit-for-Tat".to_string(), || {
+                Strategy::lagged_mirror(1, 'C')
+            })
+        };
+        let defector = || Player::new("Defector".to_string(), || Strategy::pure('D'));
+
+        let history = rpd
+            .play(&Matchup::from_players([tit_for_tat(), tit_for_tat()]))
+            .unwrap();
+        assert_eq!(history.reciprocity_score(), 1.0);
+        assert_eq!(history.first_defection_round(for2::P0, defect), None);
+        assert_eq!(history.first_defection_round(for2::P1, defect), None);
+
+        let history = rpd
+            .play(&Matchup::from_players([tit_for_tat(), defector()]))
+            .unwrap();
+        assert!(history.reciprocity_score() < 1.0);
+        assert_eq!(history.first_defection_round(for2::P0, defect), Some(1));
+        assert_eq!(history.first_defection_round(for2::P1, defect), Some(0));
+
+        let bounded = history_from(&[('C', 'C'), ('C', 'C'), ('C', 'D')], Some(2));
+        assert_eq!(bounded.first_defection_round(for2::P1, defect), Some(2));
+        assert_eq!(history_from(&[('C', 'D')], None).reciprocity_score(), 0.0);
+    }
+
+    #[test]
+    fn opponent_model_predicts_periodic_opponent() {
+        // Player 1 cycles through C, C, D regardless of player 0.
+        let moves: Vec<(char, char)> = "CDDCCDCDCCCD".chars().zip("CCD".chars().cycle()).collect();
+        let history = history_from(&moves, None);
+
+        // One game of context is not enough to tell which C of the cycle was played last.
+        let short = history.conditional_move_frequencies(for2::P1, 1);
+        let after_cc = short[&vec![Profile::new(['C', 'C'])]].clone();
+        assert_eq!(after_cc.len(), 2);
+
+        // With two games of context, the model predicts every move perfectly.
+        let model = history.conditional_move_frequencies(for2::P1, 2);
+        assert_eq!(
+            model
+                .values()
+                .map(|counts| counts.values().sum::<usize>())
+                .sum::<usize>(),
+            moves.len() - 2
+        );
+        for end in 2..moves.len() {
+            let context: Vec<_> = moves[end - 2..end]
+                .iter()
+                .map(|&(a, b)| Profile::new([a, b]))
+                .collect();
+            let counts = &model[&context];
+            assert_eq!(counts.len(), 1);
+            assert!(counts.contains_key(&moves[end].1));
+        }
+
+        let marginal = history.conditional_move_frequencies(for2::P1, 0);
+        assert_eq!(marginal[&vec![]], HashMap::from([('C', 8), ('D', 4)]));
+        assert!(history
+            .conditional_move_frequencies(for2::P1, moves.len())
+            .is_empty());
+    }
+
+    #[test]
+    fn average_payoff_is_independent_of_length() {
+        let pd: Dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let cooperator = || Player::new("Cooperator".to_string(), || Strategy::pure('C'));
+        let play = |repetitions| {
+            Repeated::new(Arc::new(pd.clone()), repetitions)
+                .play(&Matchup::from_players([cooperator(), cooperator()]))
+                .unwrap()
+        };
+
+        let short = play(100);
+        let long = play(200);
+        assert_ne!(short.score(), long.score());
+        assert_eq!(short.average_payoff(), long.average_payoff());
+        assert_eq!(long.average_payoff(), Payoff::from([2.0, 2.0]));
+
+        let empty: History<Dilemma, 2> = History::empty();
+        assert_eq!(empty.average_payoff(), Payoff::zeros());
+    }
+
+    #[test]
+    fn table_string_for_three_players() {
+        let mut history: History<Normal<char, i32, 3>, 3> = History::with_window(2);
+        for (moves, payoff) in [
+            (['A', 'B', 'C'], [1, 2, 3]),
+            (['B', 'B', 'A'], [10, 0, -1]),
+            (['C', 'A', 'A'], [0, 100, 1]),
+        ] {
+            history.add(SimultaneousOutcome::new(
+                Profile::new(moves),
+                Payoff::from(payoff),
+            ));
+        }
+
+        assert_eq!(
+            history.to_table_string(),
+            "\
+| Round | P0 | P1 | P2 | P0 score | P1 score | P2 score |
+|-------|----|----|----|----------|----------|----------|
+|     2 | B  | B  | A  |       11 |        2 |        2 |
+|     3 | C  | A  | A  |       11 |      102 |        3 |
+"
+        );
+    }
+
+    #[test]
+    fn payoff_variance_steady_vs_volatile() {
+        let mut history: History<Dilemma, 2> = History::empty();
+        for i in 0..10 {
+            let volatile = if i % 2 == 0 { 0 } else { 10 };
+            history.add(SimultaneousOutcome::new(
+                Profile::new(['C', 'C']),
+                Payoff::from([5, volatile]),
+            ));
+        }
+
+        // Both players have a mean utility of 5.
+        assert_eq!(history.payoff_variance(for2::P0), 0.0);
+        assert_eq!(history.payoff_variance(for2::P1), 25.0);
+
+        let empty: History<Dilemma, 2> = History::empty();
+        assert_eq!(empty.payoff_variance(for2::P0), 0.0);
     }
 }
@@ -24,6 +24,10 @@
 //!   possibly with moves of chance interspersed.
 //! - `StateBased` (coming soon): Games that revolve around manipulating a shared state.
 //! - [`Repeated`]: Games where another game is played repeatedly a given number of times.
+//! - [`AlternatingRepeated`]: Like [`Repeated`], except that within each repetition the players
+//!   move in turn rather than simultaneously.
+//! - [`CommonKnowledge`]: Games where all players can observe a shared payload, such as a public
+//!   signal.
 //!
 //! Each of these game types represents a class of games that work in a similar way, and most
 //! specific games you may want to define can be defined using the constructors on these types.
@@ -183,14 +187,20 @@
 //! [repeated-game]: https://en.wikipedia.org/wiki/Repeated_game
 //! [games-crate]: https://crates.io/crates/t4t-games
 
+pub(crate) mod alternating;
+pub(crate) mod best_response;
+pub(crate) mod common;
+pub(crate) mod compiled;
 pub(crate) mod distribution;
 pub(crate) mod dominated;
+pub(crate) mod equilibrium;
 pub(crate) mod error;
 pub(crate) mod finite;
 // pub(crate) mod extensive;
 pub(crate) mod game;
 pub(crate) mod history;
 pub(crate) mod matchup;
+pub(crate) mod mixed;
 pub(crate) mod moves;
 pub(crate) mod normal;
 pub(crate) mod outcome;
@@ -201,6 +211,7 @@ pub(crate) mod player;
 pub(crate) mod ply;
 pub(crate) mod possible_profiles;
 pub(crate) mod profile;
+pub(crate) mod random;
 pub(crate) mod record;
 pub(crate) mod repeated;
 pub(crate) mod score;
@@ -208,17 +219,24 @@ pub(crate) mod simultaneous;
 pub(crate) mod strategy;
 pub(crate) mod summary;
 pub(crate) mod tournament;
+pub(crate) mod trace;
 pub(crate) mod transcript;
 pub(crate) mod tree;
 
+pub use alternating::*;
+pub use best_response::*;
+pub use common::*;
+pub use compiled::*;
 pub use distribution::*;
 pub use dominated::*;
+pub use equilibrium::*;
 pub use error::*;
 pub use finite::*;
 // pub use extensive::*;
 pub use game::*;
 pub use history::*;
 pub use matchup::*;
+pub use mixed::*;
 pub use moves::*;
 pub use normal::*;
 pub use outcome::*;
@@ -229,6 +247,7 @@ pub use player::*;
 pub use ply::*;
 pub use possible_profiles::*;
 pub use profile::*;
+pub use random::*;
 pub use record::*;
 pub use repeated::*;
 pub use score::*;
@@ -236,5 +255,6 @@ pub use simultaneous::*;
 pub use strategy::*;
 pub use summary::*;
 pub use tournament::*;
+pub use trace::*;
 pub use transcript::*;
 pub use tree::*;
@@ -1,4 +1,4 @@
-use crate::{Game, PerPlayer, Player, Strategy};
+use crate::{Game, MatchupError, PerPlayer, Player, Strategy};
 use std::sync::Arc;
 
 /// A collection of players ready to play a game.
@@ -18,6 +18,27 @@ impl<G: Game<P>, const P: usize> Matchup<G, P> {
         Matchup::new(PerPlayer::new(players.map(Arc::new)))
     }
 
+    /// Construct a new matchup in which every role is filled by the same player.
+    ///
+    /// Each role still gets its own fresh strategy when the game is played, so this is useful for
+    /// studying how a strategy behaves against a copy of itself.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    ///
+    /// let matchup = Matchup::self_play(nice);
+    /// assert_eq!(matchup.names(), PerPlayer::new(["Nice".to_string(), "Nice".to_string()]));
+    /// assert_eq!(pd.play(&matchup).unwrap().payoff(), &Payoff::from([2, 2]));
+    /// ```
+    pub fn self_play(player: Player<G, P>) -> Self {
+        let player = Arc::new(player);
+        Matchup::new(PerPlayer::generate(|_| Arc::clone(&player)))
+    }
+
     /// Get the players in this matchup.
     pub fn players(&self) -> &PerPlayer<Arc<Player<G, P>>, P> {
         &self.players
@@ -28,8 +49,120 @@ impl<G: Game<P>, const P: usize> Matchup<G, P> {
         self.players.map(|player| player.name().to_owned())
     }
 
+    /// Check that this matchup is consistent with the given game, that is, that it has exactly
+    /// as many players as the game is [for](Game::num_players).
+    ///
+    /// The type system ensures that a matchup's players were built for the game's type, but a
+    /// game may report a different number of players at run time than its type parameter
+    /// indicates. Every way of playing a game, including [tournaments](crate::Tournament),
+    /// validates the matchup before the first move and fails with an
+    /// [`InvalidMatchup`](crate::ErrorKind::InvalidMatchup) error if it is inconsistent, rather
+    /// than failing later while the game is played.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    ///
+    /// assert_eq!(Matchup::self_play(nice).validate(&pd), Ok(()));
+    /// ```
+    pub fn validate(&self, game: &G) -> Result<(), MatchupError> {
+        let (expected, found) = (game.num_players(), self.players.num_players());
+        if expected == found {
+            Ok(())
+        } else {
+            Err(MatchupError::WrongNumberOfPlayers { expected, found })
+        }
+    }
+
     /// Get fresh copies of each player's strategy for playing the game.
     pub fn strategies(&self) -> PerPlayer<Strategy<G::View, G::Move, P>, P> {
         self.players.map(|player| player.new_strategy())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ErrorKind, Game, GameTree, Normal, Payoff, PlayerIndex, Profile, Repeated,
+        SimultaneousOutcome,
+    };
+    use test_log::test;
+
+    /// A two-player game that incorrectly reports being for three players.
+    #[derive(Clone)]
+    struct Misreported(Normal<char, i32, 2>);
+
+    impl Game<2> for Misreported {
+        type Move = char;
+        type Utility = i32;
+        type Outcome = SimultaneousOutcome<char, i32, 2>;
+        type State = ();
+        type View = ();
+
+        fn into_game_tree(self) -> GameTree<(), char, i32, Self::Outcome, 2> {
+            self.0.into_game_tree()
+        }
+
+        fn state_view(&self, _state: &(), _player: PlayerIndex<2>) {}
+
+        fn num_players(&self) -> usize {
+            3
+        }
+    }
+
+    #[test]
+    fn mismatched_matchup_is_invalid() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let nice = || Player::new("Nice".to_string(), || Strategy::pure('C'));
+
+        let matchup = Matchup::from_players([nice(), nice()]);
+        assert_eq!(matchup.validate(&pd), Ok(()));
+
+        let misreported = Misreported(pd);
+        let nice = || Player::new("Nice".to_string(), || Strategy::pure('C'));
+        let matchup: Matchup<Misreported, 2> = Matchup::from_players([nice(), nice()]);
+        let err = matchup.validate(&misreported).unwrap_err();
+        assert_eq!(
+            err,
+            MatchupError::WrongNumberOfPlayers {
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "the game is for 3 players, but the matchup has 2 players"
+        );
+
+        // Playing the game fails before any player moves.
+        let err = misreported.play(&matchup).unwrap_err();
+        assert_eq!(
+            err.kind,
+            ErrorKind::InvalidMatchup(MatchupError::WrongNumberOfPlayers {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn tit_for_tat_self_play_cooperates() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 20);
+
+        let tit_for_tat = Player::new("Tit-for-Tat".to_string(), || {
+            Strategy::lagged_mirror(1, 'C')
+        });
+        let history = rpd.play(&Matchup::self_play(tit_for_tat)).unwrap();
+
+        assert_eq!(history.num_games(), 20);
+        assert!(history
+            .profiles()
+            .all(|profile| *profile == Profile::new(['C', 'C'])));
+        assert_eq!(history.score(), &Payoff::from([40, 40]));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Distribution, Move, PerPlayer, PlayerIndex, Profile};
+
+/// A [mixed strategy](https://en.wikipedia.org/wiki/Strategy_(game_theory)#Mixed_strategy)
+/// profile for a simultaneous game: a probability distribution over moves for each player.
+///
+/// Each player's mixed strategy is represented as a list of moves paired with the probability of
+/// playing that move. Moves not in the list are played with probability zero.
+///
+/// # Examples
+/// ```
+/// use t4t::*;
+///
+/// let mixed = MixedProfile::new(PerPlayer::new([
+///     vec![('A', 0.25), ('B', 0.75)],
+///     vec![('C', 1.0)],
+/// ]));
+///
+/// assert_eq!(mixed.probability(for2::P0, 'B'), 0.75);
+/// assert_eq!(mixed.probability(for2::P1, 'D'), 0.0);
+/// assert_eq!(mixed.support(for2::P0).collect::<Vec<_>>(), vec!['A', 'B']);
+/// assert!(!mixed.is_pure());
+///
+/// let pure = MixedProfile::pure(Profile::new(['A', 'C']));
+/// assert!(pure.is_pure());
+/// assert_eq!(pure.to_pure(), Some(Profile::new(['A', 'C'])));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct MixedProfile<M, const P: usize> {
+    strategies: PerPlayer<Vec<(M, f64)>, P>,
+}
+
+impl<M: Move, const P: usize> MixedProfile<M, P> {
+    /// Construct a mixed profile from a list of moves and their probabilities for each player.
+    ///
+    /// The probabilities for each player are expected to be non-negative and sum to one, but this
+    /// is not checked.
+    pub fn new(strategies: PerPlayer<Vec<(M, f64)>, P>) -> Self {
+        MixedProfile { strategies }
+    }
+
+    /// Construct a mixed profile in which each player plays the corresponding move in the given
+    /// pure profile with probability one.
+    pub fn pure(profile: Profile<M, P>) -> Self {
+        MixedProfile::new(PerPlayer::generate(|player| vec![(profile[player], 1.0)]))
+    }
+
+    /// Get the moves and associated probabilities for the given player's mixed strategy.
+    pub fn for_player(&self, player: PlayerIndex<P>) -> &[(M, f64)] {
+        &self.strategies[player]
+    }
+
+    /// Get the probability that the given player plays the given move.
+    pub fn probability(&self, player: PlayerIndex<P>, the_move: M) -> f64 {
+        self.strategies[player]
+            .iter()
+            .filter(|(m, _)| *m == the_move)
+            .map(|(_, prob)| prob)
+            .sum()
+    }
+
+    /// Get an iterator over the moves the given player plays with nonzero probability.
+    pub fn support(&self, player: PlayerIndex<P>) -> impl Iterator<Item = M> + '_ {
+        self.strategies[player]
+            .iter()
+            .filter(|(_, prob)| *prob > 0.0)
+            .map(|(m, _)| *m)
+    }
+
+    /// Is every player's strategy pure? That is, does each player play a single move with
+    /// nonzero probability?
+    pub fn is_pure(&self) -> bool {
+        PlayerIndex::all().all(|player| self.support(player).count() == 1)
+    }
+
+    /// Get the corresponding pure profile, if every player's strategy is [pure](Self::is_pure).
+    pub fn to_pure(&self) -> Option<Profile<M, P>> {
+        if self.is_pure() {
+            Some(Profile::from_per_player(PerPlayer::generate(|player| {
+                self.support(player).next().unwrap()
+            })))
+        } else {
+            None
+        }
+    }
+
+    /// Get the given player's mixed strategy as a distribution that can be sampled from.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the player's probabilities do not form a valid
+    /// distribution, e.g. if they are all zero.
+    pub fn to_distribution(&self, player: PlayerIndex<P>) -> Option<Distribution<M>> {
+        Distribution::new(self.strategies[player].clone())
+    }
+}
@@ -1,6 +1,7 @@
 use dyn_clone::DynClone;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::sync::Arc;
 
 /// A trait that collects the trait requirements of moves.
 ///
@@ -27,8 +28,43 @@ impl<'a, M: Move> PossibleMoves<'a, M> {
     }
 
     /// Construct a new possible move iterator from a vector of moves.
+    ///
+    /// The moves are stored in a reference-counted slice, so cloning the iterator does not copy
+    /// the moves.
     pub fn from_vec(moves: Vec<M>) -> Self {
-        PossibleMoves::new(moves.into_iter())
+        PossibleMoves::new(SharedMoves {
+            moves: moves.into(),
+            next: 0,
+        })
+    }
+
+    /// Construct a new possible move iterator that borrows a slice of moves.
+    ///
+    /// Cloning the iterator does not copy the moves.
+    pub fn from_slice(moves: &'a [M]) -> Self {
+        PossibleMoves::new(moves.iter().copied())
+    }
+}
+
+/// An iterator over a reference-counted slice of moves, which can be cloned without copying the
+/// moves.
+#[derive(Clone)]
+struct SharedMoves<M> {
+    moves: Arc<[M]>,
+    next: usize,
+}
+
+impl<M: Copy> Iterator for SharedMoves<M> {
+    type Item = M;
+    fn next(&mut self) -> Option<M> {
+        let the_move = self.moves.get(self.next).copied();
+        self.next += 1;
+        the_move
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.moves.len().saturating_sub(self.next);
+        (remaining, Some(remaining))
     }
 }
 
@@ -1,14 +1,19 @@
-use num::Zero;
+use itertools::Itertools;
+use num::{ToPrimitive, Zero};
 use rayon::prelude::*;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
 use std::iter::Iterator;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
+use crate::history::markdown_table;
 use crate::{
-    Dominated, ErrorKind, FiniteGame, Game, GameTree, Move, Outcome, Payoff, PerPlayer,
-    PlayerIndex, PossibleMoves, PossibleOutcomes, PossibleProfiles, Profile, Record, Simultaneous,
-    SimultaneousOutcome, Utility,
+    for2, BestResponseEdge, BestResponseGraph, CsvError, Distribution, Dominated,
+    EquilibriumReport, ErrorKind, FiniteGame, Game, GameTree, MixedProfile, Move, Outcome, Payoff,
+    PerPlayer, PlayerIndex, PossibleMoves, PossibleOutcomes, PossibleProfiles, Profile, Record,
+    Simultaneous, SimultaneousOutcome, Utility,
 };
 
 /// A game represented in [normal form](https://en.wikipedia.org/wiki/Normal-form_game).
@@ -74,14 +79,46 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
     /// This constructor (and [from_utility_fns](Normal::from_utility_fns)) enables representing
     /// large normal-form games where it would be intractable to represent the payoff map/table
     /// directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any player's moves contain a duplicate. Use
+    /// [`try_from_payoff_fn`](Normal::try_from_payoff_fn) to get `None` instead.
     pub fn from_payoff_fn(
         moves: PerPlayer<Vec<M>, P>,
         payoff_fn: impl Fn(Profile<M, P>) -> Payoff<U, P> + Send + Sync + 'static,
     ) -> Self {
-        Normal {
+        Self::try_from_payoff_fn(moves, payoff_fn)
+            .expect("Normal::from_payoff_fn: a player's moves contain a duplicate")
+    }
+
+    /// Construct a normal-form game given the moves available to each player and a payoff
+    /// function, as with [`from_payoff_fn`](Normal::from_payoff_fn).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if any player's moves contain a duplicate, since the
+    /// duplicated move would yield the same profiles more than once.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let moves = PerPlayer::new([vec!['C', 'D', 'C'], vec!['C', 'D']]);
+    /// let g = Normal::try_from_payoff_fn(moves, |_| Payoff::from([0, 0]));
+    /// assert!(g.is_none());
+    /// ```
+    pub fn try_from_payoff_fn(
+        moves: PerPlayer<Vec<M>, P>,
+        payoff_fn: impl Fn(Profile<M, P>) -> Payoff<U, P> + Send + Sync + 'static,
+    ) -> Option<Self> {
+        if Self::has_duplicate_moves("try_from_payoff_fn", &moves) {
+            return None;
+        }
+        Some(Normal {
             moves,
             payoff_fn: Arc::new(payoff_fn),
-        }
+        })
     }
 
     /// Construct a normal-form game given the moves available to each player and a utility
@@ -90,18 +127,83 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
     /// This constructor (and [from_payoff_fn](Normal::from_payoff_fn)) enables representing
     /// large normal-form games where it would be intractable to represent the payoff map/table
     /// directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any player's moves contain a duplicate.
     pub fn from_utility_fns(
         moves: PerPlayer<Vec<M>, P>,
         util_fns: PerPlayer<impl Fn(M) -> U + Send + Sync + 'static, P>,
     ) -> Self {
         let payoff_fn = move |profile: Profile<M, P>| {
-            Payoff::new(PerPlayer::generate(|player| {
-                util_fns[player](profile[player])
-            }))
+            Payoff::from_fn(|player| util_fns[player](profile[player]))
         };
         Self::from_payoff_fn(moves, payoff_fn)
     }
 
+    /// Construct a normal-form game given the moves available to each player and a function that
+    /// yields the game's payoff given the index of the move played by each player.
+    ///
+    /// Each move's index is its position in the corresponding player's list of moves. This is
+    /// convenient for large games defined mathematically in terms of move indices, since the
+    /// payoff function need not map moves to their indices itself.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if any player's moves contain a duplicate, since the
+    /// duplicated move would have more than one index.
+    ///
+    /// The resulting game will log an error and return a [zero payoff](Payoff::zeros) for any
+    /// profile containing a move not in the corresponding player's list of moves.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // Each player wins if the sum of the move indices modulo 3 is their own index.
+    /// let moves = PerPlayer::new([vec!['a', 'b', 'c'], vec!['x', 'y', 'z']]);
+    /// let g = Normal::from_index_payoff_fn(moves, |indexes: PerPlayer<usize, 2>| {
+    ///     let sum = indexes.iter().sum::<usize>() % 3;
+    ///     Payoff::from_fn(|player| if sum == player.as_usize() { 1 } else { 0 })
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!(g.payoff(Profile::new(['a', 'x'])), Payoff::from([1, 0]));
+    /// assert_eq!(g.payoff(Profile::new(['a', 'y'])), Payoff::from([0, 1]));
+    /// assert_eq!(g.payoff(Profile::new(['c', 'z'])), Payoff::from([0, 1]));
+    /// assert_eq!(g.payoff(Profile::new(['b', 'y'])), Payoff::from([0, 0]));
+    /// ```
+    pub fn from_index_payoff_fn(
+        moves: PerPlayer<Vec<M>, P>,
+        index_payoff_fn: impl Fn(PerPlayer<usize, P>) -> Payoff<U, P> + Send + Sync + 'static,
+    ) -> Option<Self> {
+        if Self::has_duplicate_moves("from_index_payoff_fn", &moves) {
+            return None;
+        }
+        let indexes: PerPlayer<HashMap<M, usize>, P> = moves.map(|player_moves| {
+            player_moves
+                .into_iter()
+                .enumerate()
+                .map(|(index, the_move)| (the_move, index))
+                .collect()
+        });
+        let payoff_fn = move |profile: Profile<M, P>| match PerPlayer::generate(|player| {
+            indexes[player].get(&profile[player]).copied()
+        })
+        .all_some()
+        {
+            Some(indexes) => index_payoff_fn(indexes),
+            None => {
+                log::error!(
+                    "Normal::from_index_payoff_fn: invalid profile: {:?}",
+                    profile
+                );
+                Payoff::zeros()
+            }
+        };
+        Some(Self::from_payoff_fn(moves, payoff_fn))
+    }
+
     /// Construct a normal-form game given the moves available to each player and a map containing
     /// the payoff associated with each valid profile.
     ///
@@ -109,10 +211,61 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
     ///
     /// The resulting game will log an error and return a [zero payoff](Payoff::zeros) for
     /// any profile not contained in the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any player's moves contain a duplicate. Use
+    /// [`try_from_payoff_map`](Normal::try_from_payoff_map) to get `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use t4t::*;
+    ///
+    /// let payoffs = HashMap::from([
+    ///     (Profile::new(['C', 'C']), Payoff::from([2, 2])),
+    ///     (Profile::new(['C', 'D']), Payoff::from([0, 3])),
+    /// ]);
+    /// let g = Normal::from_payoff_map(PerPlayer::new([vec!['C'], vec!['C', 'D']]), payoffs);
+    ///
+    /// assert_eq!(g.possible_profiles().count(), 2);
+    /// assert_eq!(g.payoff(Profile::new(['C', 'D'])), Payoff::from([0, 3]));
+    /// ```
     pub fn from_payoff_map(
         moves: PerPlayer<Vec<M>, P>,
         payoff_map: HashMap<Profile<M, P>, Payoff<U, P>>,
     ) -> Self {
+        Self::try_from_payoff_map(moves, payoff_map)
+            .expect("Normal::from_payoff_map: a player's moves contain a duplicate")
+    }
+
+    /// Construct a normal-form game given the moves available to each player and a map containing
+    /// the payoff associated with each valid profile, as with
+    /// [`from_payoff_map`](Normal::from_payoff_map).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if any player's moves contain a duplicate.
+    ///
+    /// The resulting game will log an error and return a [zero payoff](Payoff::zeros) for
+    /// any profile not contained in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use t4t::*;
+    ///
+    /// let payoffs = HashMap::from([(Profile::new(['C', 'C']), Payoff::from([2, 2]))]);
+    /// let moves = PerPlayer::new([vec!['C', 'C'], vec!['C']]);
+    /// assert!(Normal::try_from_payoff_map(moves, payoffs).is_none());
+    /// ```
+    pub fn try_from_payoff_map(
+        moves: PerPlayer<Vec<M>, P>,
+        payoff_map: HashMap<Profile<M, P>, Payoff<U, P>>,
+    ) -> Option<Self> {
+        if Self::has_duplicate_moves("try_from_payoff_map", &moves) {
+            return None;
+        }
         let payoff_fn = move |profile| {
             if let Some(payoff) = payoff_map.get(&profile).copied() {
                 payoff
@@ -124,7 +277,79 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
                 Payoff::zeros()
             }
         };
-        Self::from_payoff_fn(moves, payoff_fn)
+        Some(Self::from_payoff_fn(moves, payoff_fn))
+    }
+
+    /// Construct a normal-form game given the moves available to each player and an iterator of
+    /// profiles paired with their associated payoffs.
+    ///
+    /// This is a streaming version of [`from_payoff_map`](Self::from_payoff_map), useful when
+    /// the profile-payoff pairs are generated lazily.
+    ///
+    /// # Errors
+    ///
+    /// If the iterator yields the same profile more than once, logs a warning and uses the last
+    /// payoff associated with that profile.
+    ///
+    /// As with [`from_payoff_map`](Self::from_payoff_map), the resulting game will log an error
+    /// and return a [zero payoff](Payoff::zeros) for any profile not yielded by the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any player's moves contain a duplicate. Use
+    /// [`try_from_payoff_pairs`](Normal::try_from_payoff_pairs) to get `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let moves = PerPlayer::new([vec![0, 1], vec![0, 1]]);
+    /// let g = Normal::from_payoff_pairs(
+    ///     moves.clone(),
+    ///     moves[for2::P0].iter().flat_map(|&a| {
+    ///         moves[for2::P1]
+    ///             .iter()
+    ///             .map(move |&b| (Profile::new([a, b]), Payoff::from([a * b, a + b])))
+    ///     }),
+    /// );
+    ///
+    /// assert_eq!(g.payoff(Profile::new([1, 0])), Payoff::from([0, 1]));
+    /// assert_eq!(g.payoff(Profile::new([1, 1])), Payoff::from([1, 2]));
+    /// ```
+    pub fn from_payoff_pairs(
+        moves: PerPlayer<Vec<M>, P>,
+        pairs: impl Iterator<Item = (Profile<M, P>, Payoff<U, P>)>,
+    ) -> Self {
+        Self::try_from_payoff_pairs(moves, pairs)
+            .expect("Normal::from_payoff_pairs: a player's moves contain a duplicate")
+    }
+
+    /// Construct a normal-form game given the moves available to each player and an iterator of
+    /// profiles paired with their associated payoffs, as with
+    /// [`from_payoff_pairs`](Normal::from_payoff_pairs).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if any player's moves contain a duplicate.
+    pub fn try_from_payoff_pairs(
+        moves: PerPlayer<Vec<M>, P>,
+        pairs: impl Iterator<Item = (Profile<M, P>, Payoff<U, P>)>,
+    ) -> Option<Self> {
+        if Self::has_duplicate_moves("try_from_payoff_pairs", &moves) {
+            return None;
+        }
+        let mut payoff_map = HashMap::new();
+        for (profile, payoff) in pairs {
+            if let Some(previous) = payoff_map.insert(profile, payoff) {
+                log::warn!(
+                    "Normal::from_payoff_pairs: duplicate profile {:?}, replacing payoff {:?} with {:?}",
+                    profile,
+                    previous,
+                    payoff
+                );
+            }
+        }
+        Some(Self::from_payoff_map(moves, payoff_map))
     }
 
     /// Construct a normal-form game given the moves available to each player and a vector of
@@ -139,6 +364,8 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
     /// - If *too many* payoffs are provided, logs a warning and returns a game in which the
     ///   excess payoffs are ignored.
     ///
+    /// Also logs an error and returns `None` if any player's moves contain a duplicate.
+    ///
     /// # Examples
     /// ```
     /// use t4t::*;
@@ -161,6 +388,9 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
         moves: PerPlayer<Vec<M>, P>,
         payoffs: Vec<Payoff<U, P>>,
     ) -> Option<Self> {
+        if Self::has_duplicate_moves("from_payoff_vec", &moves) {
+            return None;
+        }
         let profiles: Vec<Profile<M, P>> =
             PossibleProfiles::from_move_vecs(moves.clone()).collect();
         let num_profiles = profiles.len();
@@ -197,6 +427,23 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
     /// The game is constructed from a list of available moves and a vector of utility values for
     /// player `P0` in [row-major order](https://en.wikipedia.org/wiki/Row-_and_column-major_order).
     ///
+    /// # Errors
+    ///
+    /// This constructor expects the length of the utility vector to be `moves.len()^P`.
+    ///
+    /// - If *too few* utility values are provided, logs an error and returns `None`.
+    /// - If *too many* utility values are provided, logs a warning and returns a game in which
+    ///   the excess values are ignored.
+    ///
+    /// Also logs an error and returns `None` if the list of moves contains a duplicate, since the
+    /// utility values could not be unambiguously assigned to profiles.
+    ///
+    /// ```
+    /// use t4t::*;
+    ///
+    /// assert!(Normal::<char, i32, 2>::symmetric(vec!['C', 'C'], vec![2, 0, 3, 1]).is_none());
+    /// ```
+    ///
     /// # Examples
     ///
     /// The classic [prisoner's dilemma](https://en.wikipedia.org/wiki/Prisoner%27s_dilemma) is an
@@ -266,6 +513,9 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
     /// ```
     #[allow(clippy::needless_range_loop)]
     pub fn symmetric(moves: Vec<M>, utils: Vec<U>) -> Option<Self> {
+        if Self::has_duplicate_moves("symmetric", &PerPlayer::init_with(moves.clone())) {
+            return None;
+        }
         let num_moves = moves.len();
         let size = num_moves.pow(P as u32);
         let num_utils = utils.len();
@@ -349,9 +599,26 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
         ))
     }
 
+    /// Check whether any player's moves contain a duplicate, logging an error attributed to the
+    /// given constructor if so.
+    fn has_duplicate_moves(constructor: &str, moves: &PerPlayer<Vec<M>, P>) -> bool {
+        for player in PlayerIndex::all() {
+            if let Some(duplicate) = moves[player].iter().duplicates().next() {
+                log::error!(
+                    "Normal::{}: duplicate move for player {}: {:?}",
+                    constructor,
+                    player,
+                    duplicate
+                );
+                return true;
+            }
+        }
+        false
+    }
+
     /// Get an iterator over the available moves for the given player.
     pub fn possible_moves_for_player(&self, player: PlayerIndex<P>) -> PossibleMoves<'_, M> {
-        PossibleMoves::from_vec(self.moves[player].clone())
+        PossibleMoves::from_slice(&self.moves[player])
     }
 
     /// Get iterators for the moves available to each player.
@@ -405,578 +672,3741 @@ impl<M: Move, U: Utility, const P: usize> Normal<M, U, P> {
         PossibleOutcomes::new(self.possible_profiles(), self.payoff_fn.clone())
     }
 
-    /// Is this game zero-sum? In a zero-sum game, the utility values of each payoff sum to zero.
+    /// Get the probability distribution over the utility the given player receives when playing
+    /// `my_move` against other players who each play according to a mixed strategy.
+    ///
+    /// The `others` argument contains a distribution over moves for each player. The entry for
+    /// `player` is ignored. Outcomes yielding the same utility are combined into a single element
+    /// of the resulting distribution, whose elements are ordered by first occurrence.
+    ///
+    /// This supports risk-sensitive analysis of a move, such as its worst-case utility or the
+    /// variance of its utility, rather than only its expected utility.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `my_move` is not a valid move for `player`.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let rps: Normal<_, _, 2> = Normal::symmetric(
-    ///     vec!["Rock", "Paper", "Scissors"],
-    ///     vec![0, -1, 1, 1, 0, -1, -1, 1, 0],
-    /// ).unwrap();
+    /// // P0's utility is the number of other players who play 'X'.
+    /// let g = Normal::from_payoff_fn(
+    ///     PerPlayer::init_with(vec!['X', 'Y']),
+    ///     |profile: Profile<char, 3>| {
+    ///         let xs = [profile[for3::P1], profile[for3::P2]]
+    ///             .into_iter()
+    ///             .filter(|m| *m == 'X')
+    ///             .count();
+    ///         Payoff::from([xs as i32, 0, 0])
+    ///     },
+    /// );
     ///
-    /// assert!(rps.is_zero_sum());
+    /// let coin = Distribution::flat(vec!['X', 'Y']).unwrap();
+    /// let others = PerPlayer::init_with(coin);
     ///
-    /// let pd: Normal<_, _, 2> = Normal::symmetric(
-    ///     vec!["Cooperate", "Defect"],
-    ///     vec![2, 0, 3, 1],
-    /// ).unwrap();
+    /// let dist = g.payoff_distribution(for3::P0, 'X', &others).unwrap();
+    /// assert_eq!(
+    ///     dist.probabilities().collect::<Vec<_>>(),
+    ///     vec![(&2, 0.25), (&1, 0.5), (&0, 0.25)],
+    /// );
     ///
-    /// assert!(!pd.is_zero_sum());
+    /// assert!(g.payoff_distribution(for3::P0, 'Z', &others).is_none());
     /// ```
-    pub fn is_zero_sum(&self) -> bool {
-        self.possible_outcomes()
-            .all(|outcome| outcome.payoff().is_zero_sum())
+    pub fn payoff_distribution(
+        &self,
+        player: PlayerIndex<P>,
+        my_move: M,
+        others: &PerPlayer<Distribution<M>, P>,
+    ) -> Option<Distribution<U>> {
+        if !self.is_valid_move_for_player(player, my_move) {
+            log::error!(
+                "Normal::payoff_distribution: invalid move for player {}: {:?}",
+                player,
+                my_move,
+            );
+            return None;
+        }
+
+        let per_player = PerPlayer::generate(|p| {
+            if p == player {
+                Distribution::singleton(my_move)
+            } else {
+                others[p].clone()
+            }
+        });
+        let profiles = Distribution::product(per_player)?;
+
+        let mut weighted_utils: Vec<(U, f64)> = Vec::new();
+        for (profile, prob) in profiles.probabilities() {
+            if prob == 0.0 {
+                continue;
+            }
+            let util = self.payoff(*profile)[player];
+            match weighted_utils.iter_mut().find(|(u, _)| *u == util) {
+                Some((_, total)) => *total += prob,
+                None => weighted_utils.push((util, prob)),
+            }
+        }
+        Distribution::new(weighted_utils)
     }
 
-    /// Return a move that unilaterally improves the given player's utility, if such a move exists.
+    /// Construct a new game whose payoffs are the
+    /// [convex combination](https://en.wikipedia.org/wiki/Convex_combination)
+    /// `alpha * self + (1 - alpha) * other` of the payoffs of this game and another game.
     ///
-    /// A unilateral improvement assumes that all other player's moves will be unchanged.
+    /// This is useful for studying parameterized families of games, for example, by sweeping
+    /// `alpha` from `0` to `1` and observing how the equilibria change.
     ///
-    /// If more than one move would unilaterally improve the player's utility, then the move that
-    /// improves it by the *most* is returned.
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the two games do not have identical move sets, or if
+    /// `alpha` is not in the range `[0, 1]`.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
-    /// enum RPS { Rock, Paper, Scissors }
-    ///
-    /// let rps = Normal::symmetric(
-    ///     vec![RPS::Rock, RPS::Paper, RPS::Scissors],
-    ///     vec![ 0, -1,  1,
-    ///           1,  0, -1,
-    ///          -1,  1,  0,
-    ///     ],
-    /// ).unwrap();
-    ///
-    /// let rock_rock = Profile::new([RPS::Rock, RPS::Rock]);
-    /// assert_eq!(rps.unilaterally_improve(for2::P0, rock_rock), Some(RPS::Paper));
-    /// assert_eq!(rps.unilaterally_improve(for2::P1, rock_rock), Some(RPS::Paper));
+    /// let stag_hunt = Normal::symmetric(vec!['C', 'D'], vec![3, 0, 2, 1]).unwrap();
+    /// let dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
     ///
-    /// let paper_scissors = Profile::new([RPS::Paper, RPS::Scissors]);
-    /// assert_eq!(rps.unilaterally_improve(for2::P0, paper_scissors), Some(RPS::Rock));
-    /// assert_eq!(rps.unilaterally_improve(for2::P1, paper_scissors), None);
+    /// let halfway = stag_hunt.convex_combination(&dilemma, 0.5).unwrap();
+    /// assert_eq!(halfway.payoff(Profile::new(['C', 'C'])), Payoff::from([2.5, 2.5]));
+    /// assert_eq!(halfway.payoff(Profile::new(['D', 'C'])), Payoff::from([2.5, 0.0]));
     ///
-    /// let paper_rock = Profile::new([RPS::Paper, RPS::Rock]);
-    /// assert_eq!(rps.unilaterally_improve(for2::P0, paper_rock), None);
-    /// assert_eq!(rps.unilaterally_improve(for2::P1, paper_rock), Some(RPS::Scissors));
+    /// assert_eq!(stag_hunt.pure_nash_equilibria().len(), 2);
+    /// assert_eq!(
+    ///     stag_hunt.convex_combination(&dilemma, 0.2).unwrap().pure_nash_equilibria(),
+    ///     vec![Profile::new(['D', 'D'])],
+    /// );
     /// ```
-    pub fn unilaterally_improve(
+    pub fn convex_combination(
         &self,
-        player: PlayerIndex<P>,
-        profile: Profile<M, P>,
-    ) -> Option<M> {
-        let mut best_move = None;
-        if self.is_valid_profile(profile) {
-            let mut best_util = self.payoff(profile)[player];
-            for adjacent in self.possible_outcomes().adjacent(player, profile) {
-                let util = adjacent.payoff()[player];
-                if util > best_util {
-                    best_move = Some(adjacent.profile()[player]);
-                    best_util = util;
-                }
-            }
-            best_move
-        } else {
+        other: &Normal<M, U, P>,
+        alpha: f64,
+    ) -> Option<Normal<M, f64, P>>
+    where
+        U: ToPrimitive,
+    {
+        if self.moves != other.moves {
             log::error!(
-                "IsNormal::unilaterally_improve: invalid initial profile ({:?})",
-                profile,
+                "Normal::convex_combination: the games have different move sets: {:?} and {:?}",
+                self.moves,
+                other.moves
             );
-            None
+            return None;
         }
+        if !(0.0..=1.0).contains(&alpha) {
+            log::error!(
+                "Normal::convex_combination: alpha must be in the range [0, 1], got {}",
+                alpha
+            );
+            return None;
+        }
+
+        let (first, second) = (self.clone(), other.clone());
+        let payoff_fn = move |profile: Profile<M, P>| {
+            let (a, b) = (first.payoff(profile), second.payoff(profile));
+            Payoff::from_fn(|player| {
+                let a = a[player].to_f64().unwrap_or(f64::NAN);
+                let b = b[player].to_f64().unwrap_or(f64::NAN);
+                alpha * a + (1.0 - alpha) * b
+            })
+        };
+        Some(Normal::from_payoff_fn(self.moves.clone(), payoff_fn))
     }
 
-    /// Is the given strategy profile stable? A profile is stable if no player can unilaterally
-    /// improve their utility.
+    /// Construct the product of this game and another game, in which the players play both games
+    /// simultaneously and receive the sum of their payoffs from each.
     ///
-    /// A stable profile is a pure
-    /// [Nash equilibrium](https://en.wikipedia.org/wiki/Nash_equilibrium) of the game.
+    /// Each move in the product game is a pair of a move in this game and a move in the other
+    /// game, so each player's moves are the
+    /// [Cartesian product](https://en.wikipedia.org/wiki/Cartesian_product) of their moves in
+    /// the two games. This models players interacting in several independent arenas at once.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let dilemma = Normal::symmetric(
-    ///     vec!['C', 'D'],
-    ///     vec![2, 0, 3, 1],
-    /// ).unwrap();
-    ///
-    /// let hunt = Normal::symmetric(
-    ///     vec!['C', 'D'],
-    ///     vec![3, 0, 2, 1],
-    /// ).unwrap();
-    ///
-    /// let cc = Profile::new(['C', 'C']);
-    /// let cd = Profile::new(['C', 'D']);
-    /// let dc = Profile::new(['D', 'C']);
-    /// let dd = Profile::new(['D', 'D']);
-    ///
-    /// assert!(!dilemma.is_stable(cc));
-    /// assert!(!dilemma.is_stable(cd));
-    /// assert!(!dilemma.is_stable(dc));
-    /// assert!(dilemma.is_stable(dd));
+    /// let dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let hunt = Normal::symmetric(vec!['S', 'H'], vec![3, 0, 2, 1]).unwrap();
     ///
-    /// assert!(hunt.is_stable(cc));
-    /// assert!(!hunt.is_stable(cd));
-    /// assert!(!hunt.is_stable(dc));
-    /// assert!(hunt.is_stable(dd));
+    /// let both = dilemma.product(&hunt);
+    /// assert_eq!(both.dimensions(), PerPlayer::new([4, 4]));
+    /// assert_eq!(
+    ///     both.payoff(Profile::new([('C', 'S'), ('D', 'S')])),
+    ///     Payoff::from([3, 6]),
+    /// );
+    /// assert_eq!(
+    ///     both.pure_nash_equilibria(),
+    ///     vec![Profile::new([('D', 'S'), ('D', 'S')]), Profile::new([('D', 'H'), ('D', 'H')])],
+    /// );
     /// ```
-    pub fn is_stable(&self, profile: Profile<M, P>) -> bool {
-        PlayerIndex::all().all(|player| self.unilaterally_improve(player, profile).is_none())
+    pub fn product<M2: Move>(&self, other: &Normal<M2, U, P>) -> Normal<(M, M2), U, P> {
+        let moves = PerPlayer::generate(|player| {
+            self.moves[player]
+                .iter()
+                .cartesian_product(&other.moves[player])
+                .map(|(&m1, &m2)| (m1, m2))
+                .collect()
+        });
+        let (first, second) = (self.clone(), other.clone());
+        let payoff_fn = move |profile: Profile<(M, M2), P>| {
+            let moves = profile.per_player();
+            let a = first.payoff(Profile::from_per_player(moves.map(|(m1, _)| m1)));
+            let b = second.payoff(Profile::from_per_player(moves.map(|(_, m2)| m2)));
+            a + b
+        };
+        Normal::from_payoff_fn(moves, payoff_fn)
     }
 
-    /// All pure [Nash equilibria](https://en.wikipedia.org/wiki/Nash_equilibrium) solutions of a
-    /// finite simultaneous game.
+    /// Construct a copy of this game in which each utility value of each payoff is perturbed by
+    /// noise sampled uniformly from the range `[-magnitude, magnitude]`.
     ///
-    /// This function simply enumerates all profiles and checks to see if each one is
-    /// [stable](Normal::is_stable).
+    /// This is useful for studying the robustness of a game's equilibria to small changes in its
+    /// payoffs. The noise is sampled once for every profile when the game is constructed, in the
+    /// order of [`possible_profiles`](Normal::possible_profiles), so the resulting game is
+    /// deterministic given a seeded `rng`.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `magnitude` is negative or not finite.
     ///
     /// # Examples
     /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
     /// use t4t::*;
     ///
-    /// let dilemma = Normal::symmetric(
-    ///     vec!['C', 'D'],
-    ///     vec![2, 0, 3, 1],
-    /// ).unwrap();
+    /// let dilemma: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
     ///
-    /// let hunt = Normal::symmetric(
-    ///     vec!['C', 'D'],
-    ///     vec![3, 0, 2, 1],
-    /// ).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let noisy = dilemma.with_payoff_noise(0.1, &mut rng).unwrap();
+    /// for profile in dilemma.possible_profiles() {
+    ///     let original = dilemma.payoff(profile);
+    ///     let perturbed = noisy.payoff(profile);
+    ///     for player in PlayerIndex::all() {
+    ///         assert!((perturbed[player] - original[player] as f64).abs() <= 0.1);
+    ///     }
+    /// }
     ///
-    /// assert_eq!(
-    ///     dilemma.pure_nash_equilibria(),
-    ///     vec![Profile::new(['D', 'D'])],
-    /// );
-    /// assert_eq!(
-    ///     hunt.pure_nash_equilibria(),
-    ///     vec![Profile::new(['C', 'C']), Profile::new(['D', 'D'])],
-    /// );
+    /// assert!(dilemma.with_payoff_noise(-1.0, &mut rng).is_none());
     /// ```
-    pub fn pure_nash_equilibria(&self) -> Vec<Profile<M, P>> {
-        let mut nash = Vec::new();
-        for profile in self.possible_profiles() {
-            if self.is_stable(profile) {
-                nash.push(profile);
-            }
-        }
-        nash
-    }
-
-    /// A variant of [`pure_nash_equilibria`](Self::pure_nash_equilibria) that analyzes the outcomes
-    /// in parallel.
-    pub fn pure_nash_equilibria_parallel(&self) -> Vec<Profile<M, P>> {
-        let (sender, receiver) = std::sync::mpsc::channel();
-        self.possible_profiles()
-            .par_bridge()
-            .for_each_with(sender, |s, profile| {
-                if self.is_stable(profile) {
-                    s.send(profile).unwrap();
-                }
-            });
-        receiver.iter().collect()
-    }
-
-    /// Return a new profile that represents a
-    /// [Pareto improvement](https://en.wikipedia.org/wiki/Pareto_efficiency)
-    /// on the given profile, if one exists.
-    ///
-    /// A profile is a Pareto improvement over another if the payoff associated with the improved
-    /// profile *increases the utility for at least one player* over the payoff associated with the
-    /// original profile, *without decreasing the utility for any players*.
-    pub fn pareto_improve(&self, profile: Profile<M, P>) -> Option<Profile<M, P>> {
-        if self.is_valid_profile(profile) {
-            let payoff = self.payoff(profile);
-            let mut best_profile = None;
-            let mut best_improvement = <U as Zero>::zero();
-            for outcome in self.possible_outcomes() {
-                if let Some(improvement) = payoff.pareto_improvement(*outcome.payoff()) {
-                    if improvement.gt(&best_improvement) {
-                        best_profile = Some(*outcome.profile());
-                        best_improvement = improvement;
-                    }
-                }
-            }
-            best_profile
-        } else {
+    pub fn with_payoff_noise<R: rand::Rng>(
+        &self,
+        magnitude: f64,
+        rng: &mut R,
+    ) -> Option<Normal<M, f64, P>>
+    where
+        U: ToPrimitive,
+    {
+        if !magnitude.is_finite() || magnitude < 0.0 {
             log::error!(
-                "IsNormal::pareto_improve: invalid initial profile ({:?})",
-                profile,
+                "Normal::with_payoff_noise: magnitude must be finite and non-negative, got {}",
+                magnitude
             );
-            None
+            return None;
         }
-    }
-
-    /// A profile is [Pareto optimal](https://en.wikipedia.org/wiki/Pareto_efficiency) if there is
-    /// no other profile that represents a [Pareto improvement](Normal::pareto_improve).
-    pub fn is_pareto_optimal(&self, profile: Profile<M, P>) -> bool {
-        self.pareto_improve(profile).is_none()
-    }
 
-    /// Get all profiles that are [Pareto optimal](Normal::is_pareto_optimal).
-    pub fn pareto_optimal_solutions(&self) -> Vec<Profile<M, P>> {
-        let mut pareto = Vec::new();
+        let mut payoff_map = HashMap::new();
         for profile in self.possible_profiles() {
-            if self.is_pareto_optimal(profile) {
-                pareto.push(profile)
-            }
-        }
-        pareto
-    }
-
-    /// A variant of [`pareto_optimal_solutions`](Self::pareto_optimal_solutions) that analyzes the
-    /// outcomes in parallel.
-    pub fn pareto_optimal_solutions_parallel(&self) -> Vec<Profile<M, P>> {
-        let (sender, receiver) = std::sync::mpsc::channel();
-        self.possible_profiles()
-            .par_bridge()
-            .for_each_with(sender, |s, profile| {
-                if self.is_pareto_optimal(profile) {
-                    s.send(profile).unwrap();
-                }
+            let payoff = self.payoff(profile);
+            let noisy = Payoff::from_fn(|player| {
+                let util = payoff[player].to_f64().unwrap_or(f64::NAN);
+                util + rng.gen_range(-magnitude..=magnitude)
             });
-        receiver.iter().collect()
+            payoff_map.insert(profile, noisy);
+        }
+        Some(Normal::from_payoff_map(self.moves.clone(), payoff_map))
     }
 
-    /// Get all dominated move relationships for the given player. If a move is dominated by
-    /// multiple different moves, it will contain multiple entries in the returned vector.
+    /// Construct the discretized
+    /// [mixed extension](https://en.wikipedia.org/wiki/Strategy_(game_theory)#Mixed_strategy) of
+    /// this game, in which each player's moves are mixed strategies over their original moves and
+    /// the payoffs are the corresponding expected payoffs.
     ///
-    /// See the documentation for [`Dominated`] for more info.
+    /// The mixed strategies are the points of a grid over each player's probability simplex,
+    /// where every probability is a multiple of `1 / grid`. Analyzing the resulting game with the
+    /// existing tools for pure moves, such as [`pure_nash_equilibria`](Normal::pure_nash_equilibria)
+    /// or [`regret`](Normal::regret), approximates the analysis of the game's mixed strategies.
+    ///
+    /// Since mixed strategies cannot be used as moves directly, the moves of the resulting game are
+    /// indexes into each player's list of mixed strategies, which is also returned. Each mixed
+    /// strategy includes only the moves played with positive probability.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `grid` is zero.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let g = Normal::from_payoff_vec(
-    ///     PerPlayer::new([
-    ///         vec!['A', 'B', 'C'],
-    ///         vec!['D', 'E'],
-    ///     ]),
-    ///     vec![
-    ///         Payoff::from([3, 3]), Payoff::from([3, 5]),
-    ///         Payoff::from([2, 0]), Payoff::from([3, 1]),
-    ///         Payoff::from([4, 0]), Payoff::from([2, 1]),
-    ///     ],
-    /// ).unwrap();
+    /// let pennies = Normal::matrix(['H', 'T'], ['H', 'T'], [[1, -1], [-1, 1]]);
+    /// let (mixed, strategies) = pennies.mixed_extension(2).unwrap();
     ///
-    /// assert_eq!(g.dominated_moves_for(for2::P0), vec![Dominated::weak('B', 'A')]);
-    /// assert_eq!(g.dominated_moves_for(for2::P1), vec![Dominated::strict('D', 'E')]);
+    /// // Each player can play heads, a fair coin, or tails.
+    /// assert_eq!(strategies[for2::P0].len(), 3);
+    /// assert_eq!(
+    ///     strategies[for2::P0][1].probabilities().collect::<Vec<_>>(),
+    ///     vec![(&'H', 0.5), (&'T', 0.5)],
+    /// );
+    ///
+    /// // Both players flipping a fair coin is the only equilibrium.
+    /// assert_eq!(mixed.pure_nash_equilibria(), vec![Profile::new([1, 1])]);
     /// ```
-    pub fn dominated_moves_for(&self, player: PlayerIndex<P>) -> Vec<Dominated<M>> {
-        let mut dominated = Vec::new();
-
-        for maybe_ted in self.possible_moves_for_player(player) {
-            let ted_iter = self.possible_outcomes().include(player, maybe_ted);
+    #[allow(clippy::type_complexity)]
+    pub fn mixed_extension(
+        &self,
+        grid: usize,
+    ) -> Option<(Normal<usize, f64, P>, PerPlayer<Vec<Distribution<M>>, P>)>
+    where
+        U: ToPrimitive,
+    {
+        if grid == 0 {
+            log::error!("Normal::mixed_extension: the grid size must be positive");
+            return None;
+        }
 
-            for maybe_tor in self.possible_moves_for_player(player) {
-                if maybe_ted == maybe_tor {
-                    continue;
-                }
+        // The probability of each original move, for each mixed strategy of each player.
+        let weights: PerPlayer<Vec<Vec<f64>>, P> = self.moves.map(|moves| {
+            simplex_grid(grid, moves.len())
+                .into_iter()
+                .map(|counts| counts.into_iter().map(|c| c as f64 / grid as f64).collect())
+                .collect()
+        });
+        let strategies = PerPlayer::generate(|player| {
+            weights[player]
+                .iter()
+                .map(|probs| {
+                    let support = self.moves[player]
+                        .iter()
+                        .copied()
+                        .zip(probs.iter().copied())
+                        .filter(|(_, prob)| *prob > 0.0)
+                        .collect();
+                    Distribution::new(support)
+                })
+                .collect::<Option<Vec<_>>>()
+        })
+        .all_some()?;
 
-                let tor_iter = self.possible_outcomes().include(player, maybe_tor);
+        let pure_payoffs: Vec<(PerPlayer<usize, P>, Payoff<f64, P>)> = self
+            .moves
+            .map(|moves| 0..moves.len())
+            .into_iter()
+            .multi_cartesian_product()
+            .map(|indexes| {
+                let indexes = PerPlayer::new(indexes.try_into().unwrap());
+                let profile = Profile::from_per_player(PerPlayer::generate(|player| {
+                    self.moves[player][indexes[player]]
+                }));
+                let payoff = self.payoff(profile).map(|u| u.to_f64().unwrap_or(f64::NAN));
+                (indexes, payoff)
+            })
+            .collect();
 
-                let mut is_dominated = true;
-                let mut is_strict = true;
-                for (ted_outcome, tor_outcome) in ted_iter.clone().zip(tor_iter) {
-                    let ted_payoff = ted_outcome.payoff();
-                    let tor_payoff = tor_outcome.payoff();
-                    if let Some(ordering) = ted_payoff[player].partial_cmp(&tor_payoff[player]) {
-                        match ordering {
-                            Ordering::Less => {}
-                            Ordering::Equal => is_strict = false,
-                            Ordering::Greater => {
-                                is_dominated = false;
-                                break;
-                            }
-                        }
-                    }
-                }
-                if is_dominated {
-                    dominated.push(Dominated {
-                        dominated: maybe_ted,
-                        dominator: maybe_tor,
-                        is_strict,
-                    });
+        let moves = weights.map(|mixes| (0..mixes.len()).collect());
+        let payoff_fn = move |profile: Profile<usize, P>| {
+            let mut expected = Payoff::zeros();
+            for (indexes, payoff) in &pure_payoffs {
+                let prob: f64 = PlayerIndex::all()
+                    .map(|player| weights[player][profile[player]][indexes[player]])
+                    .product();
+                if prob > 0.0 {
+                    expected = expected + payoff.map(|u| u * prob);
                 }
             }
-        }
-        dominated
-    }
-
-    /// Get all dominated move relationships for each player.
-    pub fn dominated_moves(&self) -> PerPlayer<Vec<Dominated<M>>, P> {
-        PerPlayer::generate(|index| self.dominated_moves_for(index))
+            expected
+        };
+        Some((Normal::from_payoff_fn(moves, payoff_fn), strategies))
     }
-}
 
-impl<M: Move, U: Utility> Normal<M, U, 2> {
-    /// Construct a matrix game, a two-player zero-sum game where the payoffs are defined by a
-    /// single matrix of utility values.
-    ///
-    /// Constructed from the list of moves for each player and the matrix (in row major order) of
-    /// utility values for player `P0`.
+    /// Is this game zero-sum? In a zero-sum game, the utility values of each payoff sum to zero.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let g = Normal::matrix(
-    ///     ['A', 'B', 'C'],
-    ///     ['D', 'E'],
-    ///     [[-3, -1],
-    ///      [ 0,  2],
-    ///      [ 4,  6]],
-    /// );
+    /// let rps: Normal<_, _, 2> = Normal::symmetric(
+    ///     vec!["Rock", "Paper", "Scissors"],
+    ///     vec![0, -1, 1, 1, 0, -1, -1, 1, 0],
+    /// ).unwrap();
     ///
-    /// assert!(g.is_zero_sum());
-    /// assert_eq!(g.payoff(Profile::new(['A', 'D'])), Payoff::from([-3, 3]));
-    /// assert_eq!(g.payoff(Profile::new(['A', 'E'])), Payoff::from([-1, 1]));
-    /// assert_eq!(g.payoff(Profile::new(['B', 'D'])), Payoff::from([0, 0]));
-    /// assert_eq!(g.payoff(Profile::new(['B', 'E'])), Payoff::from([2, -2]));
-    /// assert_eq!(g.payoff(Profile::new(['C', 'D'])), Payoff::from([4, -4]));
-    /// assert_eq!(g.payoff(Profile::new(['C', 'E'])), Payoff::from([6, -6]));
+    /// assert!(rps.is_zero_sum());
+    ///
+    /// let pd: Normal<_, _, 2> = Normal::symmetric(
+    ///     vec!["Cooperate", "Defect"],
+    ///     vec![2, 0, 3, 1],
+    /// ).unwrap();
+    ///
+    /// assert!(!pd.is_zero_sum());
     /// ```
-    pub fn matrix<const ROWS: usize, const COLS: usize>(
-        row_moves: [M; ROWS],
-        col_moves: [M; COLS],
-        row_utils: [[U; COLS]; ROWS],
-    ) -> Self {
-        let moves = PerPlayer::new([row_moves.to_vec(), col_moves.to_vec()]);
-        let mut payoff_map = HashMap::with_capacity(ROWS * COLS);
-        for (r, row_move) in row_moves.into_iter().enumerate() {
-            for (c, col_move) in col_moves.into_iter().enumerate() {
-                let row_util = row_utils[r][c];
-                let payoff = Payoff::from([row_util, U::zero().sub(row_util)]);
-                let profile = Profile::new([row_move, col_move]);
-                payoff_map.insert(profile, payoff);
-            }
-        }
-        Normal::from_payoff_map(moves, payoff_map)
+    pub fn is_zero_sum(&self) -> bool {
+        self.is_constant_sum()
+            .is_some_and(|constant| constant == U::zero())
     }
 
-    /// Construct a [bimatrix game](https://en.wikipedia.org/wiki/Bimatrix_game), a two-player
-    /// game where the payoffs are defined by two matrices of utilities, one for each player.
+    /// If this game is constant-sum, get the constant that the utility values of each payoff sum
+    /// to. Otherwise, return `None`.
     ///
-    /// Constructed from the list of moves and the matrix (in row major order) of utility values
-    /// for each player.
+    /// A constant-sum game is strategically equivalent to a [zero-sum](Normal::is_zero_sum) game,
+    /// which is the special case where the constant is zero.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let g = Normal::bimatrix(
-    ///     ['A', 'B', 'C'],
-    ///     ['D', 'E'],
-    ///     [[0, 5], [4, 3], [2, 1]],
-    ///     [[5, 0], [1, 2], [4, 3]],
-    /// );
+    /// let rps: Normal<_, _, 2> = Normal::symmetric(
+    ///     vec!["Rock", "Paper", "Scissors"],
+    ///     vec![0, -1, 1, 1, 0, -1, -1, 1, 0],
+    /// ).unwrap();
     ///
-    /// assert_eq!(g.payoff(Profile::new(['A', 'D'])), Payoff::from([0, 5]));
-    /// assert_eq!(g.payoff(Profile::new(['A', 'E'])), Payoff::from([5, 0]));
-    /// assert_eq!(g.payoff(Profile::new(['B', 'D'])), Payoff::from([4, 1]));
-    /// assert_eq!(g.payoff(Profile::new(['B', 'E'])), Payoff::from([3, 2]));
-    /// assert_eq!(g.payoff(Profile::new(['C', 'D'])), Payoff::from([2, 4]));
-    /// assert_eq!(g.payoff(Profile::new(['C', 'E'])), Payoff::from([1, 3]));
+    /// assert_eq!(rps.is_constant_sum(), Some(0));
+    ///
+    /// let split: Normal<_, _, 2> = Normal::symmetric(
+    ///     vec!["Share", "Grab"],
+    ///     vec![5, 2, 8, 5],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(split.is_constant_sum(), Some(10));
+    /// assert!(!split.is_zero_sum());
+    ///
+    /// let pd: Normal<_, _, 2> = Normal::symmetric(
+    ///     vec!["Cooperate", "Defect"],
+    ///     vec![2, 0, 3, 1],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pd.is_constant_sum(), None);
     /// ```
-    pub fn bimatrix<const ROWS: usize, const COLS: usize>(
-        row_moves: [M; ROWS],
-        col_moves: [M; COLS],
-        row_utils: [[U; COLS]; ROWS],
-        col_utils: [[U; COLS]; ROWS],
-    ) -> Self {
-        let moves = PerPlayer::new([row_moves.to_vec(), col_moves.to_vec()]);
-        let mut payoff_map = HashMap::with_capacity(ROWS * COLS);
-        for (r, row_move) in row_moves.into_iter().enumerate() {
-            for (c, col_move) in col_moves.into_iter().enumerate() {
-                let profile = Profile::new([row_move, col_move]);
-                let payoff = Payoff::from([row_utils[r][c], col_utils[r][c]]);
-                payoff_map.insert(profile, payoff);
-            }
+    pub fn is_constant_sum(&self) -> Option<U> {
+        let mut sums = self.possible_outcomes().map(|outcome| {
+            PlayerIndex::all().fold(U::zero(), |sum, player| sum + outcome.payoff()[player])
+        });
+        match sums.next() {
+            None => Some(U::zero()),
+            Some(first) => sums.all(|sum| sum == first).then_some(first),
         }
-        Normal::from_payoff_map(moves, payoff_map)
     }
 
-    /// Construct a [symmetric](https://en.wikipedia.org/wiki/Symmetric_game) two-player
-    /// normal-form game. Constructed from a list of moves available to both players and the
-    /// utility values for the `ROW` player (`P0`).
+    /// Return a move that unilaterally improves the given player's utility, if such a move exists.
+    ///
+    /// A unilateral improvement assumes that all other player's moves will be unchanged.
+    ///
+    /// If more than one move would unilaterally improve the player's utility, then the move that
+    /// improves it by the *most* is returned.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let pd = Normal::symmetric_for2(
-    ///     ['C', 'D'],
-    ///     [[2, 0], [3, 1]],
-    /// );
+    /// #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    /// enum RPS { Rock, Paper, Scissors }
     ///
-    /// assert_eq!(pd.payoff(Profile::new(['C', 'C'])), Payoff::from([2, 2]));
-    /// assert_eq!(pd.payoff(Profile::new(['C', 'D'])), Payoff::from([0, 3]));
-    /// assert_eq!(pd.payoff(Profile::new(['D', 'C'])), Payoff::from([3, 0]));
-    /// assert_eq!(pd.payoff(Profile::new(['D', 'D'])), Payoff::from([1, 1]));
+    /// let rps = Normal::symmetric(
+    ///     vec![RPS::Rock, RPS::Paper, RPS::Scissors],
+    ///     vec![ 0, -1,  1,
+    ///           1,  0, -1,
+    ///          -1,  1,  0,
+    ///     ],
+    /// ).unwrap();
+    ///
+    /// let rock_rock = Profile::new([RPS::Rock, RPS::Rock]);
+    /// assert_eq!(rps.unilaterally_improve(for2::P0, rock_rock), Some(RPS::Paper));
+    /// assert_eq!(rps.unilaterally_improve(for2::P1, rock_rock), Some(RPS::Paper));
+    ///
+    /// let paper_scissors = Profile::new([RPS::Paper, RPS::Scissors]);
+    /// assert_eq!(rps.unilaterally_improve(for2::P0, paper_scissors), Some(RPS::Rock));
+    /// assert_eq!(rps.unilaterally_improve(for2::P1, paper_scissors), None);
+    ///
+    /// let paper_rock = Profile::new([RPS::Paper, RPS::Rock]);
+    /// assert_eq!(rps.unilaterally_improve(for2::P0, paper_rock), None);
+    /// assert_eq!(rps.unilaterally_improve(for2::P1, paper_rock), Some(RPS::Scissors));
     /// ```
-    pub fn symmetric_for2<const SIZE: usize>(
-        moves: [M; SIZE],
-        row_utils: [[U; SIZE]; SIZE],
-    ) -> Self {
-        let all_moves = PerPlayer::init_with(moves.to_vec());
-        let mut payoff_map = HashMap::with_capacity(SIZE * SIZE);
-        for (r, row_move) in moves.into_iter().enumerate() {
-            for (c, col_move) in moves.into_iter().enumerate() {
-                let profile = Profile::new([row_move, col_move]);
-                let payoff = Payoff::from([row_utils[r][c], row_utils[c][r]]);
-                payoff_map.insert(profile, payoff);
+    pub fn unilaterally_improve(
+        &self,
+        player: PlayerIndex<P>,
+        profile: Profile<M, P>,
+    ) -> Option<M> {
+        let mut best_move = None;
+        if self.is_valid_profile(profile) {
+            let mut best_util = self.payoff(profile)[player];
+            for adjacent in self.possible_outcomes().adjacent(player, profile) {
+                let util = adjacent.payoff()[player];
+                if util > best_util {
+                    best_move = Some(adjacent.profile()[player]);
+                    best_util = util;
+                }
             }
+            best_move
+        } else {
+            log::error!(
+                "IsNormal::unilaterally_improve: invalid initial profile ({:?})",
+                profile,
+            );
+            None
         }
-        Normal::from_payoff_map(all_moves, payoff_map)
     }
-}
 
-impl<M: Move, U: Utility> Normal<M, U, 3> {
-    /// Construct a [symmetric](https://en.wikipedia.org/wiki/Symmetric_game) three-player
-    /// normal-form game. Constructed from a list of moves available to all players and the utility
-    /// values for player `P0`.
+    /// Is the given strategy profile stable? A profile is stable if no player can unilaterally
+    /// improve their utility.
+    ///
+    /// A stable profile is a pure
+    /// [Nash equilibrium](https://en.wikipedia.org/wiki/Nash_equilibrium) of the game.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let pd3 = Normal::symmetric_for3(
-    ///     ['C', 'D'],
-    ///     [[[4, 1], [1, 0]], [[5, 3], [3, 2]]],
-    /// );
+    /// let dilemma = Normal::symmetric(
+    ///     vec!['C', 'D'],
+    ///     vec![2, 0, 3, 1],
+    /// ).unwrap();
     ///
-    /// assert_eq!(pd3.payoff(Profile::new(['C', 'C', 'C'])), Payoff::from([4, 4, 4]));
-    /// assert_eq!(pd3.payoff(Profile::new(['C', 'C', 'D'])), Payoff::from([1, 1, 5]));
-    /// assert_eq!(pd3.payoff(Profile::new(['C', 'D', 'C'])), Payoff::from([1, 5, 1]));
-    /// assert_eq!(pd3.payoff(Profile::new(['C', 'D', 'D'])), Payoff::from([0, 3, 3]));
-    /// assert_eq!(pd3.payoff(Profile::new(['D', 'C', 'C'])), Payoff::from([5, 1, 1]));
-    /// assert_eq!(pd3.payoff(Profile::new(['D', 'C', 'D'])), Payoff::from([3, 0, 3]));
-    /// assert_eq!(pd3.payoff(Profile::new(['D', 'D', 'C'])), Payoff::from([3, 3, 0]));
-    /// assert_eq!(pd3.payoff(Profile::new(['D', 'D', 'D'])), Payoff::from([2, 2, 2]));
+    /// let hunt = Normal::symmetric(
+    ///     vec!['C', 'D'],
+    ///     vec![3, 0, 2, 1],
+    /// ).unwrap();
+    ///
+    /// let cc = Profile::new(['C', 'C']);
+    /// let cd = Profile::new(['C', 'D']);
+    /// let dc = Profile::new(['D', 'C']);
+    /// let dd = Profile::new(['D', 'D']);
+    ///
+    /// assert!(!dilemma.is_stable(cc));
+    /// assert!(!dilemma.is_stable(cd));
+    /// assert!(!dilemma.is_stable(dc));
+    /// assert!(dilemma.is_stable(dd));
+    ///
+    /// assert!(hunt.is_stable(cc));
+    /// assert!(!hunt.is_stable(cd));
+    /// assert!(!hunt.is_stable(dc));
+    /// assert!(hunt.is_stable(dd));
     /// ```
-    pub fn symmetric_for3<const SIZE: usize>(
-        moves: [M; SIZE],
-        p0_utils: [[[U; SIZE]; SIZE]; SIZE],
-    ) -> Self {
-        let all_moves = PerPlayer::init_with(moves.to_vec());
-        let mut payoff_map = HashMap::with_capacity(SIZE.pow(3));
-        for (i0, m0) in moves.into_iter().enumerate() {
-            for (i1, m1) in moves.into_iter().enumerate() {
-                for (i2, m2) in moves.into_iter().enumerate() {
-                    let u0 = p0_utils[i0][i1][i2];
-                    let u1 = p0_utils[i1][i2][i0];
-                    let u2 = p0_utils[i2][i0][i1];
-                    let payoff = Payoff::from([u0, u1, u2]);
-                    let profile = Profile::new([m0, m1, m2]);
-                    payoff_map.insert(profile, payoff);
-                }
-            }
-        }
-        Normal::from_payoff_map(all_moves, payoff_map)
+    pub fn is_stable(&self, profile: Profile<M, P>) -> bool {
+        PlayerIndex::all().all(|player| self.unilaterally_improve(player, profile).is_none())
     }
-}
 
-impl<M: Move, U: Utility> Normal<M, U, 4> {
-    /// Construct a [symmetric](https://en.wikipedia.org/wiki/Symmetric_game) four-player
-    /// normal-form game. Constructed from a list of moves available to all players and the utility
-    /// values for player `P0`.
+    /// Get the given player's [regret](https://en.wikipedia.org/wiki/Regret_(decision_theory))
+    /// for the given profile, that is, how much more utility the player could have received by
+    /// unilaterally changing their move to a best response.
+    ///
+    /// Regret is never negative, and is zero for every player exactly when the profile is
+    /// [stable](Normal::is_stable).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns zero if the profile is invalid.
     ///
     /// # Examples
     /// ```
     /// use t4t::*;
     ///
-    /// let pd4 = Normal::symmetric_for4(
-    ///     ['C', 'D'],
-    ///     [[[[6, 2], [2, 1]], [[2, 1], [1, 0]]],
-    ///      [[[7, 5], [5, 4]], [[5, 4], [4, 3]]]],
-    /// );
+    /// let dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
     ///
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'C', 'C'])), Payoff::from([6, 6, 6, 6]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'C', 'D'])), Payoff::from([2, 2, 2, 7]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'D', 'C'])), Payoff::from([2, 2, 7, 2]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'D', 'D'])), Payoff::from([1, 1, 5, 5]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'C', 'C'])), Payoff::from([2, 7, 2, 2]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'C', 'D'])), Payoff::from([1, 5, 1, 5]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'D', 'C'])), Payoff::from([1, 5, 5, 1]));
-    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'D', 'D'])), Payoff::from([0, 4, 4, 4]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'C', 'C'])), Payoff::from([7, 2, 2, 2]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'C', 'D'])), Payoff::from([5, 1, 1, 5]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'D', 'C'])), Payoff::from([5, 1, 5, 1]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'D', 'D'])), Payoff::from([4, 0, 4, 4]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'C', 'C'])), Payoff::from([5, 5, 1, 1]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'C', 'D'])), Payoff::from([4, 4, 0, 4]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'D', 'C'])), Payoff::from([4, 4, 4, 0]));
-    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'D', 'D'])), Payoff::from([3, 3, 3, 3]));
+    /// assert_eq!(dilemma.regret(for2::P0, Profile::new(['D', 'D'])), 0);
+    /// assert_eq!(dilemma.regret(for2::P1, Profile::new(['D', 'D'])), 0);
+    ///
+    /// assert_eq!(dilemma.regret(for2::P0, Profile::new(['C', 'C'])), 1);
+    /// assert_eq!(dilemma.regret(for2::P0, Profile::new(['C', 'D'])), 1);
+    /// assert_eq!(dilemma.regret(for2::P1, Profile::new(['C', 'D'])), 0);
     /// ```
-    pub fn symmetric_for4<const SIZE: usize>(
-        moves: [M; SIZE],
-        p0_utils: [[[[U; SIZE]; SIZE]; SIZE]; SIZE],
-    ) -> Self {
-        let all_moves = PerPlayer::init_with(moves.to_vec());
-        let mut payoff_map = HashMap::with_capacity(SIZE.pow(4));
-        for (i0, m0) in moves.into_iter().enumerate() {
-            for (i1, m1) in moves.into_iter().enumerate() {
-                for (i2, m2) in moves.into_iter().enumerate() {
-                    for (i3, m3) in moves.into_iter().enumerate() {
-                        let u0 = p0_utils[i0][i1][i2][i3];
-                        let u1 = p0_utils[i1][i2][i3][i0];
-                        let u2 = p0_utils[i2][i3][i0][i1];
-                        let u3 = p0_utils[i3][i0][i1][i2];
-                        let payoff = Payoff::from([u0, u1, u2, u3]);
-                        let profile = Profile::new([m0, m1, m2, m3]);
-                        payoff_map.insert(profile, payoff);
-                    }
-                }
-            }
+    pub fn regret(&self, player: PlayerIndex<P>, profile: Profile<M, P>) -> U {
+        if !self.is_valid_profile(profile) {
+            log::error!("Normal::regret: invalid profile ({:?})", profile);
+            return U::zero();
         }
-        Normal::from_payoff_map(all_moves, payoff_map)
+        let actual = self.payoff(profile)[player];
+        let best = self
+            .possible_outcomes()
+            .adjacent(player, profile)
+            .map(|adjacent| adjacent.payoff()[player])
+            .fold(actual, |best, util| if util > best { util } else { best });
+        best - actual
     }
-}
-
-impl<M: Move, U: Utility, const P: usize> Game<P> for Normal<M, U, P> {
-    type Move = M;
-    type Utility = U;
-    type Outcome = SimultaneousOutcome<M, U, P>;
-    type State = ();
-    type View = ();
 
-    fn into_game_tree(self) -> GameTree<(), M, U, SimultaneousOutcome<M, U, P>, P> {
-        let state = Arc::new(());
-        GameTree::all_players(state.clone(), move |_, profile| {
-            for ply in profile.plies() {
-                let player = ply.player.unwrap();
-                if !self.is_valid_move_for_player(player, ply.the_move) {
-                    return Err(ErrorKind::InvalidMove(player, ply.the_move));
-                }
-            }
-            Ok(GameTree::end(
-                state.clone(),
-                SimultaneousOutcome::new(profile, self.payoff(profile)),
-            ))
-        })
+    /// Get the given player's pure-strategy
+    /// [minmax value](https://en.wikipedia.org/wiki/Minimax#In_repeated_games), that is, the
+    /// lowest utility the other players can hold the player to, assuming the player best responds
+    /// to the other players' moves.
+    ///
+    /// This is the punishment value used in the
+    /// [folk theorem](https://en.wikipedia.org/wiki/Folk_theorem_(game_theory)) of repeated games.
+    /// Only pure moves are considered, so when the other players could punish more severely by
+    /// randomizing, the mixed-strategy minmax value may be lower.
+    ///
+    /// Returns `None` if the game has no profiles.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let dilemma: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// assert_eq!(dilemma.minmax_value(for2::P0), Some(1));
+    ///
+    /// // Rock-paper-scissors players can always win against a known pure move.
+    /// let rps: Normal<char, i32, 2> = Normal::symmetric(
+    ///     vec!['R', 'P', 'S'],
+    ///     vec![0, -1, 1, 1, 0, -1, -1, 1, 0],
+    /// ).unwrap();
+    /// assert_eq!(rps.minmax_value(for2::P1), Some(1));
+    /// ```
+    pub fn minmax_value(&self, player: PlayerIndex<P>) -> Option<U> {
+        self.possible_profiles()
+            .map(|profile| self.payoff(profile)[player] + self.regret(player, profile))
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
     }
 
-    fn state_view(&self, _state: &(), _player: PlayerIndex<P>) {}
-}
-
-impl<M: Move, U: Utility, const P: usize> FiniteGame<P> for Normal<M, U, P> {
-    fn possible_moves(&self, player: PlayerIndex<P>, _state: &()) -> PossibleMoves<'_, M> {
-        self.possible_moves_for_player(player)
+    /// Is the given distribution over profiles a
+    /// [coarse correlated equilibrium](https://en.wikipedia.org/wiki/Correlated_equilibrium) of
+    /// this game?
+    ///
+    /// A distribution is a coarse correlated equilibrium if no player can increase their expected
+    /// utility by committing to a single fixed move in advance, rather than playing the move
+    /// recommended by a profile drawn from the distribution. This is the solution concept that
+    /// no-regret learning dynamics, such as [regret matching](crate::Strategy::regret_matching),
+    /// converge to.
+    ///
+    /// The weights in the distribution are normalized, so an empirical count of profiles may be
+    /// passed directly. To account for floating-point rounding, deviations that improve a player's
+    /// expected utility by less than `1e-9` are ignored. Use
+    /// [`is_epsilon_coarse_correlated_equilibrium`](Normal::is_epsilon_coarse_correlated_equilibrium)
+    /// to check an approximate equilibrium instead.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `false` if the distribution contains an invalid profile or a
+    /// negative or non-finite weight, or if its weights do not sum to a positive value.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use t4t::*;
+    ///
+    /// let dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// let defect = HashMap::from([(Profile::new(['D', 'D']), 1.0)]);
+    /// let cooperate = HashMap::from([(Profile::new(['C', 'C']), 1.0)]);
+    /// assert!(dilemma.is_coarse_correlated_equilibrium(&defect));
+    /// assert!(!dilemma.is_coarse_correlated_equilibrium(&cooperate));
+    ///
+    /// // In chicken, a traffic light that tells exactly one driver to go is a correlated
+    /// // equilibrium, so it is also a coarse correlated equilibrium.
+    /// let chicken = Normal::symmetric(vec!['S', 'G'], vec![0, -1, 1, -10]).unwrap();
+    /// let light = HashMap::from([
+    ///     (Profile::new(['S', 'G']), 0.5),
+    ///     (Profile::new(['G', 'S']), 0.5),
+    /// ]);
+    /// assert!(chicken.is_coarse_correlated_equilibrium(&light));
+    /// ```
+    pub fn is_coarse_correlated_equilibrium(&self, dist: &HashMap<Profile<M, P>, f64>) -> bool
+    where
+        U: ToPrimitive,
+    {
+        self.is_epsilon_coarse_correlated_equilibrium(dist, 1e-9)
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use impls::impls;
-    use test_log::test;
 
-    #[test]
-    fn normal_is_send_sync() {
-        assert!(impls!(Normal<(), u8, 2>: Send & Sync));
+    /// Is the given distribution over profiles an approximate
+    /// [coarse correlated equilibrium](Normal::is_coarse_correlated_equilibrium) of this game,
+    /// where no player can increase their expected utility by more than `epsilon` by committing
+    /// to a single fixed move?
+    ///
+    /// This is useful for validating the empirical distribution of play produced by no-regret
+    /// learning, which approaches a coarse correlated equilibrium but may not reach one exactly.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `false` if the distribution contains an invalid profile or a
+    /// negative or non-finite weight, or if its weights do not sum to a positive value.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use t4t::*;
+    ///
+    /// let dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// // Defecting instead of cooperating gains each player 0.1 in expectation.
+    /// let mostly_defect = HashMap::from([
+    ///     (Profile::new(['C', 'C']), 0.1),
+    ///     (Profile::new(['D', 'D']), 0.9),
+    /// ]);
+    /// assert!(!dilemma.is_epsilon_coarse_correlated_equilibrium(&mostly_defect, 0.05));
+    /// assert!(dilemma.is_epsilon_coarse_correlated_equilibrium(&mostly_defect, 0.15));
+    /// ```
+    pub fn is_epsilon_coarse_correlated_equilibrium(
+        &self,
+        dist: &HashMap<Profile<M, P>, f64>,
+        epsilon: f64,
+    ) -> bool
+    where
+        U: ToPrimitive,
+    {
+        if let Some(profile) = dist
+            .keys()
+            .find(|profile| !self.is_valid_profile(**profile))
+        {
+            log::error!(
+                "Normal::is_coarse_correlated_equilibrium: invalid profile ({:?})",
+                profile
+            );
+            return false;
+        }
+        if let Some(weight) = dist.values().find(|w| !w.is_finite() || **w < 0.0) {
+            log::error!(
+                "Normal::is_coarse_correlated_equilibrium: invalid weight ({})",
+                weight
+            );
+            return false;
+        }
+        let total: f64 = dist.values().sum();
+        if total <= 0.0 {
+            log::error!(
+                "Normal::is_coarse_correlated_equilibrium: weights must sum to a positive value"
+            );
+            return false;
+        }
+
+        let utility = |profile: Profile<M, P>, player: PlayerIndex<P>| {
+            self.payoff(profile)[player].to_f64().unwrap_or(f64::NAN)
+        };
+        PlayerIndex::all().all(|player| {
+            let expected: f64 = dist
+                .iter()
+                .map(|(profile, weight)| weight * utility(*profile, player))
+                .sum::<f64>()
+                / total;
+            self.moves[player].iter().all(|&deviation| {
+                let deviated: f64 = dist
+                    .iter()
+                    .map(|(profile, weight)| {
+                        let mut profile = *profile;
+                        profile[player] = deviation;
+                        weight * utility(profile, player)
+                    })
+                    .sum::<f64>()
+                    / total;
+                deviated <= expected + epsilon
+            })
+        })
+    }
+
+    /// Construct the [best-response graph](BestResponseGraph) of this game.
+    ///
+    /// The graph contains an edge from each profile to each profile reachable by a single player
+    /// [unilaterally improving](Normal::unilaterally_improve) their utility by switching to a best
+    /// response to the other players' moves. The sinks of the graph are exactly the
+    /// [pure Nash equilibria](Normal::pure_nash_equilibria) of the game.
+    ///
+    /// The graph contains a node for every profile, so this is only practical for small games.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let rps: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+    /// let graph = rps.best_response_graph();
+    ///
+    /// assert_eq!(graph.nodes().len(), 9);
+    /// assert!(graph.sinks().is_empty());
+    ///
+    /// let rock_rock = Profile::new(['R', 'R']);
+    /// let targets: Vec<_> = graph.edges_from(rock_rock).map(|edge| edge.to).collect();
+    /// assert_eq!(targets, vec![Profile::new(['P', 'R']), Profile::new(['R', 'P'])]);
+    /// ```
+    pub fn best_response_graph(&self) -> BestResponseGraph<M, P> {
+        let nodes: Vec<Profile<M, P>> = self.possible_profiles().collect();
+        let mut edges = Vec::new();
+        for &profile in &nodes {
+            let payoff = self.payoff(profile);
+            for player in PlayerIndex::all() {
+                let deviations: Vec<(Profile<M, P>, U)> = self
+                    .possible_outcomes()
+                    .adjacent(player, profile)
+                    .map(|outcome| (*outcome.profile(), outcome.payoff()[player]))
+                    .collect();
+                let best =
+                    deviations
+                        .iter()
+                        .map(|(_, util)| *util)
+                        .fold(
+                            payoff[player],
+                            |best, util| if util > best { util } else { best },
+                        );
+                if best > payoff[player] {
+                    edges.extend(
+                        deviations
+                            .into_iter()
+                            .filter(|(_, util)| *util == best)
+                            .map(|(to, _)| BestResponseEdge {
+                                from: profile,
+                                to,
+                                player,
+                            }),
+                    );
+                }
+            }
+        }
+        BestResponseGraph::new(nodes, edges)
+    }
+
+    /// All pure [Nash equilibria](https://en.wikipedia.org/wiki/Nash_equilibrium) solutions of a
+    /// finite simultaneous game.
+    ///
+    /// This function simply enumerates all profiles and checks to see if each one is
+    /// [stable](Normal::is_stable).
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let dilemma = Normal::symmetric(
+    ///     vec!['C', 'D'],
+    ///     vec![2, 0, 3, 1],
+    /// ).unwrap();
+    ///
+    /// let hunt = Normal::symmetric(
+    ///     vec!['C', 'D'],
+    ///     vec![3, 0, 2, 1],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     dilemma.pure_nash_equilibria(),
+    ///     vec![Profile::new(['D', 'D'])],
+    /// );
+    /// assert_eq!(
+    ///     hunt.pure_nash_equilibria(),
+    ///     vec![Profile::new(['C', 'C']), Profile::new(['D', 'D'])],
+    /// );
+    /// ```
+    pub fn pure_nash_equilibria(&self) -> Vec<Profile<M, P>> {
+        let mut nash = Vec::new();
+        for profile in self.possible_profiles() {
+            if self.is_stable(profile) {
+                nash.push(profile);
+            }
+        }
+        nash
+    }
+
+    /// A variant of [`pure_nash_equilibria`](Self::pure_nash_equilibria) that analyzes the outcomes
+    /// in parallel.
+    pub fn pure_nash_equilibria_parallel(&self) -> Vec<Profile<M, P>> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.possible_profiles()
+            .par_bridge()
+            .for_each_with(sender, |s, profile| {
+                if self.is_stable(profile) {
+                    s.send(profile).unwrap();
+                }
+            });
+        receiver.iter().collect()
+    }
+
+    /// Does this game have at least one pure Nash equilibrium?
+    ///
+    /// Unlike [`pure_nash_equilibria`](Self::pure_nash_equilibria), this stops analyzing the
+    /// game's profiles as soon as it finds a [stable](Normal::is_stable) one.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let dilemma: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// assert!(dilemma.has_pure_nash());
+    ///
+    /// let rps: Normal<char, i32, 2> = Normal::symmetric(
+    ///     vec!['R', 'P', 'S'],
+    ///     vec![0, -1, 1, 1, 0, -1, -1, 1, 0],
+    /// ).unwrap();
+    /// assert!(!rps.has_pure_nash());
+    /// ```
+    pub fn has_pure_nash(&self) -> bool {
+        self.possible_profiles()
+            .any(|profile| self.is_stable(profile))
+    }
+
+    /// Count the pure Nash equilibria of this game, analyzing the outcomes in parallel.
+    ///
+    /// This is equivalent to the length of
+    /// [`pure_nash_equilibria`](Self::pure_nash_equilibria), but avoids collecting the
+    /// equilibria.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let hunt: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![3, 0, 2, 1]).unwrap();
+    /// assert_eq!(hunt.count_pure_nash(), 2);
+    ///
+    /// let pennies = Normal::matrix(['H', 'T'], ['H', 'T'], [[1, -1], [-1, 1]]);
+    /// assert_eq!(pennies.count_pure_nash(), 0);
+    /// ```
+    pub fn count_pure_nash(&self) -> usize {
+        self.possible_profiles()
+            .par_bridge()
+            .filter(|profile| self.is_stable(*profile))
+            .count()
+    }
+
+    /// Return a new profile that represents a
+    /// [Pareto improvement](https://en.wikipedia.org/wiki/Pareto_efficiency)
+    /// on the given profile, if one exists.
+    ///
+    /// A profile is a Pareto improvement over another if the payoff associated with the improved
+    /// profile *increases the utility for at least one player* over the payoff associated with the
+    /// original profile, *without decreasing the utility for any players*.
+    pub fn pareto_improve(&self, profile: Profile<M, P>) -> Option<Profile<M, P>> {
+        if self.is_valid_profile(profile) {
+            let payoff = self.payoff(profile);
+            let mut best_profile = None;
+            let mut best_improvement = <U as Zero>::zero();
+            for outcome in self.possible_outcomes() {
+                if let Some(improvement) = payoff.pareto_improvement(*outcome.payoff()) {
+                    if improvement.gt(&best_improvement) {
+                        best_profile = Some(*outcome.profile());
+                        best_improvement = improvement;
+                    }
+                }
+            }
+            best_profile
+        } else {
+            log::error!(
+                "IsNormal::pareto_improve: invalid initial profile ({:?})",
+                profile,
+            );
+            None
+        }
+    }
+
+    /// A profile is [Pareto optimal](https://en.wikipedia.org/wiki/Pareto_efficiency) if there is
+    /// no other profile that represents a [Pareto improvement](Normal::pareto_improve).
+    pub fn is_pareto_optimal(&self, profile: Profile<M, P>) -> bool {
+        self.pareto_improve(profile).is_none()
+    }
+
+    /// Get all profiles that are [Pareto optimal](Normal::is_pareto_optimal).
+    pub fn pareto_optimal_solutions(&self) -> Vec<Profile<M, P>> {
+        let mut pareto = Vec::new();
+        for profile in self.possible_profiles() {
+            if self.is_pareto_optimal(profile) {
+                pareto.push(profile)
+            }
+        }
+        pareto
+    }
+
+    /// A variant of [`pareto_optimal_solutions`](Self::pareto_optimal_solutions) that analyzes the
+    /// outcomes in parallel.
+    pub fn pareto_optimal_solutions_parallel(&self) -> Vec<Profile<M, P>> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.possible_profiles()
+            .par_bridge()
+            .for_each_with(sender, |s, profile| {
+                if self.is_pareto_optimal(profile) {
+                    s.send(profile).unwrap();
+                }
+            });
+        receiver.iter().collect()
+    }
+
+    /// Get all profiles that maximize the utility of the worst-off player, that is, the
+    /// [egalitarian](https://en.wikipedia.org/wiki/Egalitarian_rule) or Rawlsian solutions of
+    /// the game.
+    ///
+    /// The profiles are ordered from best to worst by [leximin](Payoff::leximin_cmp), so among
+    /// profiles with the same minimum utility, those that are better for the second-worst-off
+    /// player (and so on) come first. Profiles that are equivalent by leximin retain the order of
+    /// [`possible_profiles`](Self::possible_profiles).
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::bimatrix(
+    ///     ['A', 'B'],
+    ///     ['C', 'D'],
+    ///     [[2, 0], [2, 1]],
+    ///     [[2, 9], [3, 1]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     g.egalitarian_solutions(),
+    ///     vec![Profile::new(['B', 'C']), Profile::new(['A', 'C'])],
+    /// );
+    /// ```
+    pub fn egalitarian_solutions(&self) -> Vec<Profile<M, P>> {
+        let min_utility = |payoff: &Payoff<U, P>| {
+            payoff
+                .iter()
+                .copied()
+                .reduce(|a, b| if b < a { b } else { a })
+        };
+
+        let mut best: Option<U> = None;
+        let mut solutions: Vec<(Profile<M, P>, Payoff<U, P>)> = Vec::new();
+        for outcome in self.possible_outcomes() {
+            let payoff = *outcome.payoff();
+            let min = min_utility(&payoff);
+            if best.is_none() || min > best {
+                best = min;
+                solutions.clear();
+            }
+            if min == best {
+                solutions.push((*outcome.profile(), payoff));
+            }
+        }
+
+        solutions.sort_by(|(_, a), (_, b)| b.leximin_cmp(a));
+        solutions.into_iter().map(|(profile, _)| profile).collect()
+    }
+
+    /// Compute the [price of anarchy](https://en.wikipedia.org/wiki/Price_of_anarchy) of the game
+    /// over its [pure Nash equilibria](Self::pure_nash_equilibria).
+    ///
+    /// The price of anarchy is the ratio of the maximum social welfare (the sum of all players'
+    /// utilities) over all profiles to the social welfare of the worst equilibrium. It measures
+    /// how much efficiency is lost when players act selfishly, where a value of `1.0` means that
+    /// no efficiency is lost.
+    ///
+    /// Returns `None` if the game has no pure equilibria, or if the welfare of the worst
+    /// equilibrium is not positive, in which case the ratio is not meaningful. For two-player
+    /// games, see also [`mixed_price_of_anarchy`](Normal::mixed_price_of_anarchy).
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // In the prisoner's dilemma, mutual defection yields a welfare of 2, while mutual
+    /// // cooperation would yield a welfare of 4.
+    /// let pd: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// assert_eq!(pd.price_of_anarchy(), Some(2.0));
+    ///
+    /// // Matching pennies has no pure equilibria.
+    /// let pennies = Normal::bimatrix(['H', 'T'], ['H', 'T'], [[2, 0], [0, 2]], [[0, 2], [2, 0]]);
+    /// assert_eq!(pennies.price_of_anarchy(), None);
+    /// ```
+    pub fn price_of_anarchy(&self) -> Option<f64>
+    where
+        U: ToPrimitive,
+    {
+        let worst = self
+            .pure_nash_equilibria()
+            .into_iter()
+            .map(|profile| welfare(&self.payoff(profile)))
+            .reduce(f64::min)?;
+        welfare_ratio("Normal::price_of_anarchy", self.max_welfare(), worst)
+    }
+
+    /// Get the maximum social welfare over all profiles of the game, converted to `f64`.
+    fn max_welfare(&self) -> f64
+    where
+        U: ToPrimitive,
+    {
+        self.possible_outcomes()
+            .map(|outcome| welfare(outcome.payoff()))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Get all dominated move relationships for the given player. If a move is dominated by
+    /// multiple different moves, it will contain multiple entries in the returned vector.
+    ///
+    /// See the documentation for [`Dominated`] for more info.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::from_payoff_vec(
+    ///     PerPlayer::new([
+    ///         vec!['A', 'B', 'C'],
+    ///         vec!['D', 'E'],
+    ///     ]),
+    ///     vec![
+    ///         Payoff::from([3, 3]), Payoff::from([3, 5]),
+    ///         Payoff::from([2, 0]), Payoff::from([3, 1]),
+    ///         Payoff::from([4, 0]), Payoff::from([2, 1]),
+    ///     ],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(g.dominated_moves_for(for2::P0), vec![Dominated::weak('B', 'A')]);
+    /// assert_eq!(g.dominated_moves_for(for2::P1), vec![Dominated::strict('D', 'E')]);
+    /// ```
+    pub fn dominated_moves_for(&self, player: PlayerIndex<P>) -> Vec<Dominated<M>> {
+        let mut dominated = Vec::new();
+
+        for maybe_ted in self.possible_moves_for_player(player) {
+            let ted_iter = self.possible_outcomes().include(player, maybe_ted);
+
+            for maybe_tor in self.possible_moves_for_player(player) {
+                if maybe_ted == maybe_tor {
+                    continue;
+                }
+
+                let tor_iter = self.possible_outcomes().include(player, maybe_tor);
+
+                let mut is_dominated = true;
+                let mut is_strict = true;
+                for (ted_outcome, tor_outcome) in ted_iter.clone().zip(tor_iter) {
+                    let ted_payoff = ted_outcome.payoff();
+                    let tor_payoff = tor_outcome.payoff();
+                    if let Some(ordering) = ted_payoff[player].partial_cmp(&tor_payoff[player]) {
+                        match ordering {
+                            Ordering::Less => {}
+                            Ordering::Equal => is_strict = false,
+                            Ordering::Greater => {
+                                is_dominated = false;
+                                break;
+                            }
+                        }
+                    }
+                }
+                if is_dominated {
+                    dominated.push(Dominated {
+                        dominated: maybe_ted,
+                        dominator: maybe_tor,
+                        is_strict,
+                    });
+                }
+            }
+        }
+        dominated
+    }
+
+    /// Get all dominated move relationships for each player.
+    pub fn dominated_moves(&self) -> PerPlayer<Vec<Dominated<M>>, P> {
+        PerPlayer::generate(|index| self.dominated_moves_for(index))
+    }
+
+    /// Get the strictly dominant move for the given player, if there is one. A move is strictly
+    /// dominant if it [strictly dominates](Dominated) every other move available to the player.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// assert_eq!(pd.dominant_move_for(for2::P0), Some('D'));
+    /// assert_eq!(pd.dominant_move_for(for2::P1), Some('D'));
+    ///
+    /// let stag_hunt = Normal::symmetric(vec!['C', 'D'], vec![3, 0, 2, 1]).unwrap();
+    /// assert_eq!(stag_hunt.dominant_move_for(for2::P0), None);
+    /// ```
+    pub fn dominant_move_for(&self, player: PlayerIndex<P>) -> Option<M> {
+        let dominated = self.dominated_moves_for(player);
+        self.possible_moves_for_player(player).find(|&candidate| {
+            self.possible_moves_for_player(player)
+                .filter(|&other| other != candidate)
+                .all(|other| dominated.contains(&Dominated::strict(other, candidate)))
+        })
+    }
+
+    /// Get the [dominant-strategy equilibrium](https://en.wikipedia.org/wiki/Strategic_dominance)
+    /// of the game, if there is one. This is the profile in which every player plays a dominant
+    /// move.
+    ///
+    /// If `strict` is true, each player's move must [strictly dominate](Self::dominant_move_for)
+    /// every other move available to them. Otherwise, each player's move need only weakly
+    /// dominate every other move, that is, always yield a greater or equal utility. Returns `None`
+    /// if any player has no such move.
+    ///
+    /// A dominant-strategy equilibrium is always a [pure Nash equilibrium](Self::is_stable), but is a
+    /// stronger solution concept since no player needs to predict what the others will play.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// assert_eq!(pd.dominant_strategy_equilibrium(true), Some(Profile::new(['D', 'D'])));
+    ///
+    /// let coordination: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['A', 'B'], vec![1, 0, 0, 1]).unwrap();
+    /// assert_eq!(coordination.dominant_strategy_equilibrium(false), None);
+    /// ```
+    pub fn dominant_strategy_equilibrium(&self, strict: bool) -> Option<Profile<M, P>> {
+        PerPlayer::generate(|player| {
+            if strict {
+                return self.dominant_move_for(player);
+            }
+            let dominated = self.dominated_moves_for(player);
+            self.possible_moves_for_player(player).find(|&candidate| {
+                self.possible_moves_for_player(player)
+                    .filter(|&other| other != candidate)
+                    .all(|other| {
+                        dominated
+                            .iter()
+                            .any(|dom| dom.dominated == other && dom.dominator == candidate)
+                    })
+            })
+        })
+        .all_some()
+        .map(Profile::from_per_player)
+    }
+
+    /// Check whether reporting one's true type is a dominant strategy for every player, where
+    /// each player's type is one of their moves, as in a direct
+    /// [mechanism](https://en.wikipedia.org/wiki/Mechanism_design).
+    ///
+    /// The game's payoffs should reflect the given true types, for example, the players' true
+    /// valuations in an auction. The mechanism is
+    /// [truthful](https://en.wikipedia.org/wiki/Incentive_compatibility), or dominant-strategy
+    /// incentive-compatible, if each player's true type is a best response to every combination
+    /// of moves by the other players, that is, if the player never has any
+    /// [regret](Self::regret) for reporting it truthfully.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `false` if the true types are not a valid profile.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // A second-price auction: the highest bid wins and pays the other bid, and ties split
+    /// // the item. The bidders value the item at 2 and 3.
+    /// let values = [2.0, 3.0];
+    /// let auction = Normal::from_payoff_fn(
+    ///     PerPlayer::new([vec![0, 1, 2, 3], vec![0, 1, 2, 3]]),
+    ///     move |bids: Profile<u8, 2>| {
+    ///         let (b0, b1) = (bids[for2::P0], bids[for2::P1]);
+    ///         let surplus = |value: f64, price: u8| value - f64::from(price);
+    ///         match b0.cmp(&b1) {
+    ///             std::cmp::Ordering::Greater => Payoff::from([surplus(values[0], b1), 0.0]),
+    ///             std::cmp::Ordering::Less => Payoff::from([0.0, surplus(values[1], b0)]),
+    ///             std::cmp::Ordering::Equal => {
+    ///                 Payoff::from([surplus(values[0], b0) / 2.0, surplus(values[1], b1) / 2.0])
+    ///             }
+    ///         }
+    ///     },
+    /// );
+    ///
+    /// assert!(auction.is_truthful(Profile::new([2, 3])));
+    /// assert!(!auction.is_truthful(Profile::new([3, 3])));
+    /// ```
+    pub fn is_truthful(&self, true_types: Profile<M, P>) -> bool {
+        if !self.is_valid_profile(true_types) {
+            log::error!("Normal::is_truthful: invalid true types ({:?})", true_types);
+            return false;
+        }
+        PlayerIndex::all().all(|player| {
+            self.possible_profiles()
+                .include(player, true_types[player])
+                .all(|profile| self.regret(player, profile) == U::zero())
+        })
+    }
+
+    /// Construct a new game in which the given move is removed from the given player's available
+    /// moves. The payoffs of all remaining profiles are unchanged.
+    ///
+    /// This is the primitive operation underlying
+    /// [iterated elimination of dominated moves](Normal::iterated_dominance_trace). Since the
+    /// result of eliminating weakly dominated moves may depend on the order of elimination, this
+    /// method can be used to script a particular order and inspect each intermediate game. Note
+    /// that this method does not check that the move is actually dominated.
+    ///
+    /// # Errors
+    ///
+    /// Logs a warning and returns an unchanged copy of the game if the move is not available to
+    /// the player.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::bimatrix(
+    ///     ['U', 'D'],
+    ///     ['L', 'M', 'R'],
+    ///     [[1, 1, 0], [0, 0, 2]],
+    ///     [[0, 2, 1], [3, 1, 0]],
+    /// );
+    ///
+    /// let reduced = g.remove_dominated_move(for2::P1, 'R');
+    /// assert_eq!(reduced.possible_moves_for_player(for2::P1).collect::<Vec<_>>(), vec!['L', 'M']);
+    /// assert_eq!(reduced.possible_profiles().count(), 4);
+    /// assert_eq!(reduced.dominated_moves_for(for2::P0), vec![Dominated::strict('D', 'U')]);
+    /// ```
+    pub fn remove_dominated_move(&self, player: PlayerIndex<P>, dominated: M) -> Normal<M, U, P> {
+        let mut reduced = self.clone();
+        if !self.is_valid_move_for_player(player, dominated) {
+            log::warn!(
+                "Normal::remove_dominated_move: move {:?} is not available to player {}",
+                dominated,
+                player
+            );
+            return reduced;
+        }
+        reduced.moves[player].retain(|m| *m != dominated);
+        reduced
+    }
+
+    /// Get the game restricted to the given supports, that is, the game in which each player may
+    /// only play the moves in their corresponding support.
+    ///
+    /// This is the primitive that support enumeration iterates over: a fully mixed equilibrium of
+    /// the restricted game is a candidate equilibrium of the original game.
+    ///
+    /// # Errors
+    ///
+    /// Logs a warning and omits from the restricted game any move in a support that is not
+    /// available to the corresponding player, or that appears in the support more than once.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let rps: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+    ///
+    /// let rp = rps.on_supports(PerPlayer::new([vec!['R', 'P'], vec!['R', 'P']]));
+    /// assert_eq!(rp.dimensions(), PerPlayer::new([2, 2]));
+    /// assert_eq!(rp.pure_nash_equilibria(), vec![Profile::new(['P', 'P'])]);
+    /// ```
+    pub fn on_supports(&self, supports: PerPlayer<Vec<M>, P>) -> Normal<M, U, P> {
+        let mut restricted = self.clone();
+        for player in PlayerIndex::all() {
+            let mut support: Vec<M> = Vec::with_capacity(supports[player].len());
+            for &the_move in &supports[player] {
+                if !self.is_valid_move_for_player(player, the_move) {
+                    log::warn!(
+                        "Normal::on_supports: move {:?} is not available to player {}",
+                        the_move,
+                        player
+                    );
+                } else if support.contains(&the_move) {
+                    log::warn!(
+                        "Normal::on_supports: duplicate move {:?} in the support of player {}",
+                        the_move,
+                        player
+                    );
+                } else {
+                    support.push(the_move);
+                }
+            }
+            restricted.moves[player] = support;
+        }
+        restricted
+    }
+
+    /// Get the sequence of eliminations performed by
+    /// [iterated elimination of dominated moves](https://en.wikipedia.org/wiki/Strategic_dominance#Iterated_elimination_of_strictly_dominated_strategies).
+    ///
+    /// Each element of the result is a triple `(player, eliminated, dominator)` indicating that
+    /// `eliminated` was removed from `player`'s available moves because it was dominated by
+    /// `dominator` in the game reduced by all previous eliminations. If `strict_only` is true,
+    /// only strictly dominated moves are eliminated; otherwise weakly dominated moves are too.
+    ///
+    /// Moves are eliminated one at a time. At each step, players are considered in order of their
+    /// index and each player's moves in the order they were provided, and the first dominated move
+    /// found is eliminated along with the first move found to dominate it. The process ends when
+    /// no player has a dominated move. Note that for weak dominance, the final reduced game may
+    /// depend on this order.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::bimatrix(
+    ///     ['U', 'D'],
+    ///     ['L', 'M', 'R'],
+    ///     [[1, 1, 0], [0, 0, 2]],
+    ///     [[0, 2, 1], [3, 1, 0]],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     g.iterated_dominance_trace(true),
+    ///     vec![(for2::P1, 'R', 'M'), (for2::P0, 'D', 'U'), (for2::P1, 'L', 'M')],
+    /// );
+    /// ```
+    pub fn iterated_dominance_trace(&self, strict_only: bool) -> Vec<(PlayerIndex<P>, M, M)> {
+        let mut trace = Vec::new();
+        let mut reduced = self.clone();
+        loop {
+            let elimination = PlayerIndex::all().find_map(|player| {
+                reduced
+                    .dominated_moves_for(player)
+                    .into_iter()
+                    .find(|dom| dom.is_strict || !strict_only)
+                    .map(|dom| (player, dom.dominated, dom.dominator))
+            });
+            match elimination {
+                Some((player, dominated, dominator)) => {
+                    reduced = reduced.remove_dominated_move(player, dominated);
+                    trace.push((player, dominated, dominator));
+                }
+                None => return trace,
+            }
+        }
+    }
+
+    /// If this game is
+    /// [dominance solvable](https://en.wikipedia.org/wiki/Strategic_dominance#Iterated_elimination_of_strictly_dominated_strategies),
+    /// get the unique profile that survives the iterated elimination of strictly dominated moves.
+    /// Otherwise, return `None`.
+    ///
+    /// The elimination follows the order of
+    /// [`iterated_dominance_trace`](Normal::iterated_dominance_trace) with `strict_only` set.
+    /// Since the result of iteratively eliminating strictly dominated moves does not depend on the
+    /// order of elimination, neither does the result of this method.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let dilemma: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// assert_eq!(dilemma.is_dominance_solvable(), Some(Profile::new(['D', 'D'])));
+    ///
+    /// let coordination: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['A', 'B'], vec![1, 0, 0, 1]).unwrap();
+    /// assert_eq!(coordination.is_dominance_solvable(), None);
+    /// ```
+    pub fn is_dominance_solvable(&self) -> Option<Profile<M, P>> {
+        let mut remaining = self.moves.clone();
+        for (player, dominated, _) in self.iterated_dominance_trace(true) {
+            remaining[player].retain(|m| *m != dominated);
+        }
+        PerPlayer::generate(|player| match remaining[player].as_slice() {
+            [the_move] => Some(*the_move),
+            _ => None,
+        })
+        .all_some()
+        .map(Profile::from_per_player)
+    }
+
+    /// Check that the given reduced game, obtained by eliminating moves from this game, has
+    /// exactly the same pure Nash equilibria as this game.
+    ///
+    /// Iteratively eliminating strictly dominated moves always preserves the pure Nash equilibria
+    /// of a game, so this is a sanity check for elimination procedures, such as those built on
+    /// [`remove_dominated_move`](Normal::remove_dominated_move). Every equilibrium of a reduced
+    /// game is also an equilibrium of the original game, even after eliminating weakly dominated
+    /// moves, so this check fails exactly when the reduced game has lost an equilibrium of the
+    /// original game, as eliminating weakly dominated moves may.
+    ///
+    /// # Errors
+    ///
+    /// Logs a warning and returns `false` if the reduced game is not a restriction of this game,
+    /// that is, if it has moves unavailable in this game or payoffs that differ from this game's.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::bimatrix(['U', 'D'], ['L', 'R'], [[1, 1], [1, 0]], [[1, 0], [1, 1]]);
+    ///
+    /// // D is weakly dominated by U, but eliminating it loses the equilibrium (D, L).
+    /// assert!(!g.nash_preserved_under(&g.remove_dominated_move(for2::P0, 'D')));
+    ///
+    /// // Neither player has a strictly dominated move, so the game is preserved trivially.
+    /// assert!(g.iterated_dominance_trace(true).is_empty());
+    /// assert!(g.nash_preserved_under(&g));
+    /// ```
+    pub fn nash_preserved_under(&self, reduced: &Normal<M, U, P>) -> bool {
+        let is_restriction = PlayerIndex::all().all(|player| {
+            reduced
+                .possible_moves_for_player(player)
+                .all(|the_move| self.is_valid_move_for_player(player, the_move))
+        }) && reduced
+            .possible_profiles()
+            .all(|profile| reduced.payoff(profile) == self.payoff(profile));
+        if !is_restriction {
+            log::warn!(
+                "Normal::nash_preserved_under: the reduced game is not a restriction of this game"
+            );
+            return false;
+        }
+        let original: HashSet<Profile<M, P>> = self.pure_nash_equilibria().into_iter().collect();
+        let remaining: HashSet<Profile<M, P>> =
+            reduced.pure_nash_equilibria().into_iter().collect();
+        original == remaining
+    }
+
+    /// Approximate the [logit quantal response equilibrium](https://en.wikipedia.org/wiki/Quantal_response_equilibrium)
+    /// (QRE) of this game with rationality parameter `lambda`.
+    ///
+    /// In a QRE, players are boundedly rational: each player plays every move with probability
+    /// proportional to `exp(lambda * u)`, where `u` is the move's expected utility against the
+    /// other players' mixed strategies. As `lambda` approaches zero, players choose uniformly at
+    /// random; as `lambda` grows, play approaches a Nash equilibrium.
+    ///
+    /// The fixed point is found by starting from uniform play and repeatedly updating every
+    /// player's mixed strategy toward their logit response for the given number of `iterations`.
+    /// Each update moves halfway toward the logit response, which damps the oscillations that
+    /// plain iterated responses exhibit in games like matching pennies.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `lambda` is negative or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let dilemma: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// let random = dilemma.logit_qre(0.0, 100).unwrap();
+    /// assert_eq!(
+    ///     random[for2::P0].probabilities().collect::<Vec<_>>(),
+    ///     vec![(&'C', 0.5), (&'D', 0.5)],
+    /// );
+    ///
+    /// let rational = dilemma.logit_qre(10.0, 100).unwrap();
+    /// for player in PlayerIndex::all() {
+    ///     assert_eq!(rational[player].most_likely(), &'D');
+    /// }
+    ///
+    /// assert!(dilemma.logit_qre(-1.0, 100).is_none());
+    /// ```
+    pub fn logit_qre(&self, lambda: f64, iterations: usize) -> Option<PerPlayer<Distribution<M>, P>>
+    where
+        U: ToPrimitive,
+    {
+        if !lambda.is_finite() || lambda < 0.0 {
+            log::error!(
+                "Normal::logit_qre: lambda must be finite and non-negative, got {}",
+                lambda
+            );
+            return None;
+        }
+
+        let dimensions = self.dimensions();
+        let payoffs = self.indexed_payoffs();
+        let mut probs: PerPlayer<Vec<f64>, P> =
+            PerPlayer::generate(|player| vec![1.0 / dimensions[player] as f64; dimensions[player]]);
+        for _ in 0..iterations {
+            let expected = self.expected_move_utilities(&payoffs, &probs);
+            for player in PlayerIndex::all() {
+                let max = expected[player]
+                    .iter()
+                    .copied()
+                    .fold(f64::NEG_INFINITY, f64::max);
+                let weights: Vec<f64> = expected[player]
+                    .iter()
+                    .map(|u| (lambda * (u - max)).exp())
+                    .collect();
+                let total: f64 = weights.iter().sum();
+                for (prob, weight) in probs[player].iter_mut().zip(weights) {
+                    *prob = 0.5 * *prob + 0.5 * weight / total;
+                }
+            }
+        }
+
+        PerPlayer::generate(|player| {
+            Distribution::new(
+                self.moves[player]
+                    .iter()
+                    .copied()
+                    .zip(probs[player].iter().copied())
+                    .collect(),
+            )
+        })
+        .all_some()
+    }
+
+    /// Compute the [exploitability](https://arxiv.org/abs/1711.00832) (also called "NashConv") of
+    /// the given mixed profile, that is, the sum over all players of the most that each player
+    /// could gain in expected utility by unilaterally switching to a best response.
+    ///
+    /// Exploitability is never negative, and it is zero exactly when the profile is a mixed Nash
+    /// equilibrium. This makes it the standard measure of convergence for iterative solvers such
+    /// as no-regret learning, where it should approach zero.
+    ///
+    /// Utility values are converted to `f64` for the computation.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `NaN` if a distribution assigns positive probability to a move
+    /// that is not available to the corresponding player.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let rps = Normal::matrix(
+    ///     ['R', 'P', 'S'],
+    ///     ['R', 'P', 'S'],
+    ///     [[0, -1, 1], [1, 0, -1], [-1, 1, 0]],
+    /// );
+    ///
+    /// let uniform = Distribution::flat(vec!['R', 'P', 'S']).unwrap();
+    /// let equilibrium = PerPlayer::new([uniform.clone(), uniform.clone()]);
+    /// assert!(rps.exploitability(&equilibrium).abs() < 1e-9);
+    ///
+    /// // Always playing rock can be exploited by paper, and the opponent then gains nothing by
+    /// // switching away from the uniform mix.
+    /// let rock = PerPlayer::new([Distribution::singleton('R'), uniform]);
+    /// assert!((rps.exploitability(&rock) - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn exploitability(&self, profile: &PerPlayer<Distribution<M>, P>) -> f64
+    where
+        U: ToPrimitive,
+    {
+        let dimensions = self.dimensions();
+        let mut probs: PerPlayer<Vec<f64>, P> =
+            PerPlayer::generate(|player| vec![0.0; dimensions[player]]);
+        for player in PlayerIndex::all() {
+            for (the_move, prob) in profile[player].probabilities() {
+                match self.moves[player].iter().position(|m| m == the_move) {
+                    Some(index) => probs[player][index] += prob,
+                    None if prob > 0.0 => {
+                        log::error!(
+                            "Normal::exploitability: player {} has no move {:?}",
+                            player,
+                            the_move
+                        );
+                        return f64::NAN;
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        let expected = self.expected_move_utilities(&self.indexed_payoffs(), &probs);
+        PlayerIndex::all()
+            .map(|player| {
+                let best = expected[player]
+                    .iter()
+                    .copied()
+                    .fold(f64::NEG_INFINITY, f64::max);
+                let current: f64 = expected[player]
+                    .iter()
+                    .zip(&probs[player])
+                    .map(|(utility, prob)| prob * utility)
+                    .sum();
+                // Clamp small negative values caused by floating point error.
+                let regret = best - current;
+                if regret < 0.0 {
+                    0.0
+                } else {
+                    regret
+                }
+            })
+            .sum()
+    }
+
+    /// Get the payoff of each profile of this game as `f64` values, paired with the index of each
+    /// player's move in their list of moves.
+    fn indexed_payoffs(&self) -> Vec<(PerPlayer<usize, P>, Payoff<f64, P>)>
+    where
+        U: ToPrimitive,
+    {
+        self.possible_profiles()
+            .map(|profile| {
+                let indexes = PerPlayer::generate(|player| {
+                    self.moves[player]
+                        .iter()
+                        .position(|m| *m == profile[player])
+                        .unwrap()
+                });
+                let payoff = self.payoff(profile).map(|u| u.to_f64().unwrap_or(f64::NAN));
+                (indexes, payoff)
+            })
+            .collect()
+    }
+
+    /// Compute the expected utility of each of each player's moves when the other players play
+    /// the given mixed strategies, where `probs` gives the probability of each move by index and
+    /// `payoffs` is the result of [`indexed_payoffs`](Self::indexed_payoffs).
+    fn expected_move_utilities(
+        &self,
+        payoffs: &[(PerPlayer<usize, P>, Payoff<f64, P>)],
+        probs: &PerPlayer<Vec<f64>, P>,
+    ) -> PerPlayer<Vec<f64>, P> {
+        let dimensions = self.dimensions();
+        let mut expected: PerPlayer<Vec<f64>, P> =
+            PerPlayer::generate(|player| vec![0.0; dimensions[player]]);
+        for (indexes, payoff) in payoffs {
+            for player in PlayerIndex::all() {
+                let others: f64 = PlayerIndex::all()
+                    .filter(|other| *other != player)
+                    .map(|other| probs[other][indexes[other]])
+                    .product();
+                expected[player][indexes[player]] += others * payoff[player];
+            }
+        }
+        expected
+    }
+}
+
+impl<M: Move, U: Utility> Normal<M, U, 2> {
+    /// Construct a matrix game, a two-player zero-sum game where the payoffs are defined by a
+    /// single matrix of utility values.
+    ///
+    /// Constructed from the list of moves for each player and the matrix (in row major order) of
+    /// utility values for player `P0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a player's moves contain a duplicate. Use [`try_matrix`](Normal::try_matrix) to
+    /// get `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::matrix(
+    ///     ['A', 'B', 'C'],
+    ///     ['D', 'E'],
+    ///     [[-3, -1],
+    ///      [ 0,  2],
+    ///      [ 4,  6]],
+    /// );
+    ///
+    /// assert!(g.is_zero_sum());
+    /// assert_eq!(g.payoff(Profile::new(['A', 'D'])), Payoff::from([-3, 3]));
+    /// assert_eq!(g.payoff(Profile::new(['A', 'E'])), Payoff::from([-1, 1]));
+    /// assert_eq!(g.payoff(Profile::new(['B', 'D'])), Payoff::from([0, 0]));
+    /// assert_eq!(g.payoff(Profile::new(['B', 'E'])), Payoff::from([2, -2]));
+    /// assert_eq!(g.payoff(Profile::new(['C', 'D'])), Payoff::from([4, -4]));
+    /// assert_eq!(g.payoff(Profile::new(['C', 'E'])), Payoff::from([6, -6]));
+    /// ```
+    pub fn matrix<const ROWS: usize, const COLS: usize>(
+        row_moves: [M; ROWS],
+        col_moves: [M; COLS],
+        row_utils: [[U; COLS]; ROWS],
+    ) -> Self {
+        Self::try_matrix(row_moves, col_moves, row_utils)
+            .expect("Normal::matrix: a player's moves contain a duplicate")
+    }
+
+    /// Construct a matrix game, as with [`matrix`](Normal::matrix).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if a player's moves contain a duplicate.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// assert!(Normal::try_matrix(['A', 'B'], ['C', 'D'], [[1, 2], [3, 4]]).is_some());
+    /// assert!(Normal::try_matrix(['A', 'A'], ['C', 'D'], [[1, 2], [3, 4]]).is_none());
+    /// ```
+    pub fn try_matrix<const ROWS: usize, const COLS: usize>(
+        row_moves: [M; ROWS],
+        col_moves: [M; COLS],
+        row_utils: [[U; COLS]; ROWS],
+    ) -> Option<Self> {
+        let moves = PerPlayer::new([row_moves.to_vec(), col_moves.to_vec()]);
+        if Self::has_duplicate_moves("try_matrix", &moves) {
+            return None;
+        }
+        let mut payoff_map = HashMap::with_capacity(ROWS * COLS);
+        for (r, row_move) in row_moves.into_iter().enumerate() {
+            for (c, col_move) in col_moves.into_iter().enumerate() {
+                let row_util = row_utils[r][c];
+                let payoff = Payoff::from([row_util, U::zero().sub(row_util)]);
+                let profile = Profile::new([row_move, col_move]);
+                payoff_map.insert(profile, payoff);
+            }
+        }
+        Some(Normal::from_payoff_map(moves, payoff_map))
+    }
+
+    /// Construct a [bimatrix game](https://en.wikipedia.org/wiki/Bimatrix_game), a two-player
+    /// game where the payoffs are defined by two matrices of utilities, one for each player.
+    ///
+    /// Constructed from the list of moves and the matrix (in row major order) of utility values
+    /// for each player.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a player's moves contain a duplicate. Use [`try_bimatrix`](Normal::try_bimatrix)
+    /// to get `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::bimatrix(
+    ///     ['A', 'B', 'C'],
+    ///     ['D', 'E'],
+    ///     [[0, 5], [4, 3], [2, 1]],
+    ///     [[5, 0], [1, 2], [4, 3]],
+    /// );
+    ///
+    /// assert_eq!(g.payoff(Profile::new(['A', 'D'])), Payoff::from([0, 5]));
+    /// assert_eq!(g.payoff(Profile::new(['A', 'E'])), Payoff::from([5, 0]));
+    /// assert_eq!(g.payoff(Profile::new(['B', 'D'])), Payoff::from([4, 1]));
+    /// assert_eq!(g.payoff(Profile::new(['B', 'E'])), Payoff::from([3, 2]));
+    /// assert_eq!(g.payoff(Profile::new(['C', 'D'])), Payoff::from([2, 4]));
+    /// assert_eq!(g.payoff(Profile::new(['C', 'E'])), Payoff::from([1, 3]));
+    /// ```
+    pub fn bimatrix<const ROWS: usize, const COLS: usize>(
+        row_moves: [M; ROWS],
+        col_moves: [M; COLS],
+        row_utils: [[U; COLS]; ROWS],
+        col_utils: [[U; COLS]; ROWS],
+    ) -> Self {
+        Self::try_bimatrix(row_moves, col_moves, row_utils, col_utils)
+            .expect("Normal::bimatrix: a player's moves contain a duplicate")
+    }
+
+    /// Construct a bimatrix game, as with [`bimatrix`](Normal::bimatrix).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if a player's moves contain a duplicate.
+    pub fn try_bimatrix<const ROWS: usize, const COLS: usize>(
+        row_moves: [M; ROWS],
+        col_moves: [M; COLS],
+        row_utils: [[U; COLS]; ROWS],
+        col_utils: [[U; COLS]; ROWS],
+    ) -> Option<Self> {
+        let moves = PerPlayer::new([row_moves.to_vec(), col_moves.to_vec()]);
+        if Self::has_duplicate_moves("try_bimatrix", &moves) {
+            return None;
+        }
+        let mut payoff_map = HashMap::with_capacity(ROWS * COLS);
+        for (r, row_move) in row_moves.into_iter().enumerate() {
+            for (c, col_move) in col_moves.into_iter().enumerate() {
+                let profile = Profile::new([row_move, col_move]);
+                let payoff = Payoff::from([row_utils[r][c], col_utils[r][c]]);
+                payoff_map.insert(profile, payoff);
+            }
+        }
+        Some(Normal::from_payoff_map(moves, payoff_map))
+    }
+
+    /// Construct the symmetric game induced by assigning the roles of this game at random, with
+    /// each assignment equally likely.
+    ///
+    /// In the resulting game, the payoff to a player for each profile is the average of their
+    /// utility in that profile in either role. That is, if `g` is this game, then in the
+    /// symmetrized game, the utility of `P0` for profile `(a, b)` is the average of `g(a, b)` for
+    /// `P0` and `g(b, a)` for `P1`, and vice versa for `P1`. Symmetrizing a game that is already
+    /// symmetric yields an equivalent game. This is useful for evolutionary analysis, where
+    /// members of a single population are matched in randomly assigned roles.
+    ///
+    /// Since utilities are averaged by dividing their sum by two, the averages of integer
+    /// utilities are rounded toward zero.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the two players do not have the same set of moves.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let g = Normal::bimatrix(
+    ///     ['A', 'B'],
+    ///     ['A', 'B'],
+    ///     [[4, 0], [6, 2]],
+    ///     [[2, 8], [0, 4]],
+    /// );
+    ///
+    /// let sym = g.symmetrize().unwrap();
+    /// assert_eq!(sym.payoff(Profile::new(['A', 'A'])), Payoff::from([3, 3]));
+    /// assert_eq!(sym.payoff(Profile::new(['A', 'B'])), Payoff::from([0, 7]));
+    /// assert_eq!(sym.payoff(Profile::new(['B', 'A'])), Payoff::from([7, 0]));
+    /// assert_eq!(sym.payoff(Profile::new(['B', 'B'])), Payoff::from([3, 3]));
+    ///
+    /// let mismatched = Normal::bimatrix(['A', 'B'], ['A', 'C'], [[0, 0], [0, 0]], [[0, 0], [0, 0]]);
+    /// assert!(mismatched.symmetrize().is_none());
+    /// ```
+    pub fn symmetrize(&self) -> Option<Self> {
+        let row_moves = &self.moves[for2::P0];
+        let col_moves = &self.moves[for2::P1];
+        if row_moves.len() != col_moves.len() || !row_moves.iter().all(|m| col_moves.contains(m)) {
+            log::error!(
+                "Normal::symmetrize: players must have the same moves, got {:?} and {:?}",
+                row_moves,
+                col_moves,
+            );
+            return None;
+        }
+
+        let two = U::one() + U::one();
+        let mut payoff_map = HashMap::with_capacity(row_moves.len() * col_moves.len());
+        for &a in row_moves {
+            for &b in col_moves {
+                let payoff = self.payoff(Profile::new([a, b]));
+                let swapped = self.payoff(Profile::new([b, a]));
+                let symmetrized = Payoff::from([
+                    (payoff[for2::P0] + swapped[for2::P1]) / two,
+                    (payoff[for2::P1] + swapped[for2::P0]) / two,
+                ]);
+                payoff_map.insert(Profile::new([a, b]), symmetrized);
+            }
+        }
+        Some(Normal::from_payoff_map(self.moves.clone(), payoff_map))
+    }
+
+    /// Construct a bimatrix game from CSV data.
+    ///
+    /// The expected layout is:
+    /// - The first row is a header. Its first cell is ignored (and may be empty); the remaining
+    ///   cells are the labels of the moves available to the column player (`P1`).
+    /// - Each subsequent row starts with the label of a move available to the row player (`P0`),
+    ///   followed by one cell for each column move.
+    /// - Each payoff cell has the form `u0;u1`, where `u0` is the row player's utility and `u1` is
+    ///   the column player's utility.
+    ///
+    /// Cells are separated by commas and surrounding whitespace is trimmed. Empty lines are
+    /// skipped. Quoted cells are not supported.
+    ///
+    /// Move labels are parsed with `parse_move` and utility values with `parse_util`; either
+    /// function should return `None` if its input is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CsvError`] if the input cannot be read, has no header row, contains a row with
+    /// the wrong number of cells, or contains a move label or payoff cell that cannot be parsed.
+    /// Also returns an error if two row labels or two column labels are parsed to the same move,
+    /// such as "Apple" and "Alpha" with a `parse_move` that takes the first character.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let csv = "
+    ///     , D, E
+    ///     A, 0;5, 5;0
+    ///     B, 4;1, 3;2
+    /// ";
+    ///
+    /// let g = Normal::from_csv(
+    ///     csv.as_bytes(),
+    ///     |s| s.chars().next(),
+    ///     |s| s.parse::<i32>().ok(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(g.payoff(Profile::new(['A', 'D'])), Payoff::from([0, 5]));
+    /// assert_eq!(g.payoff(Profile::new(['A', 'E'])), Payoff::from([5, 0]));
+    /// assert_eq!(g.payoff(Profile::new(['B', 'D'])), Payoff::from([4, 1]));
+    /// assert_eq!(g.payoff(Profile::new(['B', 'E'])), Payoff::from([3, 2]));
+    /// ```
+    pub fn from_csv(
+        reader: impl std::io::Read,
+        parse_move: impl Fn(&str) -> Option<M>,
+        parse_util: impl Fn(&str) -> Option<U>,
+    ) -> Result<Self, CsvError> {
+        let mut rows = Vec::new();
+        for (index, line) in std::io::BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                rows.push((index + 1, line));
+            }
+        }
+
+        let mut rows = rows.into_iter();
+        let (header_line, header) = rows.next().ok_or(CsvError::MissingHeader)?;
+        let parse_label = |line: usize, label: &str| {
+            parse_move(label).ok_or_else(|| CsvError::InvalidMove {
+                line,
+                label: label.to_string(),
+            })
+        };
+        let mut col_moves = Vec::new();
+        for label in header.split(',').skip(1).map(|label| label.trim()) {
+            let col_move = parse_label(header_line, label)?;
+            if col_moves.contains(&col_move) {
+                return Err(CsvError::DuplicateMove {
+                    line: header_line,
+                    label: label.to_string(),
+                });
+            }
+            col_moves.push(col_move);
+        }
+
+        let mut row_moves = Vec::new();
+        let mut payoff_map = HashMap::new();
+        for (line, row) in rows {
+            let cells: Vec<&str> = row.split(',').map(|cell| cell.trim()).collect();
+            if cells.len() != col_moves.len() + 1 {
+                return Err(CsvError::RowLength {
+                    line,
+                    expected: col_moves.len() + 1,
+                    found: cells.len(),
+                });
+            }
+            let row_move = parse_label(line, cells[0])?;
+            if row_moves.contains(&row_move) {
+                return Err(CsvError::DuplicateMove {
+                    line,
+                    label: cells[0].to_string(),
+                });
+            }
+            for (col_move, cell) in col_moves.iter().zip(&cells[1..]) {
+                let utils = cell
+                    .split(';')
+                    .map(|util| parse_util(util.trim()))
+                    .collect::<Option<Vec<U>>>()
+                    .and_then(|utils| <[U; 2]>::try_from(utils).ok())
+                    .ok_or_else(|| CsvError::InvalidPayoff {
+                        line,
+                        cell: cell.to_string(),
+                    })?;
+                let profile = Profile::new([row_move, *col_move]);
+                payoff_map.insert(profile, Payoff::from(utils));
+            }
+            row_moves.push(row_move);
+        }
+
+        let moves = PerPlayer::new([row_moves, col_moves]);
+        Ok(Normal::from_payoff_map(moves, payoff_map))
+    }
+
+    /// Get the Nash equilibria of this game in mixed strategies, including pure equilibria, using
+    /// [support enumeration](https://en.wikipedia.org/wiki/Nash_equilibrium#Computing_Nash_equilibria).
+    ///
+    /// For each pair of equally sized supports, this method solves for the mixed strategies that
+    /// make each player indifferent among the moves in their support, then checks that no move
+    /// outside the support is a better response. All equilibria of
+    /// [nondegenerate](https://en.wikipedia.org/wiki/Nash_equilibrium#Nondegenerate_games) games
+    /// are found this way. For degenerate games, some equilibria may be missed, and games with a
+    /// continuum of equilibria are represented by some of its extreme points.
+    ///
+    /// Utility values are converted to `f64` for the computation, so probabilities may be subject
+    /// to small floating point errors. The running time is exponential in the number of moves.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // Battle of the sexes has two pure equilibria and one mixed equilibrium.
+    /// let g = Normal::bimatrix(
+    ///     ['O', 'F'],
+    ///     ['O', 'F'],
+    ///     [[3, 0], [0, 2]],
+    ///     [[2, 0], [0, 3]],
+    /// );
+    ///
+    /// let nash = g.mixed_nash_equilibria();
+    /// assert_eq!(nash.len(), 3);
+    /// assert_eq!(nash[0].to_pure(), Some(Profile::new(['O', 'O'])));
+    /// assert_eq!(nash[1].to_pure(), Some(Profile::new(['F', 'F'])));
+    ///
+    /// let mixed = &nash[2];
+    /// assert!((mixed.probability(for2::P0, 'O') - 0.6).abs() < 1e-9);
+    /// assert!((mixed.probability(for2::P1, 'O') - 0.4).abs() < 1e-9);
+    /// ```
+    pub fn mixed_nash_equilibria(&self) -> Vec<MixedProfile<M, 2>>
+    where
+        U: ToPrimitive,
+    {
+        let max_size = self.moves[for2::P0].len().min(self.moves[for2::P1].len());
+        self.support_enumeration(1..=max_size)
+    }
+
+    /// Get the Nash equilibria of this game in which each player mixes over exactly
+    /// `support_size` moves, using [support enumeration](Normal::mixed_nash_equilibria) restricted
+    /// to supports of that size.
+    ///
+    /// This finds the equilibria of [`mixed_nash_equilibria`](Normal::mixed_nash_equilibria) with
+    /// the given support size, without enumerating supports of any other size. For example, a
+    /// support size of 1 yields the pure equilibria, while a support size equal to the number of
+    /// moves yields the fully mixed equilibria. Returns an empty vector if no player can have a
+    /// support of the given size.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // Battle of the sexes has two pure equilibria and one fully mixed equilibrium.
+    /// let g = Normal::bimatrix(
+    ///     ['O', 'F'],
+    ///     ['O', 'F'],
+    ///     [[3, 0], [0, 2]],
+    ///     [[2, 0], [0, 3]],
+    /// );
+    ///
+    /// let pure = g.nash_equilibria_with_support(1);
+    /// assert_eq!(pure.len(), 2);
+    /// assert!(pure.iter().all(|profile| profile.is_pure()));
+    ///
+    /// let mixed = g.nash_equilibria_with_support(2);
+    /// assert_eq!(mixed.len(), 1);
+    /// assert!((mixed[0].probability(for2::P0, 'O') - 0.6).abs() < 1e-9);
+    ///
+    /// assert!(g.nash_equilibria_with_support(3).is_empty());
+    /// ```
+    pub fn nash_equilibria_with_support(&self, support_size: usize) -> Vec<MixedProfile<M, 2>>
+    where
+        U: ToPrimitive,
+    {
+        if support_size == 0 {
+            return Vec::new();
+        }
+        self.support_enumeration(support_size..=support_size)
+    }
+
+    /// Find the Nash equilibria whose supports have each of the given sizes, by support
+    /// enumeration. See [`mixed_nash_equilibria`](Normal::mixed_nash_equilibria).
+    fn support_enumeration(&self, sizes: RangeInclusive<usize>) -> Vec<MixedProfile<M, 2>>
+    where
+        U: ToPrimitive,
+    {
+        let row_moves = &self.moves[for2::P0];
+        let col_moves = &self.moves[for2::P1];
+        let utils = |player| -> Vec<Vec<f64>> {
+            row_moves
+                .iter()
+                .map(|&r| {
+                    col_moves
+                        .iter()
+                        .map(|&c| {
+                            let payoff = self.payoff(Profile::new([r, c]));
+                            payoff[player].to_f64().unwrap_or(f64::NAN)
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+        let row_utils = utils(for2::P0);
+        let col_utils = transpose(&utils(for2::P1));
+
+        let mut equilibria = Vec::new();
+        for size in sizes {
+            for row_support in (0..row_moves.len()).combinations(size) {
+                for col_support in (0..col_moves.len()).combinations(size) {
+                    let Some(col_probs) = indifferent_mix(&row_utils, &row_support, &col_support)
+                    else {
+                        continue;
+                    };
+                    let Some(row_probs) = indifferent_mix(&col_utils, &col_support, &row_support)
+                    else {
+                        continue;
+                    };
+                    let strategy = |moves: &[M], support: &[usize], probs: Vec<f64>| {
+                        support.iter().map(|&i| moves[i]).zip(probs).collect()
+                    };
+                    equilibria.push(MixedProfile::new(PerPlayer::new([
+                        strategy(row_moves, &row_support, row_probs),
+                        strategy(col_moves, &col_support, col_probs),
+                    ])));
+                }
+            }
+        }
+        equilibria
+    }
+
+    /// Compute the [price of anarchy](https://en.wikipedia.org/wiki/Price_of_anarchy) of the game
+    /// over its [mixed Nash equilibria](Normal::mixed_nash_equilibria).
+    ///
+    /// This is the ratio of the maximum social welfare over all profiles to the lowest expected
+    /// social welfare of any equilibrium. Unlike the [pure variant](Normal::price_of_anarchy),
+    /// this is defined for every game with positive welfare, since every finite game has a mixed
+    /// equilibrium. Returns `None` if no equilibrium is found, or if the expected welfare of the
+    /// worst equilibrium is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // A variant of matching pennies with no pure equilibria, where every outcome has a welfare
+    /// // of 2 and so no efficiency is lost.
+    /// let pennies = Normal::bimatrix(['H', 'T'], ['H', 'T'], [[2, 0], [0, 2]], [[0, 2], [2, 0]]);
+    /// assert_eq!(pennies.price_of_anarchy(), None);
+    /// assert!((pennies.mixed_price_of_anarchy().unwrap() - 1.0).abs() < 1e-9);
+    ///
+    /// // In battle of the sexes, the mixed equilibrium is worse than either pure equilibrium.
+    /// let g = Normal::bimatrix(
+    ///     ['O', 'F'],
+    ///     ['O', 'F'],
+    ///     [[3, 0], [0, 2]],
+    ///     [[2, 0], [0, 3]],
+    /// );
+    /// assert_eq!(g.price_of_anarchy(), Some(1.0));
+    /// assert!((g.mixed_price_of_anarchy().unwrap() - 5.0 / 2.4).abs() < 1e-9);
+    /// ```
+    pub fn mixed_price_of_anarchy(&self) -> Option<f64>
+    where
+        U: ToPrimitive,
+    {
+        let expected_welfare = |equilibrium: &MixedProfile<M, 2>| -> f64 {
+            let mut expected = 0.0;
+            for &(row, p) in equilibrium.for_player(for2::P0) {
+                for &(col, q) in equilibrium.for_player(for2::P1) {
+                    expected += p * q * welfare(&self.payoff(Profile::new([row, col])));
+                }
+            }
+            expected
+        };
+        let worst = self
+            .mixed_nash_equilibria()
+            .iter()
+            .map(expected_welfare)
+            .reduce(f64::min)?;
+        welfare_ratio("Normal::mixed_price_of_anarchy", self.max_welfare(), worst)
+    }
+
+    /// Compute the given player's mixed
+    /// [maximin strategy](https://en.wikipedia.org/wiki/Minimax#Maximin), also called their
+    /// security strategy, along with the expected utility it guarantees.
+    ///
+    /// The maximin strategy maximizes the player's expected utility against the worst-case move of
+    /// the opponent. It is the "safe" strategy that guarantees the returned value regardless of how
+    /// the opponent plays. In a zero-sum game, this coincides with the player's Nash equilibrium
+    /// strategy and the value of the game, but in other games it may differ from any equilibrium.
+    ///
+    /// The strategy is found by solving a linear program with the simplex method. Utility values
+    /// are converted to `f64` for the computation, so probabilities may be subject to small
+    /// floating point errors.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if either player has no moves, or if a utility value
+    /// cannot be converted to a finite `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // Matching pennies, where the maximin strategy is to flip a fair coin.
+    /// let pennies = Normal::matrix(['H', 'T'], ['H', 'T'], [[1, -1], [-1, 1]]);
+    /// let (strategy, value) = pennies.maximin_mixed(for2::P0).unwrap();
+    /// assert!(strategy.probabilities().all(|(_, p)| (p - 0.5).abs() < 1e-9));
+    /// assert!(value.abs() < 1e-9);
+    ///
+    /// // In the prisoner's dilemma, defecting guarantees a utility of 1.
+    /// let pd: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let (strategy, value) = pd.maximin_mixed(for2::P1).unwrap();
+    /// assert_eq!(strategy.probabilities().collect::<Vec<_>>(), vec![(&'D', 1.0)]);
+    /// assert!((value - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn maximin_mixed(&self, player: PlayerIndex<2>) -> Option<(Distribution<M>, f64)>
+    where
+        U: ToPrimitive,
+    {
+        let opponent = player.next();
+        let utils: Vec<Vec<f64>> = self.moves[player]
+            .iter()
+            .map(|&mine| {
+                self.moves[opponent]
+                    .iter()
+                    .map(|&theirs| {
+                        let mut profile = Profile::new([mine, mine]);
+                        profile[opponent] = theirs;
+                        self.payoff(profile)[player].to_f64().unwrap_or(f64::NAN)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let Some((probs, value)) = maximin_mix(&utils) else {
+            log::error!(
+                "Normal::maximin_mixed: no maximin strategy for player {}, since a player has no moves or a utility is not finite",
+                player
+            );
+            return None;
+        };
+        let strategy = self.moves[player]
+            .iter()
+            .copied()
+            .zip(probs)
+            .filter(|(_, prob)| *prob > EPSILON)
+            .collect();
+        Distribution::new(strategy).map(|strategy| (strategy, value))
+    }
+
+    /// Analyze this game, collecting its pure and mixed Nash equilibria, the dominant move of each
+    /// player (if any), and its Pareto optimal profiles into a single report.
+    ///
+    /// The report implements [`Display`](std::fmt::Display) to produce a readable summary.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let report = pd.equilibrium_report();
+    ///
+    /// assert_eq!(report.pure_nash_equilibria(), &[Profile::new(['D', 'D'])]);
+    /// assert!(report.mixed_nash_equilibria().is_empty());
+    /// assert_eq!(report.dominant_moves(), &PerPlayer::new([Some('D'), Some('D')]));
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "Pure Nash equilibria:
+    ///   ('D', 'D')
+    /// Mixed Nash equilibria:
+    ///   none
+    /// Dominant moves:
+    ///   P0: 'D'
+    ///   P1: 'D'
+    /// Pareto optimal profiles:
+    ///   ('C', 'C')
+    ///   ('C', 'D')
+    ///   ('D', 'C')
+    /// ",
+    /// );
+    /// ```
+    pub fn equilibrium_report(&self) -> EquilibriumReport<M>
+    where
+        U: ToPrimitive,
+    {
+        EquilibriumReport::new(
+            self.pure_nash_equilibria(),
+            self.mixed_nash_equilibria()
+                .into_iter()
+                .filter(|mixed| !mixed.is_pure())
+                .collect(),
+            PerPlayer::generate(|player| self.dominant_move_for(player)),
+            self.pareto_optimal_solutions(),
+        )
+    }
+
+    /// Render the game as a bimatrix in which each utility that is a
+    /// [best response](https://en.wikipedia.org/wiki/Best_response) is marked with an asterisk.
+    ///
+    /// This is the classic teaching technique of "underlining the best responses". The row
+    /// player's (`P0`) utility in a cell is marked if no other move in the same column yields
+    /// them a greater utility, and the column player's (`P1`) utility is marked if no other move
+    /// in the same row yields them a greater utility. Cells in which both utilities are marked are
+    /// exactly the [pure Nash equilibria](Self::pure_nash_equilibria) of the game.
+    ///
+    /// The diagram is rendered as a Markdown table with one row per move of the row player and
+    /// one column per move of the column player. Each cell lists the row player's utility, then
+    /// the column player's utility.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     pd.best_response_diagram(),
+    ///     "\
+    /// |   | C     | D      |
+    /// |---|-------|--------|
+    /// | C | 2, 2  | 0, 3*  |
+    /// | D | 3*, 0 | 1*, 1* |
+    /// ",
+    /// );
+    /// ```
+    pub fn best_response_diagram(&self) -> String
+    where
+        M: std::fmt::Display,
+        U: std::fmt::Display,
+    {
+        let rows = &self.moves[for2::P0];
+        let cols = &self.moves[for2::P1];
+        let payoff = |row: M, col: M| self.payoff(Profile::new([row, col]));
+        let mark = |is_best: bool| if is_best { "*" } else { "" };
+
+        let mut table = vec![std::iter::once(String::new())
+            .chain(cols.iter().map(|col| col.to_string()))
+            .collect::<Vec<_>>()];
+        for &row in rows {
+            let mut cells = vec![row.to_string()];
+            for &col in cols {
+                let utils = payoff(row, col);
+                let row_best = rows
+                    .iter()
+                    .all(|&other| payoff(other, col)[for2::P0] <= utils[for2::P0]);
+                let col_best = cols
+                    .iter()
+                    .all(|&other| payoff(row, other)[for2::P1] <= utils[for2::P1]);
+                cells.push(format!(
+                    "{}{}, {}{}",
+                    utils[for2::P0],
+                    mark(row_best),
+                    utils[for2::P1],
+                    mark(col_best)
+                ));
+            }
+            table.push(cells);
+        }
+
+        markdown_table(&table[0], &table[1..], |_| false)
+    }
+
+    /// Construct a [symmetric](https://en.wikipedia.org/wiki/Symmetric_game) two-player
+    /// normal-form game. Constructed from a list of moves available to both players and the
+    /// utility values for the `ROW` player (`P0`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the moves contain a duplicate. Use [`try_symmetric_for2`](Normal::try_symmetric_for2) to get
+    /// `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric_for2(
+    ///     ['C', 'D'],
+    ///     [[2, 0], [3, 1]],
+    /// );
+    ///
+    /// assert_eq!(pd.payoff(Profile::new(['C', 'C'])), Payoff::from([2, 2]));
+    /// assert_eq!(pd.payoff(Profile::new(['C', 'D'])), Payoff::from([0, 3]));
+    /// assert_eq!(pd.payoff(Profile::new(['D', 'C'])), Payoff::from([3, 0]));
+    /// assert_eq!(pd.payoff(Profile::new(['D', 'D'])), Payoff::from([1, 1]));
+    /// ```
+    pub fn symmetric_for2<const SIZE: usize>(
+        moves: [M; SIZE],
+        row_utils: [[U; SIZE]; SIZE],
+    ) -> Self {
+        Self::try_symmetric_for2(moves, row_utils)
+            .expect("Normal::symmetric_for2: the moves contain a duplicate")
+    }
+
+    /// Construct a symmetric two-player normal-form game, as with
+    /// [`symmetric_for2`](Normal::symmetric_for2).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the moves contain a duplicate.
+    pub fn try_symmetric_for2<const SIZE: usize>(
+        moves: [M; SIZE],
+        row_utils: [[U; SIZE]; SIZE],
+    ) -> Option<Self> {
+        let all_moves = PerPlayer::init_with(moves.to_vec());
+        if Self::has_duplicate_moves("try_symmetric_for2", &all_moves) {
+            return None;
+        }
+        let mut payoff_map = HashMap::with_capacity(SIZE * SIZE);
+        for (r, row_move) in moves.into_iter().enumerate() {
+            for (c, col_move) in moves.into_iter().enumerate() {
+                let profile = Profile::new([row_move, col_move]);
+                let payoff = Payoff::from([row_utils[r][c], row_utils[c][r]]);
+                payoff_map.insert(profile, payoff);
+            }
+        }
+        Some(Normal::from_payoff_map(all_moves, payoff_map))
+    }
+}
+
+impl<M: Move, U: Utility> Normal<M, U, 3> {
+    /// Construct a [symmetric](https://en.wikipedia.org/wiki/Symmetric_game) three-player
+    /// normal-form game. Constructed from a list of moves available to all players and the utility
+    /// values for player `P0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the moves contain a duplicate. Use [`try_symmetric_for3`](Normal::try_symmetric_for3) to get
+    /// `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd3 = Normal::symmetric_for3(
+    ///     ['C', 'D'],
+    ///     [[[4, 1], [1, 0]], [[5, 3], [3, 2]]],
+    /// );
+    ///
+    /// assert_eq!(pd3.payoff(Profile::new(['C', 'C', 'C'])), Payoff::from([4, 4, 4]));
+    /// assert_eq!(pd3.payoff(Profile::new(['C', 'C', 'D'])), Payoff::from([1, 1, 5]));
+    /// assert_eq!(pd3.payoff(Profile::new(['C', 'D', 'C'])), Payoff::from([1, 5, 1]));
+    /// assert_eq!(pd3.payoff(Profile::new(['C', 'D', 'D'])), Payoff::from([0, 3, 3]));
+    /// assert_eq!(pd3.payoff(Profile::new(['D', 'C', 'C'])), Payoff::from([5, 1, 1]));
+    /// assert_eq!(pd3.payoff(Profile::new(['D', 'C', 'D'])), Payoff::from([3, 0, 3]));
+    /// assert_eq!(pd3.payoff(Profile::new(['D', 'D', 'C'])), Payoff::from([3, 3, 0]));
+    /// assert_eq!(pd3.payoff(Profile::new(['D', 'D', 'D'])), Payoff::from([2, 2, 2]));
+    /// ```
+    pub fn symmetric_for3<const SIZE: usize>(
+        moves: [M; SIZE],
+        p0_utils: [[[U; SIZE]; SIZE]; SIZE],
+    ) -> Self {
+        Self::try_symmetric_for3(moves, p0_utils)
+            .expect("Normal::symmetric_for3: the moves contain a duplicate")
+    }
+
+    /// Construct a symmetric three-player normal-form game, as with
+    /// [`symmetric_for3`](Normal::symmetric_for3).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the moves contain a duplicate.
+    pub fn try_symmetric_for3<const SIZE: usize>(
+        moves: [M; SIZE],
+        p0_utils: [[[U; SIZE]; SIZE]; SIZE],
+    ) -> Option<Self> {
+        let all_moves = PerPlayer::init_with(moves.to_vec());
+        if Self::has_duplicate_moves("try_symmetric_for3", &all_moves) {
+            return None;
+        }
+        let mut payoff_map = HashMap::with_capacity(SIZE.pow(3));
+        for (i0, m0) in moves.into_iter().enumerate() {
+            for (i1, m1) in moves.into_iter().enumerate() {
+                for (i2, m2) in moves.into_iter().enumerate() {
+                    let u0 = p0_utils[i0][i1][i2];
+                    let u1 = p0_utils[i1][i2][i0];
+                    let u2 = p0_utils[i2][i0][i1];
+                    let payoff = Payoff::from([u0, u1, u2]);
+                    let profile = Profile::new([m0, m1, m2]);
+                    payoff_map.insert(profile, payoff);
+                }
+            }
+        }
+        Some(Normal::from_payoff_map(all_moves, payoff_map))
+    }
+}
+
+impl<M: Move, U: Utility> Normal<M, U, 4> {
+    /// Construct a [symmetric](https://en.wikipedia.org/wiki/Symmetric_game) four-player
+    /// normal-form game. Constructed from a list of moves available to all players and the utility
+    /// values for player `P0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the moves contain a duplicate. Use [`try_symmetric_for4`](Normal::try_symmetric_for4) to get
+    /// `None` instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd4 = Normal::symmetric_for4(
+    ///     ['C', 'D'],
+    ///     [[[[6, 2], [2, 1]], [[2, 1], [1, 0]]],
+    ///      [[[7, 5], [5, 4]], [[5, 4], [4, 3]]]],
+    /// );
+    ///
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'C', 'C'])), Payoff::from([6, 6, 6, 6]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'C', 'D'])), Payoff::from([2, 2, 2, 7]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'D', 'C'])), Payoff::from([2, 2, 7, 2]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'C', 'D', 'D'])), Payoff::from([1, 1, 5, 5]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'C', 'C'])), Payoff::from([2, 7, 2, 2]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'C', 'D'])), Payoff::from([1, 5, 1, 5]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'D', 'C'])), Payoff::from([1, 5, 5, 1]));
+    /// assert_eq!(pd4.payoff(Profile::new(['C', 'D', 'D', 'D'])), Payoff::from([0, 4, 4, 4]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'C', 'C'])), Payoff::from([7, 2, 2, 2]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'C', 'D'])), Payoff::from([5, 1, 1, 5]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'D', 'C'])), Payoff::from([5, 1, 5, 1]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'C', 'D', 'D'])), Payoff::from([4, 0, 4, 4]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'C', 'C'])), Payoff::from([5, 5, 1, 1]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'C', 'D'])), Payoff::from([4, 4, 0, 4]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'D', 'C'])), Payoff::from([4, 4, 4, 0]));
+    /// assert_eq!(pd4.payoff(Profile::new(['D', 'D', 'D', 'D'])), Payoff::from([3, 3, 3, 3]));
+    /// ```
+    pub fn symmetric_for4<const SIZE: usize>(
+        moves: [M; SIZE],
+        p0_utils: [[[[U; SIZE]; SIZE]; SIZE]; SIZE],
+    ) -> Self {
+        Self::try_symmetric_for4(moves, p0_utils)
+            .expect("Normal::symmetric_for4: the moves contain a duplicate")
+    }
+
+    /// Construct a symmetric four-player normal-form game, as with
+    /// [`symmetric_for4`](Normal::symmetric_for4).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the moves contain a duplicate.
+    pub fn try_symmetric_for4<const SIZE: usize>(
+        moves: [M; SIZE],
+        p0_utils: [[[[U; SIZE]; SIZE]; SIZE]; SIZE],
+    ) -> Option<Self> {
+        let all_moves = PerPlayer::init_with(moves.to_vec());
+        if Self::has_duplicate_moves("try_symmetric_for4", &all_moves) {
+            return None;
+        }
+        let mut payoff_map = HashMap::with_capacity(SIZE.pow(4));
+        for (i0, m0) in moves.into_iter().enumerate() {
+            for (i1, m1) in moves.into_iter().enumerate() {
+                for (i2, m2) in moves.into_iter().enumerate() {
+                    for (i3, m3) in moves.into_iter().enumerate() {
+                        let u0 = p0_utils[i0][i1][i2][i3];
+                        let u1 = p0_utils[i1][i2][i3][i0];
+                        let u2 = p0_utils[i2][i3][i0][i1];
+                        let u3 = p0_utils[i3][i0][i1][i2];
+                        let payoff = Payoff::from([u0, u1, u2, u3]);
+                        let profile = Profile::new([m0, m1, m2, m3]);
+                        payoff_map.insert(profile, payoff);
+                    }
+                }
+            }
+        }
+        Some(Normal::from_payoff_map(all_moves, payoff_map))
+    }
+}
+
+impl<M0: Move, M1: Move, U: Utility> Normal<for2::Move<M0, M1>, U, 2> {
+    /// Construct a two-player normal-form game where each player has a different type of move.
+    ///
+    /// Constructed from the moves available to each player, as produced by
+    /// [`for2::per_player_moves`], and a payoff function over the unwrapped moves of each player.
+    ///
+    /// # Errors
+    ///
+    /// The resulting game will log an error and return a [zero payoff](Payoff::zeros) for any
+    /// profile where a player's move is wrapped in the other player's [`for2::Move`] variant.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// // P0 picks a color and P1 picks a number.
+    /// let g = Normal::heterogeneous(
+    ///     for2::per_player_moves(vec!["red", "blue"], vec![1, 2, 3]),
+    ///     |color, n| {
+    ///         if color == "red" {
+    ///             Payoff::from([n, n])
+    ///         } else {
+    ///             Payoff::from([0, 3 - n])
+    ///         }
+    ///     },
+    /// );
+    ///
+    /// let red = for2::Move::P0("red");
+    /// let blue = for2::Move::P0("blue");
+    /// let one = for2::Move::P1(1);
+    /// let three = for2::Move::P1(3);
+    ///
+    /// assert_eq!(g.payoff(Profile::new([red, three])), Payoff::from([3, 3]));
+    /// assert_eq!(g.payoff(Profile::new([blue, one])), Payoff::from([0, 2]));
+    /// assert_eq!(g.possible_profiles().count(), 6);
+    /// assert_eq!(g.pure_nash_equilibria(), vec![Profile::new([red, three])]);
+    /// ```
+    pub fn heterogeneous(
+        moves: PerPlayer<Vec<for2::Move<M0, M1>>, 2>,
+        payoff_fn: impl Fn(M0, M1) -> Payoff<U, 2> + Send + Sync + 'static,
+    ) -> Self {
+        let payoff_fn = move |profile: Profile<for2::Move<M0, M1>, 2>| match (
+            profile[for2::P0],
+            profile[for2::P1],
+        ) {
+            (for2::Move::P0(m0), for2::Move::P1(m1)) => payoff_fn(m0, m1),
+            _ => {
+                log::error!(
+                    "Normal::heterogeneous: attempted to get the payoff of a profile with misplaced moves: {:?}",
+                    profile
+                );
+                Payoff::zeros()
+            }
+        };
+        Normal::from_payoff_fn(moves, payoff_fn)
+    }
+}
+
+impl<M: Move, U: Utility, const P: usize> Game<P> for Normal<M, U, P> {
+    type Move = M;
+    type Utility = U;
+    type Outcome = SimultaneousOutcome<M, U, P>;
+    type State = ();
+    type View = ();
+
+    fn into_game_tree(self) -> GameTree<(), M, U, SimultaneousOutcome<M, U, P>, P> {
+        let state = Arc::new(());
+        GameTree::all_players(state.clone(), move |_, profile| {
+            for ply in profile.plies() {
+                let player = ply.player.unwrap();
+                if !self.is_valid_move_for_player(player, ply.the_move) {
+                    return Err(ErrorKind::InvalidMove(player, ply.the_move));
+                }
+            }
+            Ok(GameTree::end(
+                state.clone(),
+                SimultaneousOutcome::new(profile, self.payoff(profile)),
+            ))
+        })
+    }
+
+    fn state_view(&self, _state: &(), _player: PlayerIndex<P>) {}
+}
+
+impl<M: Move, U: Utility, const P: usize> FiniteGame<P> for Normal<M, U, P> {
+    fn possible_moves(&self, player: PlayerIndex<P>, _state: &()) -> PossibleMoves<'_, M> {
+        self.possible_moves_for_player(player)
+    }
+}
+
+/// Tolerance used when comparing floating point values in equilibrium computations.
+const EPSILON: f64 = 1e-9;
+
+/// Get the social welfare of a payoff, that is, the sum of all players' utilities, converted to
+/// `f64`.
+fn welfare<U: Utility + ToPrimitive, const P: usize>(payoff: &Payoff<U, P>) -> f64 {
+    payoff.iter().map(|u| u.to_f64().unwrap_or(f64::NAN)).sum()
+}
+
+/// Compute the ratio of the optimal welfare to the welfare of the worst equilibrium, logging an
+/// error and returning `None` if the ratio is not meaningful.
+fn welfare_ratio(caller: &str, optimal: f64, worst: f64) -> Option<f64> {
+    if worst > 0.0 && optimal.is_finite() {
+        Some(optimal / worst)
+    } else {
+        log::error!(
+            "{}: the price of anarchy is undefined when the worst equilibrium welfare is not positive (optimal: {}, worst: {})",
+            caller,
+            optimal,
+            worst,
+        );
+        None
+    }
+}
+
+/// Transpose a matrix represented as a vector of rows.
+fn transpose(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let num_cols = matrix.first().map_or(0, |row| row.len());
+    (0..num_cols)
+        .map(|c| matrix.iter().map(|row| row[c]).collect())
+        .collect()
+}
+
+/// Given a player's utilities, indexed by their own move then their opponent's move, find the
+/// opponent's mixed strategy over `opp_support` that makes the player indifferent among the moves
+/// in `own_support`.
+///
+/// Returns the probabilities corresponding to each move in `opp_support`, or `None` if there is no
+/// such strategy with all probabilities positive, or if some move outside of `own_support` would
+/// be a strictly better response.
+fn indifferent_mix(
+    utils: &[Vec<f64>],
+    own_support: &[usize],
+    opp_support: &[usize],
+) -> Option<Vec<f64>> {
+    // Unknowns are the opponent's probabilities followed by the player's expected utility.
+    let size = opp_support.len() + 1;
+    let mut system: Vec<Vec<f64>> = own_support
+        .iter()
+        .map(|&i| {
+            let mut row: Vec<f64> = opp_support.iter().map(|&j| utils[i][j]).collect();
+            row.extend([-1.0, 0.0]);
+            row
+        })
+        .collect();
+    let mut sum_to_one = vec![1.0; size - 1];
+    sum_to_one.extend([0.0, 1.0]);
+    system.push(sum_to_one);
+
+    let solution = solve_linear_system(system)?;
+    let (probs, value) = (&solution[..size - 1], solution[size - 1]);
+    if probs.iter().any(|&prob| prob <= EPSILON) {
+        return None;
+    }
+    let is_best_response = (0..utils.len()).all(|i| {
+        let expected: f64 = opp_support
+            .iter()
+            .zip(probs)
+            .map(|(&j, p)| utils[i][j] * p)
+            .sum();
+        expected <= value + EPSILON
+    });
+    is_best_response.then(|| probs.to_vec())
+}
+
+/// Given a player's utilities, indexed by their own move then their opponent's move, find the
+/// player's mixed strategy that maximizes their worst-case expected utility.
+///
+/// Returns the probability of each of the player's moves and the guaranteed expected utility, or
+/// `None` if either player has no moves or a utility is not finite.
+///
+/// This solves the linear program: maximize `v` subject to `Σᵢ pᵢ·u(i, j) ≥ v` for every
+/// opponent move `j`, `Σᵢ pᵢ = 1`, and `p ≥ 0`. The utilities are first shifted to be positive,
+/// which makes `v` positive and lets the program be rewritten in the standard form: minimize
+/// `Σᵢ xᵢ` subject to `Σᵢ xᵢ·u(i, j) ≥ 1` and `x ≥ 0`, where `x = p / v`. Its dual, maximize
+/// `Σⱼ yⱼ` subject to `Σⱼ u(i, j)·yⱼ ≤ 1` and `y ≥ 0`, is solved with the simplex method, and `x`
+/// is read off the final tableau.
+fn maximin_mix(utils: &[Vec<f64>]) -> Option<(Vec<f64>, f64)> {
+    let (num_own, num_opp) = (utils.len(), utils.first().map_or(0, |row| row.len()));
+    if num_own == 0 || num_opp == 0 || utils.iter().flatten().any(|u| !u.is_finite()) {
+        return None;
+    }
+    let min = utils
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::INFINITY, f64::min);
+    let shift = 1.0 - min;
+
+    // The tableau has one row per constraint of the dual, with columns for each `yⱼ`, then each
+    // slack variable, then the right-hand side. The last row is the objective.
+    let width = num_opp + num_own + 1;
+    let mut tableau: Vec<Vec<f64>> = utils
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut cells: Vec<f64> = row.iter().map(|u| u + shift).collect();
+            cells.extend((0..num_own).map(|k| if k == i { 1.0 } else { 0.0 }));
+            cells.push(1.0);
+            cells
+        })
+        .collect();
+    let mut objective = vec![-1.0; num_opp];
+    objective.resize(width, 0.0);
+    tableau.push(objective);
+    let mut basis: Vec<usize> = (num_opp..num_opp + num_own).collect();
+
+    // Pivot using Bland's rule, which avoids cycling on degenerate games.
+    while let Some(col) = (0..width - 1).find(|&col| tableau[num_own][col] < -EPSILON) {
+        let row = (0..num_own)
+            .filter(|&row| tableau[row][col] > EPSILON)
+            .min_by(|&a, &b| {
+                let ratio = |row: usize| tableau[row][width - 1] / tableau[row][col];
+                ratio(a)
+                    .partial_cmp(&ratio(b))
+                    .unwrap_or(Ordering::Equal)
+                    .then(basis[a].cmp(&basis[b]))
+            })?;
+        let pivot = tableau[row][col];
+        for cell in tableau[row].iter_mut() {
+            *cell /= pivot;
+        }
+        let pivot_row = tableau[row].clone();
+        for (other, cells) in tableau.iter_mut().enumerate() {
+            let factor = cells[col];
+            if other != row && factor != 0.0 {
+                for (cell, pivot_cell) in cells.iter_mut().zip(&pivot_row) {
+                    *cell -= factor * pivot_cell;
+                }
+            }
+        }
+        basis[row] = col;
+    }
+
+    let total = tableau[num_own][width - 1];
+    let probs = (0..num_own)
+        .map(|i| tableau[num_own][num_opp + i].max(0.0) / total)
+        .collect();
+    Some((probs, 1.0 / total - shift))
+}
+
+/// Enumerate the ways of splitting `total` units among `parts` parts, in lexicographically
+/// decreasing order of the first part, then the second, and so on.
+fn simplex_grid(total: usize, parts: usize) -> Vec<Vec<usize>> {
+    match parts {
+        0 => Vec::new(),
+        1 => vec![vec![total]],
+        _ => (0..=total)
+            .rev()
+            .flat_map(|first| {
+                simplex_grid(total - first, parts - 1)
+                    .into_iter()
+                    .map(move |rest| [vec![first], rest].concat())
+            })
+            .collect(),
+    }
+}
+
+/// Solve a square system of linear equations given as an augmented matrix, using Gaussian
+/// elimination with partial pivoting. Returns `None` if the system has no unique solution.
+#[allow(clippy::needless_range_loop)]
+fn solve_linear_system(mut system: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = system.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| {
+            system[a][col]
+                .abs()
+                .partial_cmp(&system[b][col].abs())
+                .unwrap_or(Ordering::Equal)
+        })?;
+        if system[pivot][col].abs() < EPSILON || system[pivot][col].is_nan() {
+            return None;
+        }
+        system.swap(col, pivot);
+        for row in col + 1..n {
+            let factor = system[row][col] / system[col][col];
+            for k in col..=n {
+                system[row][k] -= factor * system[col][k];
+            }
+        }
+    }
+    let mut solution = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = (row + 1..n).map(|k| system[row][k] * solution[k]).sum();
+        solution[row] = (system[row][n] - known) / system[row][row];
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::for3;
+    use impls::impls;
+    use test_log::test;
+
+    #[test]
+    fn regret_zero_at_equilibrium_positive_when_dominated() {
+        let g = Normal::bimatrix(
+            ['U', 'D'],
+            ['L', 'M', 'R'],
+            [[1, 1, 0], [0, 0, 2]],
+            [[0, 2, 1], [3, 1, 0]],
+        );
+
+        let equilibrium = Profile::new(['U', 'M']);
+        assert_eq!(g.pure_nash_equilibria(), vec![equilibrium]);
+        for player in PlayerIndex::all() {
+            assert_eq!(g.regret(player, equilibrium), 0);
+        }
+
+        // R is strictly dominated by M for P1, so playing R always incurs regret.
+        for row in ['U', 'D'] {
+            assert!(g.regret(for2::P1, Profile::new([row, 'R'])) > 0);
+        }
+        assert_eq!(g.regret(for2::P1, Profile::new(['U', 'R'])), 1);
+        assert_eq!(g.regret(for2::P1, Profile::new(['D', 'R'])), 3);
+        assert_eq!(g.regret(for2::P0, Profile::new(['X', 'R'])), 0);
+    }
+
+    #[test]
+    fn best_response_graph_sinks_are_pure_nash_equilibria() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let graph = pd.best_response_graph();
+        assert_eq!(graph.sinks(), vec![Profile::new(['D', 'D'])]);
+        assert_eq!(graph.edges().len(), 4);
+
+        let hunt: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['C', 'D'], vec![3, 0, 2, 1]).unwrap();
+        assert_eq!(
+            hunt.best_response_graph().sinks(),
+            hunt.pure_nash_equilibria()
+        );
+    }
+
+    #[test]
+    fn payoff_noise_preserves_robust_equilibrium() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+
+        let unchanged = pd
+            .with_payoff_noise(0.0, &mut StdRng::seed_from_u64(0))
+            .unwrap();
+        for profile in pd.possible_profiles() {
+            assert_eq!(
+                unchanged.payoff(profile),
+                pd.payoff(profile).map(|u| u as f64)
+            );
+        }
+
+        for seed in 0..20 {
+            let noisy = pd
+                .with_payoff_noise(0.25, &mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            assert_eq!(noisy.pure_nash_equilibria(), vec![Profile::new(['D', 'D'])]);
+
+            let again = pd
+                .with_payoff_noise(0.25, &mut StdRng::seed_from_u64(seed))
+                .unwrap();
+            for profile in pd.possible_profiles() {
+                assert_eq!(noisy.payoff(profile), again.payoff(profile));
+            }
+        }
+    }
+
+    #[test]
+    fn logit_qre_interpolates_between_uniform_and_nash() {
+        let dilemma: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+
+        let uniform = dilemma.logit_qre(0.0, 50).unwrap();
+        for player in PlayerIndex::all() {
+            for (_, prob) in uniform[player].probabilities() {
+                assert_eq!(prob, 0.5);
+            }
+        }
+
+        let rational = dilemma.logit_qre(20.0, 200).unwrap();
+        for player in PlayerIndex::all() {
+            let probs: HashMap<char, f64> = rational[player]
+                .probabilities()
+                .map(|(m, p)| (*m, p))
+                .collect();
+            assert!(probs[&'D'] > 0.99);
+        }
+
+        // In matching pennies, the unique QRE is uniform play for every lambda.
+        let pennies: Normal<char, i32, 2> =
+            Normal::matrix(['H', 'T'], ['H', 'T'], [[1, -1], [-1, 1]]);
+        let qre = pennies.logit_qre(5.0, 500).unwrap();
+        for player in PlayerIndex::all() {
+            for (_, prob) in qre[player].probabilities() {
+                assert!((prob - 0.5).abs() < 0.01);
+            }
+        }
+    }
+
+    #[test]
+    fn maximin_mixed_matches_nash_in_zero_sum_game() {
+        let g = Normal::matrix(['A', 'B'], ['C', 'D'], [[3, -1], [-2, 1]]);
+        let nash = g.mixed_nash_equilibria();
+        assert_eq!(nash.len(), 1);
+
+        let (row, row_value) = g.maximin_mixed(for2::P0).unwrap();
+        let (col, col_value) = g.maximin_mixed(for2::P1).unwrap();
+        for (player, strategy) in [(for2::P0, &row), (for2::P1, &col)] {
+            let expected = nash[0].for_player(player);
+            let actual: Vec<_> = strategy.probabilities().collect();
+            assert_eq!(actual.len(), expected.len());
+            for ((m, p), (em, ep)) in actual.into_iter().zip(expected) {
+                assert_eq!(m, em);
+                assert!((p - ep).abs() < 1e-9);
+            }
+        }
+
+        // The game value is 1/7, guaranteed by the row player and conceded by the column player.
+        assert!((row_value - 1.0 / 7.0).abs() < 1e-9);
+        assert!((col_value + 1.0 / 7.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn maximin_mixed_in_degenerate_game() {
+        // Mixing A and B evenly guarantees 1.5 against X or Y, but only 1 against Z. No pure move
+        // guarantees more than 0, and the only tight opponent move, Z, forms a support of a
+        // different size than the player's.
+        let g = Normal::matrix(['A', 'B'], ['X', 'Y', 'Z'], [[3, 0, 1], [0, 3, 1]]);
+        let (strategy, value) = g.maximin_mixed(for2::P0).unwrap();
+        assert!((value - 1.0).abs() < 1e-9);
+        let guaranteed = ['X', 'Y', 'Z']
+            .into_iter()
+            .map(|theirs| {
+                strategy
+                    .probabilities()
+                    .map(|(&mine, p)| p * g.payoff(Profile::new([mine, theirs]))[for2::P0] as f64)
+                    .sum::<f64>()
+            })
+            .fold(f64::INFINITY, f64::min);
+        assert!((guaranteed - 1.0).abs() < 1e-9);
+
+        let empty: Normal<char, i32, 2> =
+            Normal::from_payoff_fn(PerPlayer::new([vec![], vec!['X']]), |_| Payoff::zeros());
+        assert!(empty.maximin_mixed(for2::P0).is_none());
+        assert!(empty.maximin_mixed(for2::P1).is_none());
+    }
+
+    #[test]
+    fn has_pure_nash_short_circuits() {
+        use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let coordination = Normal::from_payoff_fn(
+            PerPlayer::new([vec!['A', 'B', 'C'], vec!['A', 'B', 'C']]),
+            move |profile: Profile<char, 2>| {
+                counter.fetch_add(1, AtomicOrdering::SeqCst);
+                let util = if profile[for2::P0] == profile[for2::P1] {
+                    1
+                } else {
+                    0
+                };
+                Payoff::from([util, util])
+            },
+        );
+
+        assert!(coordination.has_pure_nash());
+        let short_circuited = calls.swap(0, AtomicOrdering::SeqCst);
+        assert_eq!(coordination.pure_nash_equilibria().len(), 3);
+        let exhaustive = calls.swap(0, AtomicOrdering::SeqCst);
+        assert!(short_circuited < exhaustive);
+        assert_eq!(coordination.count_pure_nash(), 3);
+
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        assert!(!rps.has_pure_nash());
+        assert_eq!(rps.count_pure_nash(), 0);
+    }
+
+    #[test]
+    fn duplicate_moves_fail_to_construct() {
+        let moves = PerPlayer::new([vec!['C', 'D', 'C'], vec!['C', 'D']]);
+        assert!(Normal::try_from_payoff_fn(moves.clone(), |_| Payoff::from([0, 0])).is_none());
+        assert!(
+            Normal::<char, i32, 2>::try_from_payoff_map(moves.clone(), HashMap::new()).is_none()
+        );
+        assert!(
+            Normal::<char, i32, 2>::try_from_payoff_pairs(moves.clone(), std::iter::empty())
+                .is_none()
+        );
+
+        assert!(Normal::try_matrix(['C', 'C'], ['D'], [[1], [2]]).is_none());
+        assert!(Normal::try_bimatrix(
+            ['C', 'D'],
+            ['C', 'D', 'C'],
+            [[2, 0, 9], [3, 1, 9]],
+            [[2, 3, 9], [0, 1, 9]],
+        )
+        .is_none());
+        assert!(Normal::try_symmetric_for2(['C', 'D', 'C'], [[0; 3]; 3]).is_none());
+        assert!(Normal::try_symmetric_for3(['C', 'C'], [[[0; 2]; 2]; 2]).is_none());
+        assert!(Normal::try_symmetric_for4(['C', 'C'], [[[[0; 2]; 2]; 2]; 2]).is_none());
+
+        let result = std::panic::catch_unwind(|| Normal::matrix(['C', 'C'], ['D'], [[1], [2]]));
+        assert!(result.is_err());
+
+        // Without duplicates, the fallible constructors agree with the others.
+        let pd = Normal::symmetric_for2(['C', 'D'], [[2, 0], [3, 1]]);
+        let try_pd = Normal::try_symmetric_for2(['C', 'D'], [[2, 0], [3, 1]]).unwrap();
+        for profile in pd.possible_profiles() {
+            assert_eq!(pd.payoff(profile), try_pd.payoff(profile));
+        }
+    }
+
+    #[test]
+    fn index_payoff_fn_matches_profile_payoff_fn() {
+        let moves = PerPlayer::new([vec!['R', 'P', 'S'], vec!['R', 'P', 'S']]);
+        let by_index = Normal::from_index_payoff_fn(moves, |indexes: PerPlayer<usize, 2>| {
+            let (a, b) = (indexes[for2::P0], indexes[for2::P1]);
+            match (3 + a - b) % 3 {
+                0 => Payoff::from([0, 0]),
+                1 => Payoff::from([1, -1]),
+                _ => Payoff::from([-1, 1]),
+            }
+        })
+        .unwrap();
+        let by_profile: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+
+        for profile in by_profile.possible_profiles() {
+            assert_eq!(by_index.payoff(profile), by_profile.payoff(profile));
+        }
+        assert_eq!(by_index.payoff(Profile::new(['X', 'R'])), Payoff::zeros());
+    }
+
+    #[test]
+    fn index_payoff_fn_rejects_duplicate_moves() {
+        let moves = PerPlayer::new([vec!['R', 'P', 'R'], vec!['R', 'P', 'S']]);
+        let game = Normal::<char, i32, 2>::from_index_payoff_fn(moves, |indexes| {
+            Payoff::from([indexes[for2::P0] as i32, indexes[for2::P1] as i32])
+        });
+        assert!(game.is_none());
+    }
+
+    #[test]
+    fn payoff_pairs_match_payoff_map() {
+        let moves = PerPlayer::new([vec!['A', 'B'], vec!['X', 'Y', 'Z']]);
+        let pairs = || {
+            moves[for2::P0].iter().enumerate().flat_map(|(i, &a)| {
+                moves[for2::P1]
+                    .iter()
+                    .enumerate()
+                    .map(move |(j, &b)| (Profile::new([a, b]), Payoff::from([i as i32, j as i32])))
+            })
+        };
+
+        let from_map = Normal::from_payoff_map(moves.clone(), pairs().collect());
+        // Duplicates are resolved in favor of the last payoff.
+        let from_pairs = Normal::from_payoff_pairs(
+            moves.clone(),
+            std::iter::once((Profile::new(['A', 'X']), Payoff::from([9, 9]))).chain(pairs()),
+        );
+
+        for profile in from_map.possible_profiles() {
+            assert_eq!(from_pairs.payoff(profile), from_map.payoff(profile));
+        }
+        assert_eq!(
+            from_pairs.payoff(Profile::new(['B', 'Z'])),
+            Payoff::from([1, 2])
+        );
+    }
+
+    #[test]
+    fn rock_paper_scissors_on_rock_paper_supports() {
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        let expected: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P'], vec![0, -1, 1, 0]).unwrap();
+
+        let rp = rps.on_supports(PerPlayer::new([vec!['R', 'P'], vec!['R', 'P']]));
+        assert_eq!(rp.dimensions(), PerPlayer::new([2, 2]));
+        assert_eq!(
+            rp.possible_profiles().collect::<Vec<_>>(),
+            expected.possible_profiles().collect::<Vec<_>>()
+        );
+        for profile in expected.possible_profiles() {
+            assert_eq!(rp.payoff(profile), expected.payoff(profile));
+        }
+
+        // Invalid and duplicate moves are omitted.
+        let rp = rps.on_supports(PerPlayer::new([vec!['R', 'X', 'P'], vec!['R', 'P', 'R']]));
+        assert_eq!(rp.dimensions(), PerPlayer::new([2, 2]));
+    }
+
+    #[test]
+    fn weakly_dominant_strategy_equilibrium() {
+        let g = Normal::bimatrix(['U', 'D'], ['L', 'R'], [[1, 1], [1, 0]], [[1, 0], [1, 1]]);
+        assert_eq!(g.dominant_strategy_equilibrium(true), None);
+        assert_eq!(
+            g.dominant_strategy_equilibrium(false),
+            Some(Profile::new(['U', 'L']))
+        );
+        assert!(g.is_stable(Profile::new(['U', 'L'])));
+    }
+
+    /// A sealed-bid auction with the given valuations and bids from 0 to 4, where the highest bid
+    /// wins, and tied bidders share the item. The winner pays the highest losing bid if
+    /// `second_price` is true, otherwise their own bid.
+    fn auction<const P: usize>(values: [f64; P], second_price: bool) -> Normal<u8, f64, P> {
+        Normal::from_payoff_fn(
+            PerPlayer::generate(|_| (0..=4).collect()),
+            move |profile: Profile<u8, P>| {
+                let bids = profile.per_player();
+                let high = *bids.iter().max().unwrap();
+                let winners = bids.iter().filter(|&&bid| bid == high).count();
+                let price = if second_price && winners == 1 {
+                    bids.iter()
+                        .copied()
+                        .filter(|&bid| bid < high)
+                        .max()
+                        .unwrap()
+                } else {
+                    high
+                };
+                Payoff::from_fn(|player| {
+                    if bids[player] == high {
+                        (values[player.as_usize()] - f64::from(price)) / winners as f64
+                    } else {
+                        0.0
+                    }
+                })
+            },
+        )
+    }
+
+    #[test]
+    fn second_price_auction_is_truthful_but_first_price_is_not() {
+        let second = auction([2.0, 3.0], true);
+        assert!(second.is_truthful(Profile::new([2, 3])));
+        assert!(!second.is_truthful(Profile::new([1, 3])));
+
+        let first = auction([2.0, 3.0], false);
+        assert!(!first.is_truthful(Profile::new([2, 3])));
+
+        let three_bidders = auction([1.0, 4.0, 2.0], true);
+        assert!(three_bidders.is_truthful(Profile::new([1, 4, 2])));
+        assert!(!auction([1.0, 4.0, 2.0], false).is_truthful(Profile::new([1, 4, 2])));
+
+        // Bids must be valid moves.
+        assert!(!second.is_truthful(Profile::new([2, 5])));
+    }
+
+    /// Apply the eliminations of the iterated dominance trace to the game.
+    fn eliminate_dominated<M: Move, U: Utility, const P: usize>(
+        game: &Normal<M, U, P>,
+        strict_only: bool,
+    ) -> Normal<M, U, P> {
+        game.iterated_dominance_trace(strict_only)
+            .into_iter()
+            .fold(game.clone(), |reduced, (player, dominated, _)| {
+                reduced.remove_dominated_move(player, dominated)
+            })
+    }
+
+    #[test]
+    fn strict_elimination_preserves_nash_but_weak_elimination_may_not() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let reduced_pd = eliminate_dominated(&pd, true);
+        assert_eq!(reduced_pd.possible_profiles().count(), 1);
+        assert!(pd.nash_preserved_under(&reduced_pd));
+
+        let g = Normal::bimatrix(
+            ['U', 'D'],
+            ['L', 'M', 'R'],
+            [[1, 1, 0], [0, 0, 2]],
+            [[0, 2, 1], [3, 1, 0]],
+        );
+        assert!(g.nash_preserved_under(&eliminate_dominated(&g, true)));
+
+        let weak = Normal::bimatrix(['U', 'D'], ['L', 'R'], [[1, 1], [1, 0]], [[1, 0], [1, 1]]);
+        assert!(weak.nash_preserved_under(&eliminate_dominated(&weak, true)));
+        assert!(!weak.nash_preserved_under(&eliminate_dominated(&weak, false)));
+
+        // A game with different payoffs is not a reduction of the original.
+        let other: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['C', 'D'], vec![2, 0, 4, 1]).unwrap();
+        assert!(!pd.nash_preserved_under(&other));
+    }
+
+    #[test]
+    fn product_of_dilemmas_defects_in_both() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let pd2 = pd.product(&pd);
+
+        assert_eq!(pd2.dimensions(), PerPlayer::new([4, 4]));
+        assert_eq!(pd2.possible_profiles().count(), 16);
+        for profile in pd2.possible_profiles() {
+            let first = Profile::new([profile[for2::P0].0, profile[for2::P1].0]);
+            let second = Profile::new([profile[for2::P0].1, profile[for2::P1].1]);
+            assert_eq!(pd2.payoff(profile), pd.payoff(first) + pd.payoff(second));
+        }
+        assert_eq!(
+            pd2.pure_nash_equilibria(),
+            vec![Profile::new([('D', 'D'), ('D', 'D')])]
+        );
+        assert_eq!(
+            pd2.dominant_strategy_equilibrium(true),
+            Some(Profile::new([('D', 'D'), ('D', 'D')]))
+        );
+    }
+
+    #[test]
+    fn exploitability_of_rock_paper_scissors_profiles() {
+        let rps = Normal::matrix(
+            ['R', 'P', 'S'],
+            ['R', 'P', 'S'],
+            [[0, -1, 1], [1, 0, -1], [-1, 1, 0]],
+        );
+        let uniform = Distribution::flat(vec!['R', 'P', 'S']).unwrap();
+        let uniform_profile = PerPlayer::new([uniform.clone(), uniform]);
+        assert!(rps.exploitability(&uniform_profile).abs() < 1e-9);
+
+        // Both players always play rock: each could gain 1 by switching to paper.
+        let pure = PerPlayer::new([Distribution::singleton('R'), Distribution::singleton('R')]);
+        assert!((rps.exploitability(&pure) - 2.0).abs() < 1e-9);
+
+        // A slightly biased mix is only slightly exploitable.
+        let biased = Distribution::new(vec![('R', 0.4), ('P', 0.3), ('S', 0.3)]).unwrap();
+        let biased_profile = PerPlayer::new([biased.clone(), biased]);
+        let exploitability = rps.exploitability(&biased_profile);
+        assert!(exploitability > 0.0 && exploitability < 0.5);
+
+        let invalid = PerPlayer::new([Distribution::singleton('X'), Distribution::singleton('R')]);
+        assert!(rps.exploitability(&invalid).is_nan());
+    }
+
+    #[test]
+    fn mixed_price_of_anarchy_without_pure_equilibria() {
+        let g = Normal::bimatrix(['T', 'B'], ['L', 'R'], [[3, 0], [1, 2]], [[0, 2], [1, 0]]);
+        assert!(g.pure_nash_equilibria().is_empty());
+        assert_eq!(g.price_of_anarchy(), None);
+
+        // The unique equilibrium mixes 1/3 T + 2/3 B against 1/2 L + 1/2 R, for an expected
+        // welfare of 13/6, while the optimal welfare is 3.
+        let poa = g.mixed_price_of_anarchy().unwrap();
+        assert!(poa.is_finite());
+        assert!((poa - 18.0 / 13.0).abs() < 1e-9);
+
+        // A game whose equilibria have no positive welfare has no meaningful price of anarchy.
+        let pennies = Normal::matrix(['H', 'T'], ['H', 'T'], [[1, -1], [-1, 1]]);
+        assert_eq!(pennies.mixed_price_of_anarchy(), None);
+    }
+
+    #[test]
+    fn egalitarian_solution_differs_from_welfare_maximizing() {
+        // The row player is much better off in most outcomes.
+        let g = Normal::bimatrix(
+            ['T', 'M', 'B'],
+            ['L', 'R'],
+            [[10, 6], [4, 3], [9, 1]],
+            [[1, 3], [4, 2], [0, 1]],
+        );
+
+        let welfare = g
+            .possible_outcomes()
+            .max_by_key(|outcome| outcome.payoff().iter().sum::<i32>())
+            .unwrap();
+        assert_eq!(*welfare.profile(), Profile::new(['T', 'L']));
+
+        let egalitarian = g.egalitarian_solutions();
+        assert_eq!(egalitarian, vec![Profile::new(['M', 'L'])]);
+        assert!(!egalitarian.contains(welfare.profile()));
+        assert!(g.pareto_optimal_solutions().contains(&egalitarian[0]));
+    }
+
+    #[test]
+    fn symmetrize_swaps_roles() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let sym_pd = pd.symmetrize().unwrap();
+        for profile in pd.possible_profiles() {
+            assert_eq!(sym_pd.payoff(profile), pd.payoff(profile));
+        }
+
+        // The column player's moves are listed in a different order.
+        let g = Normal::bimatrix(
+            ['X', 'Y', 'Z'],
+            ['Z', 'X', 'Y'],
+            [[1.0, 2.5, -3.0], [0.5, 4.0, 2.0], [7.0, -1.0, 0.0]],
+            [[3.0, 0.0, 1.5], [2.0, -2.0, 6.0], [1.0, 5.0, 0.25]],
+        );
+        let sym = g.symmetrize().unwrap();
+        for a in ['X', 'Y', 'Z'] {
+            for b in ['X', 'Y', 'Z'] {
+                let ab = sym.payoff(Profile::new([a, b]));
+                let ba = sym.payoff(Profile::new([b, a]));
+                assert_eq!(ab[for2::P0], ba[for2::P1]);
+            }
+        }
+        let xz = sym.payoff(Profile::new(['X', 'Z']));
+        assert_eq!(xz, Payoff::from([(1.0 + 5.0) / 2.0, (3.0 - 1.0) / 2.0]));
+    }
+
+    #[test]
+    fn support_size_one_yields_pure_equilibria() {
+        let coordination: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['A', 'B', 'C'], vec![3, 0, 0, 0, 2, 0, 0, 0, 1]).unwrap();
+        let pure = coordination
+            .nash_equilibria_with_support(1)
+            .iter()
+            .map(|profile| profile.to_pure().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(pure, coordination.pure_nash_equilibria());
+        assert_eq!(pure.len(), 3);
+
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        assert!(rps.nash_equilibria_with_support(1).is_empty());
+        assert!(rps.nash_equilibria_with_support(2).is_empty());
+        let fully_mixed = rps.nash_equilibria_with_support(3);
+        assert_eq!(fully_mixed.len(), 1);
+        for m in ['R', 'P', 'S'] {
+            assert!((fully_mixed[0].probability(for2::P1, m) - 1.0 / 3.0).abs() < 1e-9);
+        }
+        assert!(rps.nash_equilibria_with_support(0).is_empty());
+    }
+
+    #[test]
+    fn dilemma_best_response_diagram_has_one_nash_cell() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let diagram = pd.best_response_diagram();
+
+        let nash_cells: Vec<(&str, &str)> = diagram
+            .lines()
+            .skip(2)
+            .flat_map(|line| {
+                let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+                let row = cells[0];
+                cells[1..]
+                    .iter()
+                    .zip(["C", "D"])
+                    .filter(|(cell, _)| cell.matches('*').count() == 2)
+                    .map(move |(_, col)| (row, col))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(nash_cells, vec![("D", "D")]);
+        assert_eq!(diagram.matches('*').count(), 4);
+    }
+
+    #[test]
+    fn best_response_diagram_aligns_non_ascii_moves() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['α', 'β'], vec![2, 0, 3, 1]).unwrap();
+        let diagram = pd.best_response_diagram();
+
+        assert_eq!(
+            diagram,
+            "\
+|   | α     | β      |
+|---|-------|--------|
+| α | 2, 2  | 0, 3*  |
+| β | 3*, 0 | 1*, 1* |
+",
+        );
+        let widths: Vec<usize> = diagram.lines().map(|line| line.chars().count()).collect();
+        assert!(widths.iter().all(|&width| width == widths[0]));
+    }
+
+    #[test]
+    fn mixed_extension_of_rock_paper_scissors() {
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        assert!(rps.mixed_extension(0).is_none());
+
+        let (mixed, strategies) = rps.mixed_extension(3).unwrap();
+        assert_eq!(mixed.dimensions(), PerPlayer::new([10, 10]));
+
+        let uniform_index = |player| {
+            strategies[player]
+                .iter()
+                .position(|dist: &Distribution<char>| {
+                    dist.probabilities()
+                        .all(|(_, prob)| (prob - 1.0 / 3.0).abs() < 1e-9)
+                        && dist.support().count() == 3
+                })
+                .unwrap()
+        };
+        let uniform = Profile::new([uniform_index(for2::P0), uniform_index(for2::P1)]);
+        for player in PlayerIndex::all() {
+            assert!(mixed.regret(player, uniform) < 1e-9);
+        }
+
+        // Any pure move can be exploited by the opponent.
+        let rock = Profile::new([0, uniform_index(for2::P1)]);
+        assert!(mixed.regret(for2::P1, Profile::new([0, 0])) > 0.9);
+        assert!(mixed.regret(for2::P0, rock) < 1e-9);
+    }
+
+    #[test]
+    fn dominance_solvable_classification() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        assert_eq!(pd.is_dominance_solvable(), Some(Profile::new(['D', 'D'])));
+
+        let coordination: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['A', 'B'], vec![2, 0, 0, 1]).unwrap();
+        assert_eq!(coordination.is_dominance_solvable(), None);
+
+        // Requires several rounds of elimination alternating between the players.
+        let g = Normal::bimatrix(
+            ['U', 'M', 'D'],
+            ['L', 'C', 'R'],
+            [[4, 5, 6], [2, 8, 3], [3, 9, 2]],
+            [[3, 1, 2], [1, 4, 6], [0, 6, 8]],
+        );
+        assert!(g.iterated_dominance_trace(true).len() >= 3);
+        assert_eq!(g.is_dominance_solvable(), Some(Profile::new(['U', 'L'])));
+    }
+
+    #[test]
+    fn duplicate_moves_are_rejected() {
+        assert!(Normal::<char, i32, 2>::symmetric(vec!['C', 'C'], vec![2, 0, 3, 1]).is_none());
+        assert!(Normal::<char, i32, 3>::symmetric(vec!['C', 'D', 'C'], vec![0; 27]).is_none());
+        assert!(Normal::from_payoff_vec(
+            PerPlayer::new([vec!['A', 'B'], vec!['C', 'C']]),
+            vec![Payoff::from([1, 2]); 4],
+        )
+        .is_none());
+
+        // The same move may be available to different players.
+        assert!(Normal::from_payoff_vec(
+            PerPlayer::new([vec!['A', 'B'], vec!['A', 'B']]),
+            vec![Payoff::from([1, 2]); 4],
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn constant_sum_game_sums_to_ten() {
+        let g = Normal::bimatrix(
+            ['A', 'B', 'C'],
+            ['X', 'Y'],
+            [[3, 10], [5, -2], [1, 6]],
+            [[7, 0], [5, 12], [9, 4]],
+        );
+        assert_eq!(g.is_constant_sum(), Some(10));
+        assert!(!g.is_zero_sum());
+
+        let g = Normal::bimatrix(['A', 'B'], ['X', 'Y'], [[3, 10], [5, 0]], [[7, 0], [5, 9]]);
+        assert_eq!(g.is_constant_sum(), None);
+        assert!(!g.is_zero_sum());
+
+        let g = Normal::matrix(['A', 'B'], ['X', 'Y'], [[3, -1], [0, 2]]);
+        assert_eq!(g.is_constant_sum(), Some(0));
+        assert!(g.is_zero_sum());
+    }
+
+    #[test]
+    fn remove_dominated_move_keeps_other_payoffs() {
+        let g = Normal::from_payoff_vec(
+            PerPlayer::new([vec!['A', 'B', 'C'], vec!['X', 'Y'], vec!['Z', 'W']]),
+            (0..12).map(|i| Payoff::from([i, 2 * i, -i])).collect(),
+        )
+        .unwrap();
+
+        let reduced = g.remove_dominated_move(for3::P0, 'B');
+        assert_eq!(
+            reduced
+                .possible_moves_for_player(for3::P0)
+                .collect::<Vec<_>>(),
+            vec!['A', 'C']
+        );
+        assert_eq!(reduced.dimensions(), PerPlayer::new([2, 2, 2]));
+        for profile in reduced.possible_profiles() {
+            assert_ne!(profile[for3::P0], 'B');
+            assert_eq!(reduced.payoff(profile), g.payoff(profile));
+        }
+
+        let unchanged = g.remove_dominated_move(for3::P1, 'Q');
+        assert_eq!(unchanged.dimensions(), g.dimensions());
+    }
+
+    #[test]
+    fn convex_combination_averages_payoffs() {
+        let g1 = Normal::bimatrix(['A', 'B'], ['C', 'D'], [[4, 0], [2, 6]], [[1, 3], [5, 7]]);
+        let g2 = Normal::bimatrix(['A', 'B'], ['C', 'D'], [[0, 2], [8, 4]], [[3, 1], [1, 1]]);
+
+        let avg = g1.convex_combination(&g2, 0.5).unwrap();
+        for profile in g1.possible_profiles() {
+            let (p1, p2) = (g1.payoff(profile), g2.payoff(profile));
+            for player in PlayerIndex::all() {
+                assert_eq!(
+                    avg.payoff(profile)[player],
+                    (p1[player] + p2[player]) as f64 / 2.0
+                );
+            }
+        }
+        assert_eq!(
+            avg.payoff(Profile::new(['B', 'C'])),
+            Payoff::from([5.0, 3.0])
+        );
+
+        assert_eq!(
+            g1.convex_combination(&g2, 1.0)
+                .unwrap()
+                .payoff(Profile::new(['A', 'D'])),
+            Payoff::from([0.0, 3.0])
+        );
+        assert!(g1.convex_combination(&g2, 1.5).is_none());
+
+        let g3 = Normal::bimatrix(['A', 'B'], ['C', 'E'], [[0, 0], [0, 0]], [[0, 0], [0, 0]]);
+        assert!(g1.convex_combination(&g3, 0.5).is_none());
+    }
+
+    #[test]
+    fn normal_is_send_sync() {
+        assert!(impls!(Normal<(), u8, 2>: Send & Sync));
+    }
+
+    #[test]
+    fn iterated_dominance_trace_weak() {
+        let g = Normal::bimatrix(
+            ['A', 'B', 'C'],
+            ['D', 'E'],
+            [[1, 1], [1, 0], [0, 0]],
+            [[1, 1], [0, 1], [2, 2]],
+        );
+
+        assert_eq!(g.iterated_dominance_trace(true), vec![(for2::P0, 'C', 'A')],);
+        assert_eq!(
+            g.iterated_dominance_trace(false),
+            vec![
+                (for2::P0, 'B', 'A'),
+                (for2::P0, 'C', 'A'),
+                (for2::P1, 'D', 'E'),
+            ],
+        );
+    }
+
+    #[test]
+    fn mixed_nash_matching_pennies() {
+        let g = Normal::matrix(['H', 'T'], ['H', 'T'], [[1, -1], [-1, 1]]);
+        let nash = g.mixed_nash_equilibria();
+        assert_eq!(nash.len(), 1);
+        for player in PlayerIndex::all() {
+            for m in ['H', 'T'] {
+                assert!((nash[0].probability(player, m) - 0.5).abs() < EPSILON);
+            }
+        }
+        let report = g.equilibrium_report();
+        assert!(report.pure_nash_equilibria().is_empty());
+        assert_eq!(report.mixed_nash_equilibria().len(), 1);
+        assert_eq!(report.dominant_moves(), &PerPlayer::new([None, None]));
+    }
+
+    #[test]
+    fn mixed_nash_rock_paper_scissors() {
+        let g = Normal::matrix(
+            ['R', 'P', 'S'],
+            ['R', 'P', 'S'],
+            [[0, -1, 1], [1, 0, -1], [-1, 1, 0]],
+        );
+        let nash = g.mixed_nash_equilibria();
+        assert_eq!(nash.len(), 1);
+        for player in PlayerIndex::all() {
+            for m in ['R', 'P', 'S'] {
+                assert!((nash[0].probability(player, m) - 1.0 / 3.0).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn from_csv_matches_bimatrix() {
+        let csv = ",D,E\nA,0;5,5;0\nB,4;1,3;2\nC,2;4,1;3\n";
+        let from_csv =
+            Normal::from_csv(csv.as_bytes(), |s| s.chars().next(), |s| s.parse().ok()).unwrap();
+        let bimatrix = Normal::bimatrix(
+            ['A', 'B', 'C'],
+            ['D', 'E'],
+            [[0, 5], [4, 3], [2, 1]],
+            [[5, 0], [1, 2], [4, 3]],
+        );
+
+        assert_eq!(
+            from_csv.possible_profiles().collect::<Vec<_>>(),
+            bimatrix.possible_profiles().collect::<Vec<_>>(),
+        );
+        for profile in bimatrix.possible_profiles() {
+            assert_eq!(from_csv.payoff(profile), bimatrix.payoff(profile));
+        }
+    }
+
+    #[test]
+    fn from_csv_errors() {
+        let load = |csv: &str| {
+            Normal::<char, i32, 2>::from_csv(
+                csv.as_bytes(),
+                |s| s.chars().next(),
+                |s| s.parse().ok(),
+            )
+        };
+        assert!(matches!(load(""), Err(CsvError::MissingHeader)));
+        assert!(matches!(
+            load(",D,E\n\nA,0;5\n"),
+            Err(CsvError::RowLength {
+                line: 3,
+                expected: 3,
+                found: 2,
+            })
+        ));
+        assert!(matches!(
+            load(",D,\nA,0;5,5;0\n"),
+            Err(CsvError::InvalidMove { line: 1, .. })
+        ));
+        assert!(matches!(
+            load(",D,E\nA,0;5,5\n"),
+            Err(CsvError::InvalidPayoff { line: 2, .. })
+        ));
+        assert!(matches!(
+            load(",D,E\nA,0;5,x;0\n"),
+            Err(CsvError::InvalidPayoff { line: 2, .. })
+        ));
+        assert!(matches!(
+            load(",Dove,Duck\nA,0;5,5;0\n"),
+            Err(CsvError::DuplicateMove { line: 1, label }) if label == "Duck"
+        ));
+        assert!(matches!(
+            load(",D,E\n\nApple,0;5,5;0\nAlpha,4;1,3;2\n"),
+            Err(CsvError::DuplicateMove { line: 4, label }) if label == "Alpha"
+        ));
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[test]
+    fn heterogeneous_color_vs_number() {
+        use Color::*;
+
+        // P0 wins if the number is the index of their color, otherwise P1 wins.
+        let g = Normal::heterogeneous(
+            for2::per_player_moves(vec![Red, Green, Blue], vec![0, 1, 2]),
+            |color, n| {
+                if color as i32 == n {
+                    Payoff::from([1, -1])
+                } else {
+                    Payoff::from([-1, 1])
+                }
+            },
+        );
+
+        assert!(g.is_zero_sum());
+        assert_eq!(g.dimensions(), PerPlayer::new([3, 3]));
+        assert_eq!(
+            g.possible_moves_for_player(for2::P0).collect::<Vec<_>>(),
+            vec![
+                for2::Move::P0(Red),
+                for2::Move::P0(Green),
+                for2::Move::P0(Blue)
+            ],
+        );
+        assert_eq!(
+            g.possible_moves_for_player(for2::P1).collect::<Vec<_>>(),
+            vec![for2::Move::P1(0), for2::Move::P1(1), for2::Move::P1(2)],
+        );
+        assert_eq!(
+            g.payoff(Profile::new([for2::Move::P0(Blue), for2::Move::P1(2)])),
+            Payoff::from([1, -1]),
+        );
+        assert_eq!(
+            g.payoff(Profile::new([for2::Move::P0(Blue), for2::Move::P1(0)])),
+            Payoff::from([-1, 1]),
+        );
+        assert!(g.pure_nash_equilibria().is_empty());
+        assert!(!g.is_valid_profile(Profile::new([for2::Move::P1(0), for2::Move::P1(1)])));
+        assert_eq!(
+            g.payoff(Profile::new([for2::Move::P1(0), for2::Move::P1(1)])),
+            Payoff::zeros(),
+        );
     }
 }
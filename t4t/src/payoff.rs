@@ -1,5 +1,6 @@
 use derive_more::{AsMut, AsRef, Index, IndexMut};
 use num::{FromPrimitive, Num};
+use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::{Add, Mul, Sub};
@@ -123,6 +124,21 @@ impl<U: Utility, const P: usize> Payoff<U, P> {
         Payoff { utilities }
     }
 
+    /// Construct a new payoff by computing each player's utility from their index.
+    ///
+    /// # Example
+    /// ```
+    /// use t4t::Payoff;
+    ///
+    /// assert_eq!(
+    ///     Payoff::from_fn(|player| player.as_usize() * 10),
+    ///     Payoff::from([0, 10, 20, 30]),
+    /// );
+    /// ```
+    pub fn from_fn(f: impl FnMut(PlayerIndex<P>) -> U) -> Self {
+        Payoff::new(PerPlayer::generate(f))
+    }
+
     /// Construct a payoff where every player's utility is identical.
     ///
     /// Note that the size of the payoff is determined by the type parameter `P`, which
@@ -181,6 +197,23 @@ impl<U: Utility, const P: usize> Payoff<U, P> {
         self
     }
 
+    /// Map a function over the utility of each player, producing a new payoff. This is useful for
+    /// converting between utility types.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::Payoff;
+    ///
+    /// let ints: Payoff<i32, 2> = Payoff::from([3, -1]);
+    /// let floats: Payoff<f64, 2> = ints.map(|u| u as f64);
+    /// assert_eq!(floats, Payoff::from([3.0, -1.0]));
+    ///
+    /// assert_eq!(ints.map(|u| u * 10), Payoff::from([30, -10]));
+    /// ```
+    pub fn map<V: Utility>(&self, f: impl Fn(U) -> V) -> Payoff<V, P> {
+        Payoff::new(self.utilities.map(f))
+    }
+
     /// Get the number of players in the game, which corresponds to the number of elements in the
     /// payoff.
     ///
@@ -249,6 +282,28 @@ impl<U: Utility, const P: usize> Payoff<U, P> {
         sum == U::zero()
     }
 
+    /// The total utility of the given coalition of players, that is, the sum of the utility values
+    /// of its members.
+    ///
+    /// Each member is counted once, even if it is listed several times. The total of an empty
+    /// coalition is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let payoff = Payoff::from([4, -1, 3]);
+    /// assert_eq!(payoff.coalition_total(&[for3::P0, for3::P2]), 7);
+    /// assert_eq!(payoff.coalition_total(&[for3::P1]), -1);
+    /// assert_eq!(payoff.coalition_total(&[for3::P2, for3::P2]), 3);
+    /// assert_eq!(payoff.coalition_total(&[]), 0);
+    /// ```
+    pub fn coalition_total(&self, members: &[PlayerIndex<P>]) -> U {
+        PlayerIndex::all()
+            .filter(|player| members.contains(player))
+            .fold(U::zero(), |total, player| total + self[player])
+    }
+
     /// The amount that a given payoff represents a
     /// [Pareto improvement](https://en.wikipedia.org/wiki/Pareto_efficiency) over this payoff.
     ///
@@ -291,13 +346,74 @@ impl<U: Utility, const P: usize> Payoff<U, P> {
         }
     }
 
-    /// Map a function over all elements in a payoff.
-    fn map(self, f: impl Fn(U) -> U) -> Self {
-        let mut result = [U::zero(); P];
-        for (r, v) in result.iter_mut().zip(self) {
-            *r = f(v);
-        }
-        Payoff::from(result)
+    /// Compare this payoff to another in
+    /// [lexicographic order](https://en.wikipedia.org/wiki/Lexicographic_order), that is, by the
+    /// utility of player `P0`, breaking ties by the utility of player `P1`, and so on.
+    ///
+    /// This ordering gives strict priority to players with lower indexes. Incomparable utility
+    /// values, such as `NaN`, are treated as equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use t4t::Payoff;
+    ///
+    /// let a = Payoff::from([3, 1, 0]);
+    /// assert_eq!(a.lexicographic_cmp(&Payoff::from([2, 9, 9])), Ordering::Greater);
+    /// assert_eq!(a.lexicographic_cmp(&Payoff::from([3, 1, 1])), Ordering::Less);
+    /// assert_eq!(a.lexicographic_cmp(&a), Ordering::Equal);
+    /// ```
+    pub fn lexicographic_cmp(&self, other: &Payoff<U, P>) -> Ordering {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Compare this payoff to another in
+    /// [leximin order](https://en.wikipedia.org/wiki/Leximin_order), where `Ordering::Greater`
+    /// means this payoff is preferred.
+    ///
+    /// The leximin order prefers the payoff whose worst-off player receives more utility, breaking
+    /// ties by the second worst-off player, and so on. This is an egalitarian criterion that, unlike
+    /// maximizing the sum of utilities, favors improving the utility of the worst-off players.
+    /// Incomparable utility values, such as `NaN`, are treated as equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use t4t::Payoff;
+    ///
+    /// let equal = Payoff::from([2, 2]);
+    /// let unequal = Payoff::from([3, 1]);
+    /// let lopsided = Payoff::from([1, 5]);
+    ///
+    /// // With the same total utility, leximin prefers the more equal payoff.
+    /// assert_eq!(equal.leximin_cmp(&unequal), Ordering::Greater);
+    ///
+    /// // Maximizing the total utility prefers the lopsided payoff, while leximin does not.
+    /// assert!(lopsided.iter().sum::<i32>() > equal.iter().sum::<i32>());
+    /// assert_eq!(equal.leximin_cmp(&lopsided), Ordering::Greater);
+    ///
+    /// // A payoff that improves on the worst-off player is preferred by both.
+    /// assert_eq!(Payoff::from([3, 3]).leximin_cmp(&equal), Ordering::Greater);
+    ///
+    /// // The order of players does not matter.
+    /// assert_eq!(unequal.leximin_cmp(&Payoff::from([1, 3])), Ordering::Equal);
+    /// ```
+    pub fn leximin_cmp(&self, other: &Payoff<U, P>) -> Ordering {
+        let sorted = |payoff: &Payoff<U, P>| {
+            let mut utils: Vec<U> = payoff.iter().copied().collect();
+            utils.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            utils
+        };
+        sorted(self)
+            .into_iter()
+            .zip(sorted(other))
+            .map(|(a, b)| a.partial_cmp(&b).unwrap_or(Ordering::Equal))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
     }
 
     /// Combine two payoffs element-wise using the given function.
@@ -139,6 +139,46 @@ impl<T, const P: usize> PerPlayer<T, P> {
         PerPlayer::new(indexes.map(gen_elem))
     }
 
+    /// Create a new per-player collection from the elements of the given iterator, which must
+    /// yield exactly one element per player.
+    ///
+    /// This is the fallible counterpart of collecting into a per-player collection via its
+    /// [`FromIterator`] implementation, which panics instead.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the iterator yields fewer or more than `P` elements.
+    /// At most `P + 1` elements are consumed from the iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::{for3, PerPlayer};
+    ///
+    /// let pp = PerPlayer::<_, 3>::try_from_iter("abc".chars()).unwrap();
+    /// assert_eq!(pp[for3::P0], 'a');
+    /// assert_eq!(pp[for3::P2], 'c');
+    ///
+    /// assert_eq!(PerPlayer::<_, 3>::try_from_iter("ab".chars()), None);
+    /// assert_eq!(PerPlayer::<_, 3>::try_from_iter("abcd".chars()), None);
+    /// ```
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+        let elements: Vec<T> = iter.into_iter().take(P + 1).collect();
+        let count = elements.len();
+        match elements.try_into() {
+            Ok(data) => Some(PerPlayer::new(data)),
+            Err(_) => {
+                log::error!(
+                    "PerPlayer<{}>::try_from_iter: expected {} elements, got {}{}",
+                    P,
+                    P,
+                    count,
+                    if count > P { " or more" } else { "" },
+                );
+                None
+            }
+        }
+    }
+
     /// Get the number of players in the game, which corresponds to the number of elements in this
     /// collection.
     pub fn num_players(&self) -> usize {
@@ -257,6 +297,41 @@ impl<T, const P: usize> PerPlayer<T, P> {
     pub fn for_player_mut(&mut self, idx: PlayerIndex<P>) -> &mut T {
         unsafe { self.data.get_unchecked_mut(idx.0) }
     }
+
+    /// Set the element corresponding to the given player.
+    ///
+    /// This is equivalent to assigning via the [`IndexMut`] trait, but may read more clearly when
+    /// passed around as a method.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::{for4, PerPlayer};
+    ///
+    /// let mut pp = PerPlayer::new(["frodo", "sam", "merry", "pippin"]);
+    /// pp.set(for4::P1, "samwise");
+    /// assert_eq!(pp, PerPlayer::new(["frodo", "samwise", "merry", "pippin"]));
+    /// ```
+    pub fn set(&mut self, idx: PlayerIndex<P>, value: T) {
+        *self.for_player_mut(idx) = value;
+    }
+
+    /// Swap the elements corresponding to two players in place. Swapping a player's element with
+    /// itself leaves the collection unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::{for4, PerPlayer};
+    ///
+    /// let mut pp = PerPlayer::new(["frodo", "sam", "merry", "pippin"]);
+    /// pp.swap(for4::P0, for4::P2);
+    /// assert_eq!(pp, PerPlayer::new(["merry", "sam", "frodo", "pippin"]));
+    ///
+    /// pp.swap(for4::P3, for4::P3);
+    /// assert_eq!(pp, PerPlayer::new(["merry", "sam", "frodo", "pippin"]));
+    /// ```
+    pub fn swap(&mut self, a: PlayerIndex<P>, b: PlayerIndex<P>) {
+        self.data.swap(a.0, b.0);
+    }
 }
 
 impl<T: Clone, const P: usize> PerPlayer<T, P> {
@@ -356,6 +431,43 @@ impl<T: Clone, const P: usize> PerPlayer<T, P> {
             f(index, elem)
         }))
     }
+
+    /// Produce a new per-player collection with the elements rotated `n` places to the left, that
+    /// is, the element for player `n` becomes the element for player `0`. Values of `n` larger
+    /// than the number of players wrap around.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::PerPlayer;
+    ///
+    /// let pp = PerPlayer::new(['a', 'b', 'c']);
+    /// assert_eq!(pp.rotate_left(0), PerPlayer::new(['a', 'b', 'c']));
+    /// assert_eq!(pp.rotate_left(1), PerPlayer::new(['b', 'c', 'a']));
+    /// assert_eq!(pp.rotate_left(2), PerPlayer::new(['c', 'a', 'b']));
+    /// assert_eq!(pp.rotate_left(4), PerPlayer::new(['b', 'c', 'a']));
+    /// ```
+    pub fn rotate_left(&self, n: usize) -> PerPlayer<T, P> {
+        let mut data = self.data.clone();
+        if P > 0 {
+            data.rotate_left(n % P);
+        }
+        PerPlayer::new(data)
+    }
+
+    /// Produce a new per-player collection with the elements in reverse order.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::PerPlayer;
+    ///
+    /// let pp = PerPlayer::new(['a', 'b', 'c']);
+    /// assert_eq!(pp.reverse(), PerPlayer::new(['c', 'b', 'a']));
+    /// ```
+    pub fn reverse(&self) -> PerPlayer<T, P> {
+        let mut data = self.data.clone();
+        data.reverse();
+        PerPlayer::new(data)
+    }
 }
 
 impl<T: core::fmt::Debug, const P: usize> PerPlayer<Option<T>, P> {
@@ -395,6 +507,42 @@ impl<T, const P: usize> From<[T; P]> for PerPlayer<T, P> {
     }
 }
 
+/// Collect an iterator into a per-player collection. The iterator must yield exactly one element
+/// per player.
+///
+/// # Panics
+///
+/// Panics if the iterator yields fewer or more than `P` elements. Use
+/// [`PerPlayer::try_from_iter`] to handle the wrong number of elements without panicking.
+///
+/// # Examples
+/// ```
+/// use t4t::{for3, PerPlayer};
+///
+/// let lengths: PerPlayer<usize, 3> = ["ann", "bo", "cyd"].iter().map(|s| s.len()).collect();
+/// assert_eq!(lengths, PerPlayer::new([3, 2, 3]));
+/// ```
+///
+/// Collecting the wrong number of elements panics.
+/// ```should_panic
+/// use t4t::PerPlayer;
+///
+/// let too_few: PerPlayer<i32, 3> = vec![1, 2].into_iter().collect();
+/// ```
+impl<T, const P: usize> FromIterator<T> for PerPlayer<T, P> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let elements: Vec<T> = iter.into_iter().collect();
+        let count = elements.len();
+        match elements.try_into() {
+            Ok(data) => PerPlayer::new(data),
+            Err(_) => panic!(
+                "PerPlayer<{}>::from_iter: expected {} elements, got {}",
+                P, P, count
+            ),
+        }
+    }
+}
+
 impl<T, const P: usize> PerPlayer<T, P> {
     /// An iterator over references to elements in the per-player collection.
     pub fn iter(&self) -> <&[T; P] as IntoIterator>::IntoIter {
@@ -1,11 +1,19 @@
-use crate::{Game, Strategy};
+use crate::{Context, Game, Strategy};
 use std::fmt;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
 /// A player consists of a name and a function that produces its [strategy](Strategy).
 ///
 /// A player's name must be unique with respect to all other players playing the same game (e.g.
 /// in a tournament).
+///
+/// The player's strategy function is called to produce a fresh strategy every time the player
+/// plays a game, including once for each matchup the player participates in within a
+/// [tournament](crate::Tournament). This ensures that strategies with internal state, such as
+/// learning strategies, do not carry state from one game or opponent to the next.
 #[derive(Clone)]
 pub struct Player<G: Game<P>, const P: usize> {
     name: String,
@@ -24,6 +32,177 @@ impl<G: Game<P>, const P: usize> Player<G, P> {
         }
     }
 
+    /// Construct a new player with the given name and a cloneable function that computes the
+    /// player's next move, as passed to [`Strategy::new`].
+    ///
+    /// This avoids the boilerplate of a strategy-producing closure when the strategy is defined
+    /// by a single function. Each game gets a fresh clone of the function, so any state captured
+    /// by the function starts over in each game, just as with [`Player::new`].
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 4);
+    ///
+    /// // Cooperates twice, then defects, counting moves in state captured by the function.
+    /// let mut moves_played = 0;
+    /// let impatient = Player::from_strategy("Impatient".to_string(), move |_| {
+    ///     moves_played += 1;
+    ///     if moves_played > 2 { 'D' } else { 'C' }
+    /// });
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    /// let matchup = Matchup::from_players([impatient, nice]);
+    ///
+    /// for _ in 0..2 {
+    ///     let history = rpd.play(&matchup).unwrap();
+    ///     assert_eq!(
+    ///         history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+    ///         vec!['C', 'C', 'D', 'D'],
+    ///     );
+    /// }
+    /// ```
+    pub fn from_strategy(
+        name: String,
+        next_move: impl FnMut(&Context<G::View, P>) -> G::Move + Clone + Send + Sync + 'static,
+    ) -> Self {
+        Player::new(name, move || Strategy::new(next_move.clone()))
+    }
+
+    /// Construct an interactive player whose moves are read from an external source, such as a
+    /// human entering moves on the command line.
+    ///
+    /// Each time it is the player's turn, `read_move` is called with the player's current
+    /// [context](Context) and should block until a move is available. The context contains the
+    /// player's view of the game state, which can be displayed when prompting for a move. The same
+    /// move source is shared by every game the player participates in.
+    ///
+    /// The entered move is validated by the game like any other move. Play the game with
+    /// [`play_with_retries`](Game::play_with_retries) to request a new move from the player after
+    /// an invalid entry, rather than ending the game with an error. On a retry, `read_move` is
+    /// called again with the same context.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// // A scripted stand-in for reading moves from stdin, where the first entry is a typo.
+    /// let mut input = "x\nD\n".lines();
+    /// let human = Player::human("Human".to_string(), move |_| {
+    ///     input.next().and_then(|line| line.chars().next()).unwrap_or('C')
+    /// });
+    /// let bot = Player::new("Bot".to_string(), || Strategy::pure('C'));
+    ///
+    /// let outcome = pd.play_with_retries(&Matchup::from_players([human, bot]), 3).unwrap();
+    /// assert_eq!(*outcome.profile(), Profile::new(['D', 'C']));
+    /// ```
+    pub fn human(
+        name: String,
+        read_move: impl FnMut(&Context<G::View, P>) -> G::Move + Send + 'static,
+    ) -> Self {
+        let read_move = Arc::new(Mutex::new(read_move));
+        Player::new(name, move || {
+            let read_move = read_move.clone();
+            Strategy::new(move |context| {
+                let mut read_move = read_move.lock().unwrap();
+                read_move(context)
+            })
+        })
+    }
+
+    /// Construct a player that plays like this player, but plays the `default` move whenever this
+    /// player's strategy takes longer than `limit` to produce a move.
+    ///
+    /// This protects games and tournaments from untrusted or expensive strategies. Each strategy
+    /// runs on its own worker thread, which computes one move at a time. If the move is not
+    /// produced within the time limit, or the strategy panics, a warning is logged and the default
+    /// move is played instead, and the game continues.
+    ///
+    /// Note that an overrunning strategy is not interrupted. It keeps running in the background,
+    /// and its move is discarded when it eventually finishes. Until then, the player's subsequent
+    /// moves are the default move, played immediately without waiting for the time limit.
+    ///
+    /// The [acting name](Strategy::acting_name) of each move computed in time is passed back from
+    /// the worker thread, so transcripts still record which strategy chose it. A default move is
+    /// not attributed to any strategy.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    ///
+    /// let slow = Player::new("Slow".to_string(), || {
+    ///     Strategy::new(|_: &Context<(), 2>| {
+    ///         std::thread::sleep(Duration::from_millis(500));
+    ///         'C'
+    ///     })
+    /// });
+    /// let limited = slow.with_time_limit(Duration::from_millis(10), 'D');
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    ///
+    /// let outcome = pd.play(&Matchup::from_players([limited, nice])).unwrap();
+    /// assert_eq!(*outcome.profile(), Profile::new(['D', 'C']));
+    /// ```
+    pub fn with_time_limit(self, limit: Duration, default: G::Move) -> Self {
+        let name = self.name.clone();
+        let new_inner = self.new_strategy;
+        Player::new(self.name, move || {
+            let mut inner = new_inner();
+            let (jobs, job_receiver) = mpsc::channel();
+            let busy = Arc::new(AtomicBool::new(false));
+            let worker_busy = busy.clone();
+            thread::spawn(move || {
+                for job in job_receiver {
+                    match job {
+                        TimedJob::Move(context, result) => {
+                            let _ = result.send(inner.next_attributed_move(&context));
+                            worker_busy.store(false, Ordering::SeqCst);
+                        }
+                        TimedJob::Reset => inner.reset(),
+                    }
+                }
+            });
+
+            let resetter = jobs.clone();
+            let name = name.clone();
+            Strategy::new_attributed(move |context: &Context<G::View, P>| {
+                if busy.swap(true, Ordering::SeqCst) {
+                    log::warn!(
+                        "Player::with_time_limit: {} is still computing an earlier move, \
+                         playing the default move {:?}",
+                        name,
+                        default
+                    );
+                    return (default, None);
+                }
+                let (sender, receiver) = mpsc::channel();
+                let _ = jobs.send(TimedJob::Move(context.clone(), sender));
+                receiver.recv_timeout(limit).unwrap_or_else(|_| {
+                    log::warn!(
+                        "Player::with_time_limit: {} failed to produce a move within {:?}, \
+                         playing the default move {:?}",
+                        name,
+                        limit,
+                        default
+                    );
+                    (default, None)
+                })
+            })
+            .with_reset(move || {
+                let _ = resetter.send(TimedJob::Reset);
+            })
+        })
+    }
+
     /// The player's name.
     pub fn name(&self) -> &str {
         &self.name
@@ -41,15 +220,161 @@ impl<G: Game<P>, const P: usize> std::fmt::Debug for Player<G, P> {
     }
 }
 
+/// A request sent to the worker thread running a [time-limited](Player::with_time_limit) strategy.
+enum TimedJob<V, M, const P: usize> {
+    /// Compute the next move in the given context and send it back on the given channel.
+    Move(Context<V, P>, mpsc::Sender<(M, Option<String>)>),
+    /// Reset the strategy.
+    Reset,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Normal;
+    use crate::{for2, Matchup, Normal, Repeated, RepeatedState};
     use impls::impls;
+    use std::sync::atomic::AtomicUsize;
     use test_log::test;
 
     #[test]
     fn player_is_send_sync() {
         assert!(impls!(Player<Normal<(), u8, 2>, 2>: Send & Sync));
     }
+
+    #[test]
+    fn from_strategy_plays_like_closure_based_player() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 10);
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        // Tit-for-tat, except that it defects every third round.
+        let next_move = |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+            let round = context.state_view().history().num_games();
+            if round % 3 == 2 {
+                'D'
+            } else {
+                context.their_last_move().unwrap_or('C')
+            }
+        };
+        let from_strategy: Player<Rpd, 2> = Player::from_strategy("Tester".to_string(), next_move);
+        let from_closure: Player<Rpd, 2> =
+            Player::new("Tester".to_string(), move || Strategy::new(next_move));
+
+        let opponent = Player::new("Alternator".to_string(), || {
+            Strategy::periodic_pure(vec!['C', 'D'])
+        });
+        let expected = rpd
+            .play(&Matchup::from_players([from_closure, opponent.clone()]))
+            .unwrap();
+        let actual = rpd
+            .play(&Matchup::from_players([from_strategy, opponent]))
+            .unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.moves_for_player(for2::P0).collect::<Vec<_>>(),
+            vec!['C', 'C', 'D', 'C', 'D', 'D', 'D', 'C', 'D', 'C'],
+        );
+    }
+
+    #[test]
+    fn time_limit_substitutes_default_move_and_play_continues() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 3);
+        let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+
+        let slow = Player::new("Slow".to_string(), || {
+            Strategy::new(|_| {
+                thread::sleep(Duration::from_millis(200));
+                'C'
+            })
+        });
+        let limited = slow.with_time_limit(Duration::from_millis(10), 'D');
+        assert_eq!(limited.name(), "Slow");
+        let history = rpd
+            .play(&Matchup::from_players([limited, nice.clone()]))
+            .unwrap();
+        assert_eq!(
+            history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+            vec!['D', 'D', 'D'],
+        );
+
+        let copycat = Player::new("Copycat".to_string(), || Strategy::lagged_mirror(1, 'D'));
+        let limited = copycat.with_time_limit(Duration::from_secs(10), 'C');
+        let history = rpd.play(&Matchup::from_players([limited, nice])).unwrap();
+        assert_eq!(
+            history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+            vec!['D', 'C', 'C'],
+        );
+    }
+
+    #[test]
+    fn time_limit_preserves_acting_name() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 4);
+        let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+
+        let alternator = Player::new("Alternator".to_string(), || {
+            Strategy::periodic(vec![
+                Strategy::pure('C').with_name("Nice"),
+                Strategy::pure('D').with_name("Mean"),
+            ])
+        });
+        let limited = alternator.with_time_limit(Duration::from_secs(10), 'C');
+        let (history, transcript) =
+            rpd.play_with_transcript(&Matchup::from_players([limited, nice]));
+        assert_eq!(
+            history
+                .unwrap()
+                .moves_for_player(for2::P0)
+                .collect::<Vec<_>>(),
+            vec!['C', 'D', 'C', 'D'],
+        );
+        let names = transcript
+            .iter()
+            .enumerate()
+            .filter(|(_, ply)| ply.player == Some(for2::P0))
+            .map(|(index, _)| transcript.annotation(index))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![Some("Nice"), Some("Mean"), Some("Nice"), Some("Mean")]
+        );
+    }
+
+    #[test]
+    fn time_limit_does_not_queue_moves_behind_a_never_returning_move() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 5);
+        let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+
+        // The strategy blocks until the release channel is closed, and counts its moves.
+        let (release, blocked) = mpsc::channel::<()>();
+        let blocked = Arc::new(Mutex::new(blocked));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let stuck = {
+            let calls = calls.clone();
+            Player::new("Stuck".to_string(), move || {
+                let blocked = blocked.clone();
+                let calls = calls.clone();
+                Strategy::new(move |_| {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    let _ = blocked.lock().unwrap().recv();
+                    'C'
+                })
+            })
+        };
+
+        let limited = stuck.with_time_limit(Duration::from_millis(10), 'D');
+        let history = rpd.play(&Matchup::from_players([limited, nice])).unwrap();
+        assert_eq!(
+            history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+            vec!['D'; 5],
+        );
+
+        // Later moves were not handed to the strategy while the first move was still running, so
+        // none of them run once it finishes.
+        drop(release);
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
 }
@@ -1,18 +1,28 @@
 use crate::{Move, PerPlayer, PlayerIndex, PossibleMoves, Profile};
-use itertools::{Itertools, MultiProduct};
 
 /// An iterator over all pure strategy profiles for a [normal-form game](crate::Normal).
 ///
 /// This iterator enumerates all profiles that can be produced from the moves available to each
 /// player.
+///
+/// Profiles are generated lazily, like an odometer: each player's move iterator is advanced in
+/// turn, and restarted from a clone of the original iterator when exhausted. No allocation is
+/// needed per profile, and restarting an iterator over moves [borrowed](PossibleMoves::from_slice)
+/// from a game or [shared](PossibleMoves::from_vec) between iterators does not copy the moves.
 #[derive(Clone)]
 pub struct PossibleProfiles<'g, M: Copy, const P: usize> {
     /// Moves that must be included in any generated profile, for each player.
     includes: PerPlayer<Vec<M>, P>,
     /// Moves that must be excluded from any generated profile, for each player.
     excludes: PerPlayer<Vec<M>, P>,
-    /// The multi-product iterator used to generate each profile.
-    multi_iter: MultiProduct<PossibleMoves<'g, M>>,
+    /// The original iterator over each player's moves, cloned to restart that player's moves.
+    move_iters: PerPlayer<PossibleMoves<'g, M>, P>,
+    /// The iterator over each player's remaining moves.
+    current_iters: PerPlayer<PossibleMoves<'g, M>, P>,
+    /// The most recently generated profile, if any.
+    current: Option<PerPlayer<M, P>>,
+    /// Have all profiles been generated?
+    is_done: bool,
 }
 
 impl<'g, M: Move, const P: usize> PossibleProfiles<'g, M, P> {
@@ -45,7 +55,10 @@ impl<'g, M: Move, const P: usize> PossibleProfiles<'g, M, P> {
         PossibleProfiles {
             includes: PerPlayer::init_with(Vec::new()),
             excludes: PerPlayer::init_with(Vec::new()),
-            multi_iter: move_iters.into_iter().multi_cartesian_product(),
+            current_iters: move_iters.clone(),
+            move_iters,
+            current: None,
+            is_done: false,
         }
     }
 
@@ -295,12 +308,49 @@ impl<'g, M: Move, const P: usize> PossibleProfiles<'g, M, P> {
     }
 }
 
+impl<'g, M: Move, const P: usize> PossibleProfiles<'g, M, P> {
+    /// Generate the next combination of moves, ignoring the include and exclude constraints.
+    fn next_unconstrained(&mut self) -> Option<PerPlayer<M, P>> {
+        if self.is_done {
+            return None;
+        }
+        let Some(moves) = &mut self.current else {
+            self.current =
+                PerPlayer::generate(|player| self.current_iters[player].next()).all_some();
+            self.is_done = self.current.is_none();
+            return self.current;
+        };
+        // Advance the last player's move, carrying over to the previous player's move whenever a
+        // player's moves are exhausted.
+        for index in (0..P).rev() {
+            let player = PlayerIndex::new(index).unwrap();
+            if let Some(the_move) = self.current_iters[player].next() {
+                moves[player] = the_move;
+                for later in index + 1..P {
+                    let later = PlayerIndex::new(later).unwrap();
+                    self.current_iters[later] = self.move_iters[later].clone();
+                    match self.current_iters[later].next() {
+                        Some(the_move) => moves[later] = the_move,
+                        None => {
+                            self.is_done = true;
+                            return None;
+                        }
+                    }
+                }
+                return Some(*moves);
+            }
+        }
+        self.is_done = true;
+        None
+    }
+}
+
 impl<'g, M: Move, const P: usize> Iterator for PossibleProfiles<'g, M, P> {
     type Item = Profile<M, P>;
 
     fn next(&mut self) -> Option<Profile<M, P>> {
-        for moves in self.multi_iter.by_ref() {
-            let profile = Profile::new(moves.try_into().unwrap());
+        while let Some(moves) = self.next_unconstrained() {
+            let profile = Profile::from_per_player(moves);
             let mut good = true;
             for player in PlayerIndex::all() {
                 let m = profile[player];
@@ -326,6 +376,12 @@ mod tests {
     use impls::impls;
     use test_log::test;
 
+    #[test]
+    fn profiles_with_an_empty_move_set() {
+        let profiles = PossibleProfiles::from_move_vecs(PerPlayer::new([vec!['A', 'B'], vec![]]));
+        assert_eq!(profiles.count(), 0);
+    }
+
     #[test]
     fn possible_profiles_is_send_sync() {
         assert!(impls!(PossibleProfiles<'_, (), 2>: Send & Sync));
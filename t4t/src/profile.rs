@@ -39,7 +39,7 @@ impl<M: Move, const P: usize> Profile<M, P> {
 }
 
 impl<M: Move, const P: usize> Record<M, P> for Profile<M, P> {
-    fn plies(&self) -> Plies<M, P> {
+    fn plies(&self) -> Plies<'_, M, P> {
         Plies::from_iter(
             P,
             self.as_ref()
@@ -52,11 +52,11 @@ impl<M: Move, const P: usize> Record<M, P> for Profile<M, P> {
         Summary::simultaneous()
     }
 
-    fn played_moves_by_chance(&self) -> PlayedMoves<M> {
+    fn played_moves_by_chance(&self) -> PlayedMoves<'_, M> {
         PlayedMoves::empty()
     }
 
-    fn played_moves_by_player(&self, player: PlayerIndex<P>) -> PlayedMoves<M> {
+    fn played_moves_by_player(&self, player: PlayerIndex<P>) -> PlayedMoves<'_, M> {
         PlayedMoves::from_move(self[player])
     }
 }
@@ -0,0 +1,84 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::cell::RefCell;
+
+thread_local! {
+    /// The seeded source of randomness for the current thread, if any.
+    static SEEDED_RNG: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Run the given function with the library's source of randomness seeded by `seed` on the
+/// current thread.
+///
+/// All randomness in the library, such as [sampling a distribution](crate::Distribution::sample),
+/// playing a [mixed strategy](crate::Strategy::mixed), or making a move of chance, draws from
+/// this source. By default, it is [`rand::thread_rng`]. Within the given function, it is instead a
+/// deterministic generator seeded by `seed`, so playing a game with stochastic strategies is
+/// reproducible. Calls may be nested, in which case the previous source is restored when the
+/// inner call returns.
+///
+/// Since the seeded source is local to the current thread, randomness on any threads spawned by
+/// the function is not affected.
+///
+/// # Examples
+/// ```
+/// use t4t::*;
+///
+/// let coin = Distribution::flat((0..100).collect()).unwrap();
+/// let flip = || (0..10).map(|_| *coin.sample()).collect::<Vec<_>>();
+///
+/// assert_eq!(with_seed(42, flip), with_seed(42, flip));
+/// assert_ne!(with_seed(42, flip), with_seed(43, flip));
+/// ```
+pub fn with_seed<T>(seed: u64, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous source of randomness when dropped, even if the function panics.
+    struct Restore(Option<StdRng>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SEEDED_RNG.with(|rng| *rng.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SEEDED_RNG.with(|rng| rng.replace(Some(StdRng::seed_from_u64(seed))));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Call the given function with the library's source of randomness for the current thread. See
+/// [`with_seed`].
+///
+/// The function must not draw randomness via this function again.
+pub(crate) fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    SEEDED_RNG.with(|seeded| match seeded.borrow_mut().as_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut rand::thread_rng()),
+    })
+}
+
+/// A handle to the library's source of randomness for the current thread, which can be used as a
+/// random number generator by custom strategies. See [`Context::rng`](crate::Context::rng).
+///
+/// Each random number is drawn from the source in effect when it is drawn, so within
+/// [`with_seed`], the numbers drawn are reproducible.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StrategyRng;
+
+impl RngCore for StrategyRng {
+    fn next_u32(&mut self) -> u32 {
+        with_rng(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        with_rng(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        with_rng(|rng| rng.fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        with_rng(|rng| rng.try_fill_bytes(dest))
+    }
+}
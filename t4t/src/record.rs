@@ -1,5 +1,5 @@
 use crate::summary::Summary;
-use crate::{Move, PerPlayer, PlayerIndex, Plies, Transcript};
+use crate::{Move, PerPlayer, PlayerIndex, Plies, Profile, Transcript, Utility};
 
 /// A record of moves played during a game.
 ///
@@ -13,7 +13,7 @@ pub trait Record<M: Move, const P: usize> {
     /// A [ply](https://en.wikipedia.org/wiki/Ply_(game_theory)) typically refers only to a move
     /// played in a sequential game. For records of simultaneous games this iterator will return
     /// the move played by each player in order of their player index.
-    fn plies(&self) -> Plies<M, P>;
+    fn plies(&self) -> Plies<'_, M, P>;
 
     /// A summary of the number of moves in this record.
     fn summary(&self) -> Summary<P>;
@@ -24,7 +24,7 @@ pub trait Record<M: Move, const P: usize> {
     }
 
     /// An iterator over all moves by chance.
-    fn played_moves_by_chance(&self) -> PlayedMoves<M> {
+    fn played_moves_by_chance(&self) -> PlayedMoves<'_, M> {
         let move_iter = self
             .plies()
             .filter(move |ply| ply.player.is_none())
@@ -33,7 +33,7 @@ pub trait Record<M: Move, const P: usize> {
     }
 
     /// An iterator over all moves by a particular player.
-    fn played_moves_by_player(&self, player: PlayerIndex<P>) -> PlayedMoves<M> {
+    fn played_moves_by_player(&self, player: PlayerIndex<P>) -> PlayedMoves<'_, M> {
         let move_iter = self
             .plies()
             .filter(move |ply| ply.player == Some(player))
@@ -42,9 +42,123 @@ pub trait Record<M: Move, const P: usize> {
     }
 
     /// Iterators over the moves by each player.
-    fn played_moves_per_player(&self) -> PerPlayer<PlayedMoves<M>, P> {
+    fn played_moves_per_player(&self) -> PerPlayer<PlayedMoves<'_, M>, P> {
         PerPlayer::generate(|player| self.played_moves_by_player(player))
     }
+
+    /// Group the moves by players into consecutive rounds in which each player moves exactly
+    /// once, and get the profile of each round. Moves by chance are ignored.
+    ///
+    /// This provides the same view of a record of simultaneous games, whether it is a
+    /// [`Profile`](crate::Profile), a [`Transcript`], or a [`History`](crate::History), so that
+    /// analysis code can be written once against this trait.
+    ///
+    /// Returns `None` if the moves cannot be grouped into such rounds, that is, if a player moves
+    /// twice before every other player has moved, or if the last round is incomplete.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let transcript: Transcript<char, 2> = Transcript::from_ply_vec(vec![
+    ///     Ply::player(for2::P0, 'A'),
+    ///     Ply::player(for2::P1, 'B'),
+    ///     Ply::chance('X'),
+    ///     Ply::player(for2::P1, 'C'),
+    ///     Ply::player(for2::P0, 'D'),
+    /// ]);
+    /// assert_eq!(
+    ///     transcript.played_profiles(),
+    ///     Some(vec![Profile::new(['A', 'B']), Profile::new(['D', 'C'])]),
+    /// );
+    ///
+    /// let unfinished: Transcript<char, 2> = Transcript::from_ply_vec(vec![
+    ///     Ply::player(for2::P0, 'A'),
+    ///     Ply::player(for2::P1, 'B'),
+    ///     Ply::player(for2::P0, 'C'),
+    /// ]);
+    /// assert_eq!(unfinished.played_profiles(), None);
+    /// ```
+    fn played_profiles(&self) -> Option<Vec<Profile<M, P>>> {
+        let mut profiles = Vec::new();
+        let mut round: PerPlayer<Option<M>, P> = PerPlayer::generate(|_| None);
+        for ply in self.plies() {
+            let Some(player) = ply.player else {
+                continue;
+            };
+            if round[player].replace(ply.the_move).is_some() {
+                return None;
+            }
+            if let Some(moves) = round.all_some() {
+                profiles.push(Profile::from_per_player(moves));
+                round = PerPlayer::generate(|_| None);
+            }
+        }
+        if round.iter().any(Option::is_some) {
+            return None;
+        }
+        Some(profiles)
+    }
+}
+
+/// A record of a sequence of games from the perspective of a single player.
+///
+/// Contains the moves played by the player, along with the utility they received and their rank
+/// among all players in each game. This is useful for building per-player reports.
+///
+/// A player's rank in a game is one more than the number of players who received a strictly
+/// greater utility in that game, so tied players share a rank.
+///
+/// Produced for repeated games by [`History::for_player`](crate::History::for_player).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlayerRecord<M, U> {
+    moves: Vec<M>,
+    utilities: Vec<U>,
+    ranks: Vec<usize>,
+}
+
+impl<M: Move, U: Utility> PlayerRecord<M, U> {
+    /// Construct a new, empty player record.
+    pub fn new() -> Self {
+        PlayerRecord {
+            moves: Vec::new(),
+            utilities: Vec::new(),
+            ranks: Vec::new(),
+        }
+    }
+
+    /// Add a game to the record, given the moves played by the player in the game, the utility
+    /// they received, and their rank.
+    pub fn add(&mut self, moves: impl IntoIterator<Item = M>, utility: U, rank: usize) {
+        self.moves.extend(moves);
+        self.utilities.push(utility);
+        self.ranks.push(rank);
+    }
+
+    /// The number of games in the record.
+    pub fn num_games(&self) -> usize {
+        self.utilities.len()
+    }
+
+    /// All moves played by the player, in order.
+    pub fn moves(&self) -> &[M] {
+        &self.moves
+    }
+
+    /// The utility received by the player in each game.
+    pub fn utilities(&self) -> &[U] {
+        &self.utilities
+    }
+
+    /// The rank of the player in each game, where rank 1 means no other player did better.
+    pub fn ranks(&self) -> &[usize] {
+        &self.ranks
+    }
+
+    /// The player's cumulative utility across all games.
+    pub fn score(&self) -> U {
+        self.utilities.iter().fold(U::zero(), |sum, u| sum + *u)
+    }
 }
 
 /// An iterator over the moves played in a game.
@@ -1,7 +1,18 @@
 use std::fmt;
 use std::sync::Arc;
 
-use crate::{Action, FiniteGame, Game, GameTree, History, PlayerIndex, PossibleMoves};
+use rand::Rng;
+
+use crate::random::with_rng;
+use crate::{
+    Action, FiniteGame, Game, GameTree, History, Move, NextGameTree, Normal, Payoff, PerPlayer,
+    PlayerIndex, PossibleMoves, Profile, SimultaneousOutcome, State, Utility,
+};
+
+/// A function that produces the outcome of a stage game as observed by the given player, given
+/// the actual outcome. See [`Repeated::with_observation_noise`].
+type ObserveFn<G, const P: usize> =
+    Arc<dyn Fn(&<G as Game<P>>::Outcome, PlayerIndex<P>) -> <G as Game<P>>::Outcome + Send + Sync>;
 
 /// A finitely [repeated](https://en.wikipedia.org/wiki/Repeated_game) or iterated version of game
 /// `G`.
@@ -12,6 +23,9 @@ use crate::{Action, FiniteGame, Game, GameTree, History, PlayerIndex, PossibleMo
 pub struct Repeated<G: Game<P>, const P: usize> {
     stage_game: Arc<G>,
     repetitions: usize,
+    window: Option<usize>,
+    observation_noise: f64,
+    observe: Option<ObserveFn<G, P>>,
 }
 
 /// The intermediate state of a repeated game.
@@ -21,6 +35,7 @@ pub struct RepeatedState<G: Game<P>, const P: usize> {
     stage_state: Arc<G::State>,
     completed: History<G, P>,
     remaining: usize,
+    observed: Option<(ObserveFn<G, P>, PerPlayer<History<G, P>, P>)>,
 }
 
 impl<G: Game<P> + 'static, const P: usize> Repeated<G, P> {
@@ -29,6 +44,47 @@ impl<G: Game<P> + 'static, const P: usize> Repeated<G, P> {
         Repeated {
             stage_game,
             repetitions,
+            window: None,
+            observation_noise: 0.0,
+            observe: None,
+        }
+    }
+
+    /// Construct a repeated game that plays the stage game the given number of repetitions, but
+    /// retains only the outcomes of the most recent `window` repetitions in its history.
+    ///
+    /// This bounds the memory used by long-running repeated games. Strategies observe a truncated
+    /// view of the history: [`outcomes`](History::outcomes), [`profiles`](History::profiles), and
+    /// similar methods only cover the retained repetitions, while the [score](History::score),
+    /// [summary](crate::Record::summary), and [number of games](History::num_games) still account
+    /// for every repetition played. Strategies that look back at most `window` games behave
+    /// exactly as they would in an unbounded repeated game. The history returned as the outcome
+    /// of the game is similarly truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new_bounded_memory(Arc::new(pd), 100, 3);
+    ///
+    /// let copycat = Player::new("Copycat".to_string(), || Strategy::lagged_mirror(1, 'C'));
+    /// let defector = Player::new("Defector".to_string(), || Strategy::pure('D'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([copycat, defector])).unwrap();
+    ///
+    /// assert_eq!(history.outcomes().len(), 3);
+    /// assert_eq!(history.num_games(), 100);
+    /// assert_eq!(*history.score(), Payoff::from([99, 102]));
+    /// ```
+    pub fn new_bounded_memory(stage_game: Arc<G>, repetitions: usize, window: usize) -> Self {
+        Repeated {
+            stage_game,
+            repetitions,
+            window: Some(window),
+            observation_noise: 0.0,
+            observe: None,
         }
     }
 
@@ -41,17 +97,187 @@ impl<G: Game<P> + 'static, const P: usize> Repeated<G, P> {
     pub fn repetitions(&self) -> usize {
         self.repetitions
     }
+
+    /// Get the number of most recent repetitions retained in the history, or `None` if the
+    /// history is unbounded.
+    pub fn window(&self) -> Option<usize> {
+        self.window
+    }
+
+    /// Get the probability that a player misperceives each of the other players' moves, which is
+    /// zero unless the game was constructed by
+    /// [`with_observation_noise`](Repeated::with_observation_noise).
+    pub fn observation_noise(&self) -> f64 {
+        self.observation_noise
+    }
+}
+
+impl<G, M, U, const P: usize> Repeated<G, P>
+where
+    G: FiniteGame<P, Move = M, Utility = U, Outcome = SimultaneousOutcome<M, U, P>> + 'static,
+    M: Move,
+    U: Utility,
+{
+    /// Construct a repeated game with
+    /// [imperfect monitoring](https://en.wikipedia.org/wiki/Repeated_game#Imperfect_monitoring),
+    /// where players observe each other's moves with noise.
+    ///
+    /// After each repetition, every player observes each other player's move incorrectly with
+    /// probability `error_prob`, in which case they observe a different move chosen uniformly at
+    /// random from the moves available to that player. Players always observe their own moves
+    /// correctly. Each player observes independently, so players may disagree about what
+    /// happened.
+    ///
+    /// Only the [history](RepeatedState::history) seen by strategies is noisy. Each outcome in an
+    /// observed history records the stage game's payoff for the observed moves, so the payoffs do
+    /// not reveal the actual moves. The actual payoffs of each repetition are determined by the
+    /// actual moves, and the outcome of the game is the actual history.
+    ///
+    /// Misperceptions are drawn from the library's source of randomness, so they can be made
+    /// reproducible with [`with_seed`](crate::with_seed).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `error_prob` is not between 0 and 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// type Dilemma = Normal<char, i32, 2>;
+    ///
+    /// let pd: Dilemma = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let noisy = Repeated::with_observation_noise(Arc::new(pd), 100, 1.0).unwrap();
+    ///
+    /// // With certain misperception, tit-for-tat always responds to the opposite of the other
+    /// // player's actual move.
+    /// let copycat = || Player::new("Copycat".to_string(), || Strategy::lagged_mirror(1, 'C'));
+    /// let history = noisy.play(&Matchup::from_players([copycat(), copycat()])).unwrap();
+    /// assert_eq!(
+    ///     history.moves_for_player(for2::P0).take(4).collect::<Vec<_>>(),
+    ///     vec!['C', 'D', 'C', 'D'],
+    /// );
+    /// assert_eq!(*history.score(), Payoff::from([150, 150]));
+    ///
+    /// assert!(Repeated::<Dilemma, 2>::with_observation_noise(noisy.stage_game().clone(), 100, 1.5)
+    ///     .is_none());
+    /// ```
+    pub fn with_observation_noise(
+        stage_game: Arc<G>,
+        repetitions: usize,
+        error_prob: f64,
+    ) -> Option<Self> {
+        if !(0.0..=1.0).contains(&error_prob) {
+            log::error!(
+                "Repeated::with_observation_noise: error probability must be between 0 and 1, got {}",
+                error_prob
+            );
+            return None;
+        }
+
+        let game = stage_game.clone();
+        let root = stage_game.game_tree();
+        let stage_state = root.state.clone();
+        let observe = move |outcome: &SimultaneousOutcome<M, U, P>, observer: PlayerIndex<P>| {
+            let moves = outcome
+                .profile()
+                .per_player()
+                .map_with_index(|player, actual| {
+                    if player == observer || !with_rng(|rng| rng.gen_bool(error_prob)) {
+                        return actual;
+                    }
+                    let others: Vec<M> = game
+                        .possible_moves(player, &stage_state)
+                        .filter(|m| *m != actual)
+                        .collect();
+                    if others.is_empty() {
+                        actual
+                    } else {
+                        others[with_rng(|rng| rng.gen_range(0..others.len()))]
+                    }
+                });
+            let observed = Profile::from_per_player(moves);
+            if observed == *outcome.profile() {
+                return outcome.clone();
+            }
+
+            // Play the observed profile on the stage game to get the payoff it would have produced.
+            let root = root.clone();
+            if let Action::Turns { to_move, next } = root.action {
+                let moves = to_move.iter().map(|&player| observed[player]).collect();
+                if let Ok(GameTree {
+                    action: Action::End { outcome, .. },
+                    ..
+                }) = next(root.state, moves)
+                {
+                    return outcome;
+                }
+            }
+            log::error!(
+                "Repeated::with_observation_noise: the stage game did not produce an outcome for the observed profile {:?}",
+                observed
+            );
+            outcome.clone()
+        };
+
+        Some(Repeated {
+            stage_game,
+            repetitions,
+            window: None,
+            observation_noise: error_prob,
+            observe: Some(Arc::new(observe)),
+        })
+    }
+}
+
+impl<M: Move, U: Utility, const P: usize> Repeated<Normal<M, U, P>, P> {
+    /// Is the given average payoff per round
+    /// [individually rational](https://en.wikipedia.org/wiki/Folk_theorem_(game_theory))? That is,
+    /// does it give each player strictly more than their [minmax value](Normal::minmax_value) in
+    /// the stage game?
+    ///
+    /// By the folk theorem, feasible payoffs that are individually rational can be sustained as
+    /// equilibria of the infinitely repeated game by threatening to punish any deviating player,
+    /// holding them to their minmax value. Note that the minmax values are computed using pure
+    /// moves only.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 100);
+    ///
+    /// // Mutual cooperation is individually rational, but being exploited is not.
+    /// assert!(rpd.is_individually_rational(Payoff::from([2, 2])));
+    /// assert!(!rpd.is_individually_rational(Payoff::from([0, 3])));
+    /// ```
+    pub fn is_individually_rational(&self, payoff: Payoff<U, P>) -> bool {
+        PlayerIndex::all().all(|player| {
+            self.stage_game
+                .minmax_value(player)
+                .is_some_and(|minmax| payoff[player] > minmax)
+        })
+    }
 }
 
 impl<G: Game<P>, const P: usize> RepeatedState<G, P> {
     /// Construct a new repeated game state.
     pub fn new(stage_game: Arc<G>, remaining: usize) -> Self {
         let stage_state = stage_game.game_tree().state.clone();
+        RepeatedState::with_stage_state(stage_game, stage_state, remaining)
+    }
+
+    /// Construct a new repeated game state whose stage game begins in the given state.
+    fn with_stage_state(stage_game: Arc<G>, stage_state: Arc<G::State>, remaining: usize) -> Self {
         RepeatedState {
             stage_game,
             stage_state,
             completed: History::empty(),
             remaining,
+            observed: None,
         }
     }
 
@@ -61,84 +287,163 @@ impl<G: Game<P>, const P: usize> RepeatedState<G, P> {
             .state_view(self.stage_state.as_ref(), player)
     }
 
+    /// Get the stage game being repeated.
+    ///
+    /// This enables strategies to reason about the payoff structure of the stage game, for example,
+    /// to compute a best response. The stage game is shared by reference counting, so clone the
+    /// returned `Arc` to keep a handle to the game beyond the lifetime of this state.
+    pub fn stage_game(&self) -> &Arc<G> {
+        &self.stage_game
+    }
+
     /// The current history of all completed repetitions of the stage game so far.
+    ///
+    /// In a game with [observation noise](Repeated::with_observation_noise), the state viewed by
+    /// each player contains the history as observed by that player.
     pub fn history(&self) -> &History<G, P> {
         &self.completed
     }
 
-    /// The number of remaining repetitions of the stage game to play.
+    /// The number of remaining repetitions of the stage game to play, not counting the
+    /// repetition currently in progress.
     pub fn remaining(&self) -> usize {
         self.remaining
     }
+
+    /// The total number of repetitions of the stage game in this repeated game, including
+    /// completed repetitions, the current repetition, and the remaining repetitions.
+    ///
+    /// This enables strategies to reason about the end of the game, for example, via backward
+    /// induction. [`Repeated`] games are always finitely repeated, so this always returns `Some`
+    /// for their states; `None` is reserved for indefinitely repeated games, whose length is not
+    /// known in advance.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+    ///
+    /// // Cooperate except in the last round.
+    /// let endgame_defector = Player::<Rpd, 2>::new("Endgame Defector".to_string(), || {
+    ///     Strategy::new(|context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+    ///         let state = context.state_view();
+    ///         let current = state.history().num_games() + 1;
+    ///         if Some(current) == state.total_rounds() { 'D' } else { 'C' }
+    ///     })
+    /// });
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd: Rpd = Repeated::new(Arc::new(pd), 4);
+    /// let cooperator = Player::new("Cooperator".to_string(), || Strategy::pure('C'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([endgame_defector, cooperator])).unwrap();
+    /// assert_eq!(
+    ///     history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+    ///     vec!['C', 'C', 'C', 'D'],
+    /// );
+    /// ```
+    pub fn total_rounds(&self) -> Option<usize> {
+        Some(self.completed.num_games() + self.remaining + 1)
+    }
 }
 
-fn lift_node<G: Game<P> + 'static, const P: usize>(
-    stage_game: Arc<G>,
-    state: Arc<RepeatedState<G, P>>,
-    node: GameTree<G::State, G::Move, G::Utility, G::Outcome, P>,
-) -> GameTree<RepeatedState<G, P>, G::Move, G::Utility, History<G, P>, P> {
-    match node.action {
-        Action::Turns {
-            to_move: players,
-            next,
-        } => GameTree::players(
-            state.clone(),
-            players,
-            move |repeated_state: Arc<RepeatedState<G, P>>, moves: Vec<G::Move>| match next(
-                repeated_state.stage_state.clone(),
-                moves,
-            ) {
-                Ok(stage_node) => {
-                    let mut next_state = (*state).clone();
-                    next_state.stage_state = stage_node.state.clone();
-
-                    Ok(lift_node(
-                        stage_game.clone(),
-                        Arc::new(next_state),
-                        stage_node,
-                    ))
-                }
+/// A node of a stage game's tree.
+pub(crate) type StageTree<G, const P: usize> = GameTree<
+    <G as Game<P>>::State,
+    <G as Game<P>>::Move,
+    <G as Game<P>>::Utility,
+    <G as Game<P>>::Outcome,
+    P,
+>;
 
-                Err(kind) => Err(kind),
-            },
-        ),
+/// The function computing the next node of a stage game after a turn.
+pub(crate) type StageNext<G, const P: usize> = Arc<
+    dyn NextGameTree<
+        Vec<<G as Game<P>>::Move>,
+        <G as Game<P>>::State,
+        <G as Game<P>>::Move,
+        <G as Game<P>>::Utility,
+        <G as Game<P>>::Outcome,
+        P,
+    >,
+>;
+
+/// A node of the tree of a game that repeats stage game `G` and has state `S`.
+pub(crate) type LiftedTree<S, G, const P: usize> =
+    GameTree<S, <G as Game<P>>::Move, <G as Game<P>>::Utility, History<G, P>, P>;
+
+/// The state of a game that repeats a stage game, such as [`Repeated`] or
+/// [`AlternatingRepeated`](crate::AlternatingRepeated), whose tree is built by lifting each node
+/// of the stage game's tree with [`lift_node`].
+pub(crate) trait RepeatingState<G: Game<P> + 'static, const P: usize>: State {
+    /// The current intermediate state of the stage game.
+    fn stage_state(&self) -> &Arc<G::State>;
+
+    /// Advance the stage game to the given intermediate state.
+    fn set_stage_state(&mut self, stage_state: Arc<G::State>);
+
+    /// Record a move of chance played in the current repetition.
+    fn add_chance_move(&mut self, _the_move: G::Move) {}
+
+    /// The history of all completed repetitions of the stage game so far.
+    fn history(&self) -> &History<G, P>;
+
+    /// The number of remaining repetitions, not counting the current one.
+    fn remaining(&self) -> usize;
+
+    /// Record the outcome of the current repetition and begin the next one.
+    fn begin_next_repetition(&mut self, outcome: G::Outcome);
+
+    /// Lift a node of the stage game's tree where the given players move.
+    fn lift_turns(
+        stage_root: StageTree<G, P>,
+        state: Arc<Self>,
+        to_move: Vec<PlayerIndex<P>>,
+        next: StageNext<G, P>,
+    ) -> LiftedTree<Self, G, P>;
+}
+
+/// Lift a node of the stage game's tree into the tree of a game that repeats it.
+///
+/// The root of the stage game's tree is built once, when the repeating game's tree is built, and
+/// reused to begin each repetition.
+pub(crate) fn lift_node<S: RepeatingState<G, P>, G: Game<P> + 'static, const P: usize>(
+    stage_root: StageTree<G, P>,
+    state: Arc<S>,
+    node: StageTree<G, P>,
+) -> LiftedTree<S, G, P> {
+    match node.action {
+        Action::Turns { to_move, next } => S::lift_turns(stage_root, state, to_move, next),
 
         Action::Chance { distribution, next } => GameTree::chance(
             state.clone(),
             distribution,
-            move |repeated_state: Arc<RepeatedState<G, P>>, the_move: G::Move| match next(
-                repeated_state.stage_state.clone(),
-                the_move,
-            ) {
-                Ok(stage_node) => {
-                    let mut next_state = (*state).clone();
-                    next_state.stage_state = stage_node.state.clone();
-
-                    Ok(lift_node(
-                        stage_game.clone(),
-                        Arc::new(next_state),
-                        stage_node,
-                    ))
-                }
+            move |lifted_state: Arc<S>, the_move: G::Move| {
+                let stage_node = next(lifted_state.stage_state().clone(), the_move)?;
+                let mut next_state = (*state).clone();
+                next_state.set_stage_state(stage_node.state.clone());
+                next_state.add_chance_move(the_move);
 
-                Err(kind) => Err(kind),
+                Ok(lift_node(
+                    stage_root.clone(),
+                    Arc::new(next_state),
+                    stage_node,
+                ))
             },
         ),
 
-        Action::End { outcome, .. } if state.remaining > 0 => {
-            let stage_node = stage_game.game_tree();
-
+        Action::End { outcome, .. } if state.remaining() > 0 => {
             let mut next_state = (*state).clone();
-            next_state.stage_state = stage_node.state.clone();
+            next_state.set_stage_state(stage_root.state.clone());
+            next_state.begin_next_repetition(outcome);
 
-            next_state.completed.add(outcome);
-            next_state.remaining -= 1;
-
-            lift_node(stage_game, Arc::new(next_state), stage_node)
+            lift_node(stage_root.clone(), Arc::new(next_state), stage_root)
         }
 
         Action::End { outcome, .. } => {
-            let mut history = state.completed.clone(); // TODO avoid this clone
+            let mut history = state.history().clone(); // TODO avoid this clone
             history.add(outcome);
 
             GameTree::end(state, history)
@@ -146,6 +451,71 @@ fn lift_node<G: Game<P> + 'static, const P: usize>(
     }
 }
 
+/// Lift a node of the stage game's tree where the given players move simultaneously.
+pub(crate) fn lift_simultaneous_turns<
+    S: RepeatingState<G, P>,
+    G: Game<P> + 'static,
+    const P: usize,
+>(
+    stage_root: StageTree<G, P>,
+    state: Arc<S>,
+    to_move: Vec<PlayerIndex<P>>,
+    next: StageNext<G, P>,
+) -> LiftedTree<S, G, P> {
+    GameTree::players(
+        state.clone(),
+        to_move,
+        move |lifted_state: Arc<S>, moves: Vec<G::Move>| {
+            let stage_node = next(lifted_state.stage_state().clone(), moves)?;
+            let mut next_state = (*state).clone();
+            next_state.set_stage_state(stage_node.state.clone());
+
+            Ok(lift_node(
+                stage_root.clone(),
+                Arc::new(next_state),
+                stage_node,
+            ))
+        },
+    )
+}
+
+impl<G: Game<P> + 'static, const P: usize> RepeatingState<G, P> for RepeatedState<G, P> {
+    fn stage_state(&self) -> &Arc<G::State> {
+        &self.stage_state
+    }
+
+    fn set_stage_state(&mut self, stage_state: Arc<G::State>) {
+        self.stage_state = stage_state;
+    }
+
+    fn history(&self) -> &History<G, P> {
+        &self.completed
+    }
+
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    fn begin_next_repetition(&mut self, outcome: G::Outcome) {
+        if let Some((observe, observed)) = &mut self.observed {
+            for player in PlayerIndex::all() {
+                observed[player].add(observe(&outcome, player));
+            }
+        }
+        self.completed.add(outcome);
+        self.remaining -= 1;
+    }
+
+    fn lift_turns(
+        stage_root: StageTree<G, P>,
+        state: Arc<Self>,
+        to_move: Vec<PlayerIndex<P>>,
+        next: StageNext<G, P>,
+    ) -> LiftedTree<Self, G, P> {
+        lift_simultaneous_turns(stage_root, state, to_move, next)
+    }
+}
+
 impl<G: Game<P> + 'static, const P: usize> Game<P> for Repeated<G, P> {
     type Move = G::Move;
     type Utility = G::Utility;
@@ -156,24 +526,34 @@ impl<G: Game<P> + 'static, const P: usize> Game<P> for Repeated<G, P> {
     fn into_game_tree(
         self,
     ) -> GameTree<RepeatedState<G, P>, G::Move, G::Utility, History<G, P>, P> {
-        let init_state = Arc::new(RepeatedState::new(
+        let stage_root = self.stage_game.game_tree();
+        let mut init_state = RepeatedState::with_stage_state(
             self.stage_game.clone(),
+            stage_root.state.clone(),
             self.repetitions - 1,
-        ));
+        );
+        if let Some(window) = self.window {
+            init_state.completed = History::with_window(window);
+        }
+        if let Some(observe) = self.observe {
+            let observed = PerPlayer::generate(|_| init_state.completed.clone());
+            init_state.observed = Some((observe, observed));
+        }
+        let init_state = Arc::new(init_state);
 
-        lift_node(
-            self.stage_game.clone(),
-            init_state,
-            self.stage_game.game_tree(),
-        )
+        lift_node(stage_root.clone(), init_state, stage_root)
     }
 
     fn state_view(
         &self,
         state: &RepeatedState<G, P>,
-        _player: PlayerIndex<P>,
+        player: PlayerIndex<P>,
     ) -> RepeatedState<G, P> {
-        state.clone() // TODO
+        let mut view = state.clone(); // TODO
+        if let Some((_, observed)) = &view.observed {
+            view.completed = observed[player].clone();
+        }
+        view
     }
 }
 
@@ -209,12 +589,138 @@ impl<G: Game<P>, const P: usize> PartialEq for RepeatedState<G, P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Normal;
+    use crate::{Context, Matchup, Normal, Outcome, Payoff, Player, Record, Strategy};
     use impls::impls;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use test_log::test;
 
+    #[test]
+    fn individually_rational_payoffs_in_repeated_dilemma() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 10);
+
+        let copycat = || Player::new("Copycat".to_string(), || Strategy::lagged_mirror(1, 'C'));
+        let history = rpd
+            .play(&Matchup::from_players([copycat(), copycat()]))
+            .unwrap();
+        let average = Payoff::from_fn(|player| history.score()[player] / 10);
+        assert_eq!(average, Payoff::from([2, 2]));
+        assert!(rpd.is_individually_rational(average));
+
+        // Being exploited, or only receiving the punishment value, is not individually rational.
+        assert!(!rpd.is_individually_rational(Payoff::from([0, 3])));
+        assert!(!rpd.is_individually_rational(Payoff::from([1, 1])));
+    }
+
     #[test]
     fn repeated_is_send_sync() {
         assert!(impls!(Repeated<Normal<(), u8, 2>, 2>: Send & Sync));
     }
+
+    #[test]
+    fn observed_histories_record_payoffs_of_observed_moves() {
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let noisy: Rpd = Repeated::with_observation_noise(Arc::new(pd.clone()), 20, 0.5).unwrap();
+
+        // Records whether each observed outcome's payoff matches its observed profile.
+        let consistent = Arc::new(AtomicUsize::new(0));
+        let inconsistent = Arc::new(AtomicUsize::new(0));
+        let checker = {
+            let (consistent, inconsistent) = (consistent.clone(), inconsistent.clone());
+            Player::new("Checker".to_string(), move || {
+                let (consistent, inconsistent, pd) =
+                    (consistent.clone(), inconsistent.clone(), pd.clone());
+                Strategy::new(
+                    move |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+                        if let Some(outcome) = context.state_view().history().outcomes().last() {
+                            let counter = if *outcome.payoff() == pd.payoff(*outcome.profile()) {
+                                &consistent
+                            } else {
+                                &inconsistent
+                            };
+                            counter.fetch_add(1, Ordering::Relaxed);
+                        }
+                        'C'
+                    },
+                )
+            })
+        };
+        let alternator = Player::new("Alternator".to_string(), || {
+            Strategy::periodic_pure(vec!['C', 'D'])
+        });
+
+        let history = crate::with_seed(3, || {
+            noisy
+                .play(&Matchup::from_players([checker, alternator]))
+                .unwrap()
+        });
+
+        assert_eq!(consistent.load(Ordering::Relaxed), 19);
+        assert_eq!(inconsistent.load(Ordering::Relaxed), 0);
+        // The actual score is unaffected by misperceptions.
+        assert_eq!(*history.score(), Payoff::from([20, 50]));
+    }
+
+    #[test]
+    fn bounded_memory_stays_bounded() {
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        let pd = Arc::new(Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap());
+        let longest = Arc::new(AtomicUsize::new(0));
+
+        let observer = {
+            let longest = longest.clone();
+            Player::new("Observer".to_string(), move || {
+                let longest = longest.clone();
+                Strategy::new(
+                    move |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+                        let len = context.state_view().history().outcomes().len();
+                        longest.fetch_max(len, Ordering::Relaxed);
+                        'C'
+                    },
+                )
+            })
+        };
+        let alternator = Player::new("Alternator".to_string(), || {
+            Strategy::periodic_pure(vec!['C', 'D'])
+        });
+
+        let bounded: Rpd = Repeated::new_bounded_memory(pd, 1000, 5);
+        let history = bounded
+            .play(&Matchup::from_players([observer, alternator]))
+            .unwrap();
+
+        assert_eq!(longest.load(Ordering::Relaxed), 5);
+        assert_eq!(history.outcomes().len(), 5);
+        assert_eq!(history.num_games(), 1000);
+        assert_eq!(history.window(), Some(5));
+        assert_eq!(*history.score(), Payoff::from([1000, 2500]));
+    }
+
+    #[test]
+    fn bounded_memory_windowed_strategies_match_unbounded() {
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        let pd = Arc::new(Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap());
+        let matchup = Matchup::from_players([
+            Player::new("Lagged".to_string(), || Strategy::lagged_mirror(3, 'C')),
+            Player::new("Periodic".to_string(), || {
+                Strategy::periodic_pure(vec!['C', 'D', 'D', 'C', 'C'])
+            }),
+        ]);
+
+        let bounded: Rpd = Repeated::new_bounded_memory(pd.clone(), 200, 3);
+        let unbounded: Rpd = Repeated::new(pd, 200);
+
+        let bounded_history = bounded.play(&matchup).unwrap();
+        let unbounded_history = unbounded.play(&matchup).unwrap();
+
+        assert_eq!(bounded_history.score(), unbounded_history.score());
+        assert_eq!(bounded_history.summary(), unbounded_history.summary());
+        assert!(bounded_history
+            .profiles()
+            .eq(unbounded_history.profiles().skip(197)));
+    }
 }
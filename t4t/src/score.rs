@@ -1,8 +1,55 @@
-use crate::Utility;
+use crate::{Payoff, PlayerIndex, Utility};
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+/// Determines how much a player's score increases after each game in a tournament.
+///
+/// # Examples
+/// ```
+/// use t4t::*;
+///
+/// let payoff = Payoff::from([3, 0]);
+///
+/// assert_eq!(ScoreMode::OwnUtility.utility(&payoff, for2::P0), 3);
+/// assert_eq!(ScoreMode::SocialWelfare.utility(&payoff, for2::P0), 3);
+/// assert_eq!(ScoreMode::Egalitarian.utility(&payoff, for2::P0), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ScoreMode {
+    /// Each player scores their own utility. This is the default.
+    #[default]
+    OwnUtility,
+
+    /// Each player scores the sum of all players' utilities.
+    SocialWelfare,
+
+    /// Each player scores the minimum of all players' utilities.
+    Egalitarian,
+}
+
+impl ScoreMode {
+    /// Get the utility that the given player scores for a game with the given payoff.
+    pub fn utility<U: Utility, const P: usize>(
+        &self,
+        payoff: &Payoff<U, P>,
+        player: PlayerIndex<P>,
+    ) -> U {
+        match self {
+            ScoreMode::OwnUtility => payoff[player],
+            ScoreMode::SocialWelfare => payoff.iter().fold(U::zero(), |sum, u| sum + *u),
+            ScoreMode::Egalitarian => payoff
+                .iter()
+                .copied()
+                .reduce(|min, u| if u < min { u } else { min })
+                .unwrap_or(U::zero()),
+        }
+    }
+}
+
+/// The name of a team of players, for aggregating their scores. See [`Score::by_team`].
+pub type TeamId = String;
+
 /// The cumulative utility for each player across all matchups in a tournament.
 ///
 /// ```
@@ -62,6 +109,43 @@ impl<U: Utility> Score<U> {
         }
     }
 
+    /// Aggregate the scores of players into the scores of their teams, given the team of each
+    /// player.
+    ///
+    /// Each team's score is the sum of its members' scores. Players that are not assigned to a
+    /// team are omitted.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use t4t::Score;
+    ///
+    /// let mut score = Score::new();
+    /// score.add("Leela", 7);
+    /// score.add("Fry", -2);
+    /// score.add("Bender", 8);
+    /// score.add("Zoidberg", 1);
+    ///
+    /// let teams = HashMap::from([
+    ///     ("Leela".to_string(), "Crew".to_string()),
+    ///     ("Fry".to_string(), "Crew".to_string()),
+    ///     ("Bender".to_string(), "Robots".to_string()),
+    /// ]);
+    ///
+    /// let team_score = score.by_team(&teams);
+    /// assert_eq!(team_score.get("Crew"), Some(5));
+    /// assert_eq!(team_score.get("Robots"), Some(8));
+    /// assert_eq!(team_score.get("Zoidberg"), None);
+    /// ```
+    pub fn by_team(&self, teams: &HashMap<String, TeamId>) -> Score<U> {
+        let mut team_score = Score::new();
+        for (name, score) in &self.0 {
+            if let Some(team) = teams.get(name) {
+                team_score.add(team, *score);
+            }
+        }
+        team_score
+    }
+
     /// Get the current score for the given player.
     pub fn get(&self, name: &str) -> Option<U> {
         self.0.get(name).copied()
@@ -1,7 +1,8 @@
+use num::ToPrimitive;
 use std::sync::Arc;
 
 use crate::{
-    ErrorKind, Game, GameTree, Move, Payoff, PerPlayer, PlayerIndex, Profile, Record,
+    ErrorKind, Game, GameTree, Move, Normal, Payoff, PerPlayer, PlayerIndex, Profile, Record,
     SimultaneousOutcome, Utility,
 };
 
@@ -85,9 +86,7 @@ impl<M: Move, U: Utility, const P: usize> Simultaneous<M, U, P> {
         UtilFn: Fn(M) -> U + Send + Sync + 'static,
     {
         let payoff_fn = move |profile: Profile<M, P>| {
-            Payoff::new(PerPlayer::generate(|player| {
-                util_fns[player](profile[player])
-            }))
+            Payoff::from_fn(|player| util_fns[player](profile[player]))
         };
         Self::from_payoff_fn(move_fn, payoff_fn)
     }
@@ -116,6 +115,122 @@ impl<M: Move, U: Utility, const P: usize> Simultaneous<M, U, P> {
     pub fn payoff(&self, profile: Profile<M, P>) -> Payoff<U, P> {
         (*self.payoff_fn)(profile)
     }
+
+    /// Get this simultaneous move game as a normal-form game, restricted to the given finite set
+    /// of moves for each player.
+    ///
+    /// This is the inverse of [`Normal::as_simultaneous`] and enables applying the analyses
+    /// defined on normal-form games, such as finding Nash equilibria or dominated moves, to a
+    /// simultaneous move game once the moves under consideration have been fixed.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if any of the given moves is not a valid move for the
+    /// corresponding player.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let moves = PerPlayer::init_with(vec!['C', 'D']);
+    ///
+    /// let round_trip = pd.as_simultaneous().try_into_normal(moves.clone()).unwrap();
+    /// for profile in pd.possible_profiles() {
+    ///     assert_eq!(round_trip.payoff(profile), pd.payoff(profile));
+    /// }
+    /// assert_eq!(round_trip.pure_nash_equilibria(), vec![Profile::new(['D', 'D'])]);
+    ///
+    /// let invalid = PerPlayer::init_with(vec!['C', 'X']);
+    /// assert!(pd.as_simultaneous().try_into_normal(invalid).is_none());
+    /// ```
+    pub fn try_into_normal(&self, moves: PerPlayer<Vec<M>, P>) -> Option<Normal<M, U, P>> {
+        for player in PlayerIndex::all() {
+            for the_move in &moves[player] {
+                if !self.is_valid_move_for_player(player, *the_move) {
+                    log::error!(
+                        "Simultaneous::try_into_normal: invalid move for player {}: {:?}",
+                        player,
+                        the_move,
+                    );
+                    return None;
+                }
+            }
+        }
+        let payoff_fn = self.payoff_fn.clone();
+        Some(Normal::from_payoff_fn(moves, move |profile| {
+            payoff_fn(profile)
+        }))
+    }
+
+    /// Estimate the expected payoff when each player samples their move independently from a
+    /// mixed strategy, using [Monte Carlo](https://en.wikipedia.org/wiki/Monte_Carlo_method)
+    /// sampling.
+    ///
+    /// Each player's mixed strategy is represented by a sampling function that draws a move using
+    /// the given source of randomness. The estimate is the average payoff of `n` sampled profiles.
+    /// This supports analyzing games with non-finite move domains, where the expected payoff
+    /// cannot be computed exactly. Passing a seeded `rng` makes the estimate reproducible.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `n` is zero or if any sampled profile is
+    /// [invalid](Simultaneous::is_valid_profile).
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::{Rng, SeedableRng};
+    /// use t4t::*;
+    ///
+    /// // Each player's utility is their own number minus the other player's number.
+    /// let game = Simultaneous::from_payoff_fn(
+    ///     |_, x: i32| (0..=10).contains(&x),
+    ///     |profile: Profile<i32, 2>| {
+    ///         let (a, b) = (profile[for2::P0], profile[for2::P1]);
+    ///         Payoff::from([a - b, b - a])
+    ///     },
+    /// );
+    ///
+    /// let samplers = PerPlayer::new([
+    ///     |rng: &mut StdRng| rng.gen_range(0..=10),
+    ///     |rng: &mut StdRng| rng.gen_range(0..=4),
+    /// ]);
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let estimate = game.monte_carlo_payoff(samplers, 10_000, &mut rng).unwrap();
+    ///
+    /// // The expected payoff is [5 - 2, 2 - 5].
+    /// assert!((estimate[for2::P0] - 3.0).abs() < 0.2);
+    /// assert!((estimate[for2::P1] + 3.0).abs() < 0.2);
+    /// ```
+    pub fn monte_carlo_payoff<R: rand::Rng>(
+        &self,
+        mut samplers: PerPlayer<impl FnMut(&mut R) -> M, P>,
+        n: usize,
+        rng: &mut R,
+    ) -> Option<Payoff<f64, P>>
+    where
+        U: ToPrimitive,
+    {
+        if n == 0 {
+            log::error!("Simultaneous::monte_carlo_payoff: the number of samples must be positive");
+            return None;
+        }
+        let mut total = Payoff::zeros();
+        for _ in 0..n {
+            let profile =
+                Profile::from_per_player(PerPlayer::generate(|player| samplers[player](rng)));
+            if !self.is_valid_profile(profile) {
+                log::error!(
+                    "Simultaneous::monte_carlo_payoff: sampled an invalid profile: {:?}",
+                    profile
+                );
+                return None;
+            }
+            total = total + self.payoff(profile).map(|u| u.to_f64().unwrap_or(f64::NAN));
+        }
+        Some(total.map(|u| u / n as f64))
+    }
 }
 
 impl<M: Move, U: Utility, const P: usize> Game<P> for Simultaneous<M, U, P> {
@@ -154,4 +269,49 @@ mod tests {
     fn simultaneous_is_send_sync() {
         assert!(impls!(Simultaneous<(), u8, 2>: Send & Sync));
     }
+
+    #[test]
+    fn monte_carlo_payoff_of_continuous_game() {
+        use crate::for2;
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        // Moves are points in the unit interval, represented in millionths since floating point
+        // numbers are not valid moves. Player 0 gets the product of the two points, player 1 gets
+        // the square of their own point.
+        const SCALE: u32 = 1_000_000;
+        let game = Simultaneous::from_payoff_fn(
+            |_, x: u32| x <= SCALE,
+            |profile: Profile<u32, 2>| {
+                let a = profile[for2::P0] as f64 / SCALE as f64;
+                let b = profile[for2::P1] as f64 / SCALE as f64;
+                Payoff::from([a * b, b * b])
+            },
+        );
+
+        let uniform = |rng: &mut StdRng| rng.gen_range(0..=SCALE);
+        let mut rng = StdRng::seed_from_u64(42);
+        let estimate = game
+            .monte_carlo_payoff(PerPlayer::new([uniform, uniform]), 100_000, &mut rng)
+            .unwrap();
+
+        // For independent uniform points, E[ab] = 1/4 and E[b^2] = 1/3.
+        assert!((estimate[for2::P0] - 0.25).abs() < 0.01);
+        assert!((estimate[for2::P1] - 1.0 / 3.0).abs() < 0.01);
+
+        // Seeding the source of randomness makes the estimate reproducible.
+        let mut rng = StdRng::seed_from_u64(42);
+        let again = game
+            .monte_carlo_payoff(PerPlayer::new([uniform, uniform]), 100_000, &mut rng)
+            .unwrap();
+        assert_eq!(estimate, again);
+
+        assert!(game
+            .monte_carlo_payoff(PerPlayer::new([uniform, uniform]), 0, &mut rng)
+            .is_none());
+        let out_of_range = |_: &mut StdRng| 2 * SCALE;
+        assert!(game
+            .monte_carlo_payoff(PerPlayer::new([uniform, out_of_range]), 10, &mut rng)
+            .is_none());
+    }
 }
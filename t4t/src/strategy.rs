@@ -1,10 +1,18 @@
-use crate::{Distribution, Move, PlayerIndex, State};
+use crate::random::with_rng;
+use crate::{
+    CommonView, Distribution, Game, Move, Normal, PlayerIndex, RepeatedState, SimultaneousOutcome,
+    State, StrategyRng, Utility,
+};
+use num::ToPrimitive;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// The strategic context in which a player makes a move during a game.
 ///
-/// This type includes all information, besides the definition of the stage game, that a strategy
-/// may use to compute its next move. It includes the player's index and the player's view of the
-/// game state.
+/// This type includes all information that a strategy may use to compute its next move. It includes
+/// the player's index and the player's view of the game state. For repeated games, the view of the
+/// state also provides access to the definition of the stage game via [`Context::game`].
 #[derive(Clone, Debug, PartialEq)]
 pub struct Context<V, const P: usize> {
     index: PlayerIndex<P>,
@@ -28,6 +36,37 @@ impl<V: State, const P: usize> Context<V, P> {
     pub fn my_index(&self) -> PlayerIndex<P> {
         self.index
     }
+
+    /// Get a random number generator that draws from the library's source of randomness.
+    ///
+    /// Strategies that make random choices should draw from this generator, rather than from
+    /// [`rand::thread_rng`], so that their play is reproducible when the game is played
+    /// [with a seed](crate::with_seed), for example, in a [seeded tournament](crate::Tournament::with_seed).
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::Rng;
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 20);
+    ///
+    /// let coin = || {
+    ///     Player::new("Coin".to_string(), || {
+    ///         Strategy::new(|context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+    ///             if context.rng().gen_bool(0.5) { 'C' } else { 'D' }
+    ///         })
+    ///     })
+    /// };
+    /// let matchup = Matchup::from_players([coin(), coin()]);
+    ///
+    /// let play = || with_seed(5, || rpd.play(&matchup).unwrap());
+    /// assert_eq!(play(), play());
+    /// ```
+    pub fn rng(&self) -> StrategyRng {
+        StrategyRng
+    }
 }
 
 impl<V: State> Context<V, 2> {
@@ -38,18 +77,261 @@ impl<V: State> Context<V, 2> {
     }
 }
 
+impl<V: State, C: State, const P: usize> Context<CommonView<V, C>, P> {
+    /// Get the payload shared by all players of a [`CommonKnowledge`](crate::CommonKnowledge)
+    /// game, such as a public signal.
+    pub fn common(&self) -> &C {
+        self.state_view.common()
+    }
+}
+
+impl<G: Game<P> + 'static, const P: usize> Context<RepeatedState<G, P>, P> {
+    /// Get the stage game of the repeated game being played.
+    ///
+    /// This enables introspective strategies that reason about the stage game's payoffs, such as
+    /// computing a best response to the opponent's previous moves. The returned reference borrows
+    /// from the context, so it is only valid while computing the current move. Use
+    /// [`RepeatedState::stage_game`] and clone the `Arc` to retain the game across moves.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+    ///
+    /// // Play the move that maximizes utility against the opponent's previous move.
+    /// let best_responder = Player::<Rpd, 2>::new("Best Responder".to_string(), || {
+    ///     Strategy::new(|context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+    ///         let game = context.game();
+    ///         let theirs = context.their_last_move().unwrap_or('C');
+    ///         game.possible_moves_for_player(context.my_index())
+    ///             .max_by_key(|&mine| {
+    ///                 let profile = PerPlayer::generate(|p| {
+    ///                     if p == context.my_index() { mine } else { theirs }
+    ///                 });
+    ///                 game.payoff(Profile::from_per_player(profile))[context.my_index()]
+    ///             })
+    ///             .unwrap()
+    ///     })
+    /// });
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd: Rpd = Repeated::new(Arc::new(pd), 3);
+    /// let cooperator = Player::new("Cooperator".to_string(), || Strategy::pure('C'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([best_responder, cooperator])).unwrap();
+    /// assert_eq!(history.moves_for_player(for2::P0).collect::<Vec<_>>(), vec!['D', 'D', 'D']);
+    /// ```
+    pub fn game(&self) -> &G {
+        self.state_view.stage_game()
+    }
+
+    /// Get the total number of rounds in the repeated game being played, or `None` if the game is
+    /// repeated indefinitely. See [`RepeatedState::total_rounds`].
+    pub fn total_rounds(&self) -> Option<usize> {
+        self.state_view.total_rounds()
+    }
+
+    /// Get the number of rounds left to play in the repeated game, including the current round,
+    /// or `None` if the game is repeated indefinitely. In the final round, this returns `Some(1)`.
+    pub fn rounds_remaining(&self) -> Option<usize> {
+        self.total_rounds()
+            .map(|total| total - self.state_view.history().num_games())
+    }
+}
+
+impl<G, M, U, const P: usize> Context<RepeatedState<G, P>, P>
+where
+    G: Game<P, Move = M, Utility = U, Outcome = SimultaneousOutcome<M, U, P>> + 'static,
+    M: Move,
+    U: Utility,
+{
+    /// Get the move played by the player whose turn it is to move in the previous round of a
+    /// repeated game. Returns `None` if no rounds have been played.
+    pub fn my_last_move(&self) -> Option<M> {
+        self.state_view
+            .history()
+            .moves_for_player(self.my_index())
+            .last()
+    }
+}
+
+impl<G, M, U> Context<RepeatedState<G, 2>, 2>
+where
+    G: Game<2, Move = M, Utility = U, Outcome = SimultaneousOutcome<M, U, 2>> + 'static,
+    M: Move,
+    U: Utility,
+{
+    /// Get the move played by the other player in the previous round of a repeated two-player
+    /// game. Returns `None` if no rounds have been played.
+    pub fn their_last_move(&self) -> Option<M> {
+        self.state_view
+            .history()
+            .moves_for_player(self.their_index())
+            .last()
+    }
+}
+
 /// A strategy is a function from an intermediate game context to a move.
+///
+/// A strategy may carry mutable state across the moves of a single game, for example, to learn
+/// from an opponent's behavior in a repeated game. A fresh strategy is produced by the
+/// [player](crate::Player) for every game played, so state captured by the strategy's function
+/// never carries over from one game to the next. State stored elsewhere, such as in a structure
+/// shared between strategies, can be cleared by attaching a [reset hook](Strategy::with_reset),
+/// which is called at the start of each game.
+///
+/// A strategy may be [named](Strategy::with_name) to identify which strategy chose each move of a
+/// game, which is especially useful for strategies built from other strategies.
 pub struct Strategy<V, M, const P: usize> {
     #[allow(clippy::type_complexity)]
-    next_move: Box<dyn FnMut(&Context<V, P>) -> M + Send + Sync>,
+    next_move: Box<dyn FnMut(&Context<V, P>) -> (M, Option<String>) + Send + Sync>,
+    on_reset: Option<Box<dyn FnMut() + Send + Sync>>,
+    name: Option<String>,
+    acting_name: Option<String>,
 }
 
 impl<V: State + 'static, M: Move, const P: usize> Strategy<V, M, P> {
     /// Construct a new strategy from a function that computes the next move given a strategic
     /// context.
-    pub fn new(next_move: impl FnMut(&Context<V, P>) -> M + Send + Sync + 'static) -> Self {
+    pub fn new(mut next_move: impl FnMut(&Context<V, P>) -> M + Send + Sync + 'static) -> Self {
+        Strategy::new_attributed(move |context| (next_move(context), None))
+    }
+
+    /// Construct a new strategy from a function that computes the next move given a strategic
+    /// context, along with the name of the strategy that chose it, if known.
+    ///
+    /// This is useful for strategies built from other strategies, which can report the
+    /// [acting name](Strategy::acting_name) of the component strategy whose move they play. If the
+    /// function returns `None` for the name, the name of the constructed strategy is used instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let mut nice = Strategy::pure('C').with_name("Nice");
+    /// let mut mean = Strategy::pure('D');
+    /// let mut mostly_nice: Strategy<usize, char, 2> = Strategy::new_attributed(move |context| {
+    ///     let member = if *context.state_view() % 3 == 0 { &mut mean } else { &mut nice };
+    ///     let the_move = member.next_move(context);
+    ///     (the_move, member.acting_name().map(String::from))
+    /// })
+    /// .with_name("Mostly Nice");
+    ///
+    /// assert_eq!(mostly_nice.next_move(&Context::new(for2::P0, 1)), 'C');
+    /// assert_eq!(mostly_nice.acting_name(), Some("Nice"));
+    /// assert_eq!(mostly_nice.next_move(&Context::new(for2::P0, 3)), 'D');
+    /// assert_eq!(mostly_nice.acting_name(), Some("Mostly Nice"));
+    /// ```
+    pub fn new_attributed(
+        next_move: impl FnMut(&Context<V, P>) -> (M, Option<String>) + Send + Sync + 'static,
+    ) -> Self {
         Strategy {
             next_move: Box::new(next_move),
+            on_reset: None,
+            name: None,
+            acting_name: None,
+        }
+    }
+
+    /// Give this strategy a name, replacing any previous name.
+    ///
+    /// The name identifies the strategy as the [provenance](Strategy::acting_name) of the moves it
+    /// chooses, for example, in the transcript produced by
+    /// [`Game::play_with_transcript`](crate::Game::play_with_transcript).
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Get the name of this strategy, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the name of the strategy that chose the most recent move of this strategy, or `None`
+    /// if no move has been played yet or no named strategy was involved.
+    ///
+    /// For a strategy built from other strategies, such as a
+    /// [probabilistic](Strategy::probabilistic) ensemble, this is the name of the innermost named
+    /// component strategy that chose the move. If the component that chose the move is unnamed,
+    /// the name of the enclosing strategy is used instead.
+    ///
+    /// The component's name is passed back along with its move, so it is reported correctly
+    /// even if the component runs on another thread, as with
+    /// [`Player::with_time_limit`](crate::Player::with_time_limit). Only the strategies
+    /// constructed by this library's combinators and by [`new_attributed`](Strategy::new_attributed)
+    /// report the names of their components. A strategy constructed by [`new`](Strategy::new) from
+    /// other strategies reports only its own name.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let mut ensemble: Strategy<(), char, 2> = Strategy::periodic(vec![
+    ///     Strategy::pure('C').with_name("Nice"),
+    ///     Strategy::pure('D'),
+    /// ])
+    /// .with_name("Ensemble");
+    ///
+    /// let context = Context::new(for2::P0, ());
+    /// assert_eq!(ensemble.acting_name(), None);
+    ///
+    /// assert_eq!(ensemble.next_move(&context), 'C');
+    /// assert_eq!(ensemble.acting_name(), Some("Nice"));
+    ///
+    /// assert_eq!(ensemble.next_move(&context), 'D');
+    /// assert_eq!(ensemble.acting_name(), Some("Ensemble"));
+    /// ```
+    pub fn acting_name(&self) -> Option<&str> {
+        self.acting_name.as_deref()
+    }
+
+    /// Attach a hook to this strategy that is called by [`reset`](Strategy::reset), replacing any
+    /// previously attached hook.
+    ///
+    /// When a game is [played](crate::Game::play), each player's strategy is reset before the game
+    /// begins. This is useful for strategies whose state lives outside of the strategy itself and
+    /// so would otherwise carry over between games, for example, between the matchups of a
+    /// [tournament](crate::Tournament).
+    ///
+    /// Note that strategies built from other strategies, such as by
+    /// [`conditional`](Strategy::conditional), do not reset their component strategies.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let moves_played = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let counter = moves_played.clone();
+    /// let resetter = moves_played.clone();
+    /// let mut strategy = Strategy::new(move |_: &Context<(), 2>| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// })
+    /// .with_reset(move || resetter.store(0, Ordering::Relaxed));
+    ///
+    /// let context = Context::new(for2::P0, ());
+    /// strategy.next_move(&context);
+    /// strategy.next_move(&context);
+    /// assert_eq!(moves_played.load(Ordering::Relaxed), 2);
+    ///
+    /// strategy.reset();
+    /// assert_eq!(moves_played.load(Ordering::Relaxed), 0);
+    /// ```
+    pub fn with_reset(mut self, on_reset: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_reset = Some(Box::new(on_reset));
+        self
+    }
+
+    /// Reset any state associated with this strategy by calling its
+    /// [reset hook](Strategy::with_reset), if it has one. Otherwise, does nothing.
+    pub fn reset(&mut self) {
+        if let Some(on_reset) = &mut self.on_reset {
+            on_reset();
         }
     }
 
@@ -83,21 +365,95 @@ impl<V: State + 'static, M: Move, const P: usize> Strategy<V, M, P> {
     ///
     /// A distribution of pure strategies is equivalent to a [mixed](Strategy::mixed) strategy.
     pub fn probabilistic(mut dist: Distribution<Strategy<V, M, P>>) -> Self {
-        Strategy::new(move |context| dist.sample_mut().next_move(context))
+        Strategy::new_attributed(move |context| dist.sample_mut().next_attributed_move(context))
+    }
+
+    /// Construct a strategy that randomly commits to one of several strategies for an entire
+    /// game.
+    ///
+    /// Unlike a [probabilistic](Strategy::probabilistic) strategy, which samples a new strategy
+    /// for every move, this strategy samples a strategy from the distribution once when it is
+    /// [reset](Strategy::reset) at the start of each game, then plays that strategy for the
+    /// remainder of the game, including every round of a repeated game. This models uncertainty
+    /// about the type of a player. The committed strategy is also reset at the start of the game.
+    ///
+    /// If the strategy is used without being reset, it commits to a strategy on its first move.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 10);
+    ///
+    /// let unknown = Player::new("Unknown".to_string(), || {
+    ///     let types = vec![(Strategy::pure('C'), 0.5), (Strategy::pure('D'), 0.5)];
+    ///     Strategy::committed(Distribution::new(types).unwrap())
+    /// });
+    /// let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([unknown, nice])).unwrap();
+    /// let moves: Vec<char> = history.moves_for_player(for2::P0).collect();
+    /// assert!(moves.iter().all(|m| *m == moves[0]));
+    /// ```
+    pub fn committed(dist: Distribution<Strategy<V, M, P>>) -> Self {
+        let shared = Arc::new(Mutex::new((dist, None)));
+        let resetter = shared.clone();
+        Strategy::new_attributed(move |context| {
+            let mut shared = shared.lock().unwrap();
+            let (dist, committed) = &mut *shared;
+            let index = *committed.get_or_insert_with(|| with_rng(|rng| dist.weighted_index(rng)));
+            dist.element_mut(index).next_attributed_move(context)
+        })
+        .with_reset(move || {
+            let mut shared = resetter.lock().unwrap();
+            let (dist, committed) = &mut *shared;
+            let index = with_rng(|rng| dist.weighted_index(rng));
+            dist.element_mut(index).reset();
+            *committed = Some(index);
+        })
     }
 
     /// Construct a periodic strategy that plays the given sequence of strategies in order, then
     /// repeats.
+    ///
+    /// The position in the sequence starts over when the strategy is [reset](Strategy::reset),
+    /// so each game starts from the beginning of the sequence.
     pub fn periodic(mut strategies: Vec<Strategy<V, M, P>>) -> Self {
-        let mut next_index = 0;
-        Strategy::new(move |context| {
-            let the_move = strategies[next_index].next_move(context);
-            next_index = (next_index + 1) % strategies.len();
-            the_move
+        let next_index = Arc::new(AtomicUsize::new(0));
+        let resetter = next_index.clone();
+        Strategy::new_attributed(move |context| {
+            let index = next_index.fetch_add(1, Ordering::Relaxed) % strategies.len();
+            strategies[index].next_attributed_move(context)
         })
+        .with_reset(move || resetter.store(0, Ordering::Relaxed))
     }
+
     /// Construct a periodic strategy of pure strategies. That is, play the given moves in order
     /// and repeat indefinitely.
+    ///
+    /// This works for any type of move and any game, and is useful for building simple
+    /// strategies for repeated games.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let rps: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+    /// let repeated = Repeated::new(Arc::new(rps), 5);
+    ///
+    /// let cycler = Player::new("Cycler".to_string(), || Strategy::periodic_pure(vec!['R', 'S']));
+    /// let rock = Player::new("Rock".to_string(), || Strategy::pure('R'));
+    ///
+    /// let history = repeated.play(&Matchup::from_players([cycler, rock])).unwrap();
+    /// assert_eq!(
+    ///     history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+    ///     vec!['R', 'S', 'R', 'S', 'R'],
+    /// );
+    /// ```
     pub fn periodic_pure(moves: Vec<M>) -> Self {
         let strategies = Vec::from_iter(moves.into_iter().map(|m| Strategy::pure(m)));
         Strategy::periodic(strategies)
@@ -110,11 +466,11 @@ impl<V: State + 'static, M: Move, const P: usize> Strategy<V, M, P> {
         mut on_true: Strategy<V, M, P>,
         mut on_false: Strategy<V, M, P>,
     ) -> Self {
-        Strategy::new(move |context| {
+        Strategy::new_attributed(move |context| {
             if (condition)(context) {
-                on_true.next_move(context)
+                on_true.next_attributed_move(context)
             } else {
-                on_false.next_move(context)
+                on_false.next_attributed_move(context)
             }
         })
     }
@@ -127,32 +483,470 @@ impl<V: State + 'static, M: Move, const P: usize> Strategy<V, M, P> {
         mut after: Strategy<V, M, P>,
     ) -> Self {
         let mut triggered = false;
-        Strategy::new(move |context| {
+        Strategy::new_attributed(move |context| {
             if !triggered {
                 triggered = (trigger)(context);
             }
             if triggered {
-                after.next_move(context)
+                after.next_attributed_move(context)
             } else {
-                before.next_move(context)
+                before.next_attributed_move(context)
             }
         })
     }
 
     /// Get the next move to play given the current play context.
     pub fn next_move(&mut self, context: &Context<V, P>) -> M {
-        (self.next_move)(context)
+        let (the_move, acting_name) = (self.next_move)(context);
+        self.acting_name = acting_name.or_else(|| self.name.clone());
+        the_move
+    }
+
+    /// Get the next move to play given the current play context, along with the
+    /// [acting name](Strategy::acting_name) of the strategy that chose it.
+    pub(crate) fn next_attributed_move(&mut self, context: &Context<V, P>) -> (M, Option<String>) {
+        let the_move = self.next_move(context);
+        (the_move, self.acting_name.clone())
+    }
+}
+
+impl<G, M, U> Strategy<RepeatedState<G, 2>, M, 2>
+where
+    G: Game<2, Move = M, Utility = U, Outcome = SimultaneousOutcome<M, U, 2>> + 'static,
+    M: Move,
+    U: Utility,
+{
+    /// Construct a strategy for a repeated two-player game that plays the opponent's move from
+    /// `k` rounds ago, or the `default` move if fewer than `k` rounds have been played.
+    ///
+    /// This models reacting to the opponent with a delay. With `k = 1` it is equivalent to
+    /// [Tit-for-Tat](https://en.wikipedia.org/wiki/Tit_for_tat), assuming a cooperative default
+    /// move. If `k` is zero, the strategy always plays the default move.
+    pub fn lagged_mirror(k: usize, default: M) -> Self {
+        Strategy::new(move |context: &Context<RepeatedState<G, 2>, 2>| {
+            k.checked_sub(1)
+                .and_then(|i| {
+                    context
+                        .state_view()
+                        .history()
+                        .moves_for_player(context.their_index())
+                        .rev()
+                        .nth(i)
+                })
+                .unwrap_or(default)
+        })
+    }
+
+    /// Construct a tabular strategy for a repeated two-player game that looks up its next move
+    /// using the opponent's last `window` moves.
+    ///
+    /// The key into the table is the opponent's last `window` moves, ordered from oldest to most
+    /// recent. The `default` move is played if fewer than `window` rounds have been played, or if
+    /// the opponent's recent moves are not in the table.
+    ///
+    /// This makes it easy to define strategies by enumerating their responses to every short
+    /// history pattern, for example, when searching over a space of strategies.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd: Rpd = Repeated::new(Arc::new(pd), 6);
+    ///
+    /// // Defect only after the opponent defects twice in a row.
+    /// let table = HashMap::from([
+    ///     (vec!['C', 'C'], 'C'),
+    ///     (vec!['C', 'D'], 'C'),
+    ///     (vec!['D', 'C'], 'C'),
+    ///     (vec!['D', 'D'], 'D'),
+    /// ]);
+    /// let forgiving = Player::new("Tit-for-Two-Tats".to_string(), move || {
+    ///     Strategy::lookup(2, table.clone(), 'C')
+    /// });
+    /// let mean = Player::new("Mean".to_string(), || Strategy::pure('D'));
+    ///
+    /// let history = rpd.play(&Matchup::from_players([forgiving, mean])).unwrap();
+    /// assert_eq!(
+    ///     history.moves_for_player(for2::P0).collect::<Vec<_>>(),
+    ///     vec!['C', 'C', 'D', 'D', 'D', 'D'],
+    /// );
+    /// ```
+    pub fn lookup(window: usize, table: HashMap<Vec<M>, M>, default: M) -> Self {
+        Strategy::new(move |context: &Context<RepeatedState<G, 2>, 2>| {
+            let moves = context
+                .state_view()
+                .history()
+                .moves_for_player(context.their_index());
+            let Some(skip) = moves.len().checked_sub(window) else {
+                return default;
+            };
+            let key: Vec<M> = moves.skip(skip).collect();
+            table.get(&key).copied().unwrap_or(default)
+        })
+    }
+}
+
+impl<M, U, const P: usize> Strategy<RepeatedState<Normal<M, U, P>, P>, M, P>
+where
+    M: Move,
+    U: Utility + ToPrimitive,
+{
+    /// Construct a [regret-matching](https://doi.org/10.1111/1468-0262.00153) strategy for a
+    /// repeated normal-form game.
+    ///
+    /// The strategy maintains, for each of its available moves, the cumulative
+    /// [regret](Normal::regret) for not having played that move in each previous round, given the
+    /// moves actually played by the other players. Each round, it plays each move with probability
+    /// proportional to that move's positive cumulative regret, or uniformly at random if no move
+    /// has positive regret. The stage game is obtained from the context via [`Context::game`].
+    ///
+    /// When all players use regret matching, the empirical distribution of play converges to the
+    /// set of [coarse correlated equilibria](Normal::is_epsilon_coarse_correlated_equilibrium) of
+    /// the stage game.
+    ///
+    /// The cumulative regrets are cleared when the strategy is [reset](Strategy::reset), or when
+    /// it sees a shorter history than on its previous move, indicating that a new game has begun.
+    pub fn regret_matching() -> Self {
+        let shared: Arc<Mutex<(Vec<f64>, usize)>> = Arc::new(Mutex::new((Vec::new(), 0)));
+        let resetter = shared.clone();
+        Strategy::new(
+            move |context: &Context<RepeatedState<Normal<M, U, P>, P>, P>| {
+                let mut shared = shared.lock().unwrap();
+                let (regrets, rounds_seen) = &mut *shared;
+                let game = context.game();
+                let me = context.my_index();
+                let moves: Vec<M> = game.possible_moves_for_player(me).collect();
+
+                let history = context.state_view().history();
+                if history.num_games() < *rounds_seen {
+                    regrets.clear();
+                    *rounds_seen = 0;
+                }
+                regrets.resize(moves.len(), 0.0);
+
+                // Only the rounds played since the last move need to be incorporated.
+                let retained = history.profiles().len();
+                let new_rounds = (history.num_games() - *rounds_seen).min(retained);
+                for profile in history.profiles().skip(retained - new_rounds) {
+                    let actual = game.payoff(*profile)[me].to_f64().unwrap_or(0.0);
+                    for (regret, &alternative) in regrets.iter_mut().zip(&moves) {
+                        let mut adjusted = *profile;
+                        adjusted.per_player_mut()[me] = alternative;
+                        *regret += game.payoff(adjusted)[me].to_f64().unwrap_or(0.0) - actual;
+                    }
+                }
+                *rounds_seen = history.num_games();
+
+                let weighted: Vec<(M, f64)> = moves
+                    .iter()
+                    .zip(regrets.iter())
+                    .map(|(&m, &regret)| (m, regret.max(0.0)))
+                    .collect();
+                let dist = if weighted.iter().any(|(_, weight)| *weight > 0.0) {
+                    Distribution::new(weighted)
+                } else {
+                    Distribution::flat(moves)
+                };
+                *dist
+                    .expect("regret matching requires at least one available move")
+                    .sample()
+            },
+        )
+        .with_reset(move || {
+            let mut shared = resetter.lock().unwrap();
+            shared.0.clear();
+            shared.1 = 0;
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{for2, Matchup, Normal, PerPlayer, Player, Profile, Repeated, Tournament};
     use impls::impls;
+    use itertools::Itertools;
     use test_log::test;
 
+    #[test]
+    fn periodic_pure_cycles_with_correct_period() {
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        let repeated = Repeated::new(Arc::new(rps), 10);
+
+        let cycler = Player::new("Cycler".to_string(), || {
+            Strategy::periodic_pure(vec!['R', 'P', 'S'])
+        });
+        let rock = Player::new("Rock".to_string(), || Strategy::pure('R'));
+        let history = repeated
+            .play(&Matchup::from_players([cycler, rock]))
+            .unwrap();
+
+        let moves: Vec<char> = history.moves_for_player(for2::P0).collect();
+        assert_eq!(moves.len(), 10);
+        for (i, m) in moves.iter().enumerate() {
+            assert_eq!(*m, ['R', 'P', 'S'][i % 3]);
+        }
+
+        // Resetting the strategy starts the sequence over.
+        let mut strategy = Strategy::periodic_pure(vec!['R', 'P', 'S']);
+        let context = Context::new(for2::P0, ());
+        assert_eq!(strategy.next_move(&context), 'R');
+        assert_eq!(strategy.next_move(&context), 'P');
+        strategy.reset();
+        assert_eq!(strategy.next_move(&context), 'R');
+    }
+
+    #[test]
+    fn committed_strategy_is_consistent_within_and_matches_prior_across_games() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 5);
+        let unknown = Player::new("Unknown".to_string(), || {
+            let types = vec![
+                (Strategy::pure('C'), 0.25),
+                (Strategy::periodic_pure(vec!['D', 'C']), 0.75),
+            ];
+            Strategy::committed(Distribution::new(types).unwrap())
+        });
+        let nice = Player::new("Nice".to_string(), || Strategy::pure('C'));
+        let matchup = Matchup::from_players([unknown, nice]);
+
+        let games = 2000;
+        let mut cooperators = 0;
+        for _ in 0..games {
+            let history = rpd.play(&matchup).unwrap();
+            let moves: Vec<char> = history.moves_for_player(for2::P0).collect();
+            if moves == ['C'; 5] {
+                cooperators += 1;
+            } else {
+                assert_eq!(moves, ['D', 'C', 'D', 'C', 'D']);
+            }
+        }
+        let share = cooperators as f64 / games as f64;
+        assert!(
+            (share - 0.25).abs() < 0.05,
+            "share of cooperators: {}",
+            share
+        );
+    }
+
     #[test]
     fn strategy_is_send_sync() {
         assert!(impls!(Strategy<(), u8, 2>: Send & Sync));
     }
+
+    #[test]
+    fn best_response_using_game_from_context() {
+        type Stage = Normal<char, i32, 2>;
+
+        // In this coordination game, the best response is always to match the opponent's move.
+        let game: Stage =
+            Normal::bimatrix(['A', 'B'], ['A', 'B'], [[2, 0], [0, 1]], [[2, 0], [0, 1]]);
+        let repeated = Repeated::new(Arc::new(game), 6);
+
+        let best_responder = Player::new("Best Responder".to_string(), || {
+            Strategy::new(|context: &Context<RepeatedState<Stage, 2>, 2>| {
+                let game = context.game();
+                match context.their_last_move() {
+                    None => 'A',
+                    Some(theirs) => game
+                        .possible_moves_for_player(for2::P1)
+                        .max_by_key(|&mine| game.payoff(Profile::new([theirs, mine]))[for2::P1])
+                        .unwrap(),
+                }
+            })
+        });
+        let alternator = Player::new("Alternator".to_string(), || {
+            Strategy::periodic_pure(vec!['B', 'A'])
+        });
+
+        let history = repeated
+            .play(&Matchup::from_players([alternator, best_responder]))
+            .unwrap();
+        let responses: Vec<char> = history.moves_for_player(for2::P1).collect();
+        assert_eq!(responses, vec!['A', 'B', 'A', 'B', 'A', 'B']);
+    }
+
+    #[test]
+    fn regret_matching_self_play_in_rps_approaches_uniform() {
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        let rounds = 3000;
+        let repeated = Repeated::new(Arc::new(rps), rounds);
+
+        let regret_matcher =
+            || Player::new("Regret Matching".to_string(), Strategy::regret_matching);
+        let history = repeated
+            .play(&Matchup::from_players([regret_matcher(), regret_matcher()]))
+            .unwrap();
+
+        for player in PlayerIndex::all() {
+            for m in ['R', 'P', 'S'] {
+                let count = history
+                    .moves_for_player(player)
+                    .filter(|&played| played == m)
+                    .count();
+                let frequency = count as f64 / rounds as f64;
+                assert!(
+                    (frequency - 1.0 / 3.0).abs() < 0.1,
+                    "player {} played {} with frequency {}",
+                    player,
+                    m,
+                    frequency,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn regret_matching_self_play_approaches_coarse_correlated_equilibrium() {
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        let rounds = 3000;
+        let repeated = Repeated::new(Arc::new(rps.clone()), rounds);
+
+        let regret_matcher =
+            || Player::new("Regret Matching".to_string(), Strategy::regret_matching);
+        let history = repeated
+            .play(&Matchup::from_players([regret_matcher(), regret_matcher()]))
+            .unwrap();
+
+        let mut empirical = HashMap::new();
+        for profile in history.profiles() {
+            *empirical.entry(*profile).or_insert(0.0) += 1.0;
+        }
+        assert!(rps.is_epsilon_coarse_correlated_equilibrium(&empirical, 0.05));
+
+        let always_rock = HashMap::from([(Profile::new(['R', 'R']), 1.0)]);
+        assert!(!rps.is_epsilon_coarse_correlated_equilibrium(&always_rock, 0.05));
+    }
+
+    #[test]
+    fn regret_matching_can_be_reused_across_games() {
+        let rps: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['R', 'P', 'S'], vec![0, -1, 1, 1, 0, -1, -1, 1, 0]).unwrap();
+        let longer = Repeated::new(Arc::new(rps.clone()), 20);
+        let shorter = Repeated::new(Arc::new(rps), 5);
+        let rock = Player::new("Rock".to_string(), || Strategy::pure('R'));
+
+        // Share one regret-matching strategy across games, with or without forwarding resets.
+        for forward_reset in [true, false] {
+            let shared = Arc::new(Mutex::new(Strategy::regret_matching()));
+            let reused = Player::new("Reused".to_string(), move || {
+                let mover = shared.clone();
+                let strategy =
+                    Strategy::new(move |context| mover.lock().unwrap().next_move(context));
+                let resetter = shared.clone();
+                if forward_reset {
+                    strategy.with_reset(move || resetter.lock().unwrap().reset())
+                } else {
+                    strategy
+                }
+            });
+            let matchup = Matchup::from_players([reused, rock.clone()]);
+
+            // After many rounds against rock, regret matching always plays paper.
+            let history = longer.play(&matchup).unwrap();
+            assert_eq!(history.moves_for_player(for2::P0).last(), Some('P'));
+            let history = shorter.play(&matchup).unwrap();
+            assert_eq!(history.profiles().len(), 5);
+        }
+    }
+
+    #[test]
+    fn custom_coin_flip_is_reproducible_in_seeded_tournaments() {
+        use rand::Rng;
+
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd), 50);
+        let coin = |name: &str, bias: f64| {
+            Arc::new(Player::new(name.to_string(), move || {
+                Strategy::new(
+                    move |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+                        if context.rng().gen_bool(bias) {
+                            'C'
+                        } else {
+                            'D'
+                        }
+                    },
+                )
+            }))
+        };
+        let players = vec![coin("Fair", 0.5), coin("Biased", 0.8)];
+        let tournament = Tournament::permutations_with_replacement(Arc::new(rpd), &players);
+
+        let histories = |seed| {
+            let result = tournament.clone().with_seed(seed).play();
+            players
+                .iter()
+                .cartesian_product(&players)
+                .map(|(a, b)| {
+                    let names = PerPlayer::new([a.name().to_string(), b.name().to_string()]);
+                    result.results()[&names].clone().unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+        let first = histories(11);
+        assert_eq!(first, histories(11));
+        assert_ne!(first, histories(12));
+
+        // The coin flips are actually random.
+        let moves: Vec<char> = first[0].moves_for_player(for2::P0).collect();
+        assert!(moves.contains(&'C') && moves.contains(&'D'));
+    }
+
+    #[test]
+    fn lookup_table_reproduces_tit_for_tat() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 12);
+
+        let table = HashMap::from([(vec!['C'], 'C'), (vec!['D'], 'D')]);
+        let tabular = Player::new("Tabular".to_string(), move || {
+            Strategy::lookup(1, table.clone(), 'C')
+        });
+        let tit_for_tat = Player::new("Tit-for-Tat".to_string(), || {
+            Strategy::lagged_mirror(1, 'C')
+        });
+        let opponent = || {
+            Player::new("Opponent".to_string(), || {
+                Strategy::periodic_pure(vec!['C', 'D', 'D', 'C', 'C'])
+            })
+        };
+
+        let tabular_history = rpd
+            .play(&Matchup::from_players([tabular, opponent()]))
+            .unwrap();
+        let tit_for_tat_history = rpd
+            .play(&Matchup::from_players([tit_for_tat, opponent()]))
+            .unwrap();
+        assert_eq!(tabular_history, tit_for_tat_history);
+    }
+
+    #[test]
+    fn lagged_mirror_lags_alternating_opponent() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 10);
+
+        let lagged = Player::new("Lagged".to_string(), || Strategy::lagged_mirror(2, 'C'));
+        let alternator = Player::new("Alternator".to_string(), || {
+            Strategy::periodic_pure(vec!['C', 'D'])
+        });
+
+        let history = rpd
+            .play(&Matchup::from_players([lagged, alternator]))
+            .unwrap();
+        let mine: Vec<char> = history.moves_for_player(for2::P0).collect();
+        let theirs: Vec<char> = history.moves_for_player(for2::P1).collect();
+
+        assert_eq!(&mine[..2], &['C', 'C']);
+        for i in 2..10 {
+            assert_eq!(mine[i], theirs[i - 2]);
+        }
+    }
 }
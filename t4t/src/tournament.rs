@@ -1,8 +1,14 @@
-use crate::{Game, Matchup, Outcome, PerPlayer, PlayResult, Player, Score};
+use crate::{
+    for2, with_seed, CompiledGame, Game, History, Matchup, Outcome, PerPlayer, PlayResult, Player,
+    PlayerIndex, Score, ScoreMode, TeamId,
+};
 use itertools::Itertools;
 use log::error;
+use num::ToPrimitive;
 use rayon::prelude::*;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
 
 /// A tournament in which several players play a game in a series of matchups, executed in parallel.
@@ -10,20 +16,160 @@ use std::sync::Arc;
 pub struct Tournament<G: Game<P>, const P: usize> {
     game: Arc<G>,
     matchups: Vec<Matchup<G, P>>,
+    score_mode: ScoreMode,
+    repetitions: usize,
+    seed: Option<u64>,
+    teams: HashMap<String, TeamId>,
 }
 
+/// The result of playing a single matchup in a tournament, paired with the names of the players in
+/// the matchup.
+pub type MatchResult<G, const P: usize> = (PerPlayer<String, P>, PlayResult<G, P>);
+
 /// The collected results from running a tournament.
 #[derive(Clone, Debug, PartialEq)]
 pub struct TournamentResult<G: Game<P>, const P: usize> {
     results: HashMap<PerPlayer<String, P>, PlayResult<G, P>>,
     score: Score<G::Utility>,
+    repetition_scores: Vec<Score<G::Utility>>,
+    team_score: Score<G::Utility>,
     has_errors: bool,
 }
 
+/// A schedule of players entering the population at chosen generations of an evolutionary
+/// simulation run by [`Tournament::evolve_with_immigration`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImmigrationSchedule {
+    arrivals: Vec<(usize, String, f64)>,
+}
+
+impl ImmigrationSchedule {
+    /// Construct an empty immigration schedule.
+    pub fn new() -> Self {
+        ImmigrationSchedule::default()
+    }
+
+    /// Builder method that schedules the player with the given name to enter the population at
+    /// the given generation with the given share of the population.
+    pub fn immigrate(mut self, generation: usize, name: impl Into<String>, share: f64) -> Self {
+        self.arrivals.push((generation, name.into(), share));
+        self
+    }
+
+    /// Get an iterator over the scheduled arrivals in the order they were added, as tuples of the
+    /// generation, the name of the immigrating player, and its share.
+    pub fn arrivals(&self) -> impl Iterator<Item = (usize, &str, f64)> {
+        self.arrivals
+            .iter()
+            .map(|(generation, name, share)| (*generation, name.as_str(), *share))
+    }
+}
+
 impl<G: Game<P>, const P: usize> Tournament<G, P> {
     /// Construct a new tournament for the given game with the given list of matchups.
     pub fn new(game: Arc<G>, matchups: Vec<Matchup<G, P>>) -> Self {
-        Tournament { game, matchups }
+        Tournament {
+            game,
+            matchups,
+            score_mode: ScoreMode::default(),
+            repetitions: 1,
+            seed: None,
+            teams: HashMap::new(),
+        }
+    }
+
+    /// Set how players are scored after each matchup. By default, each player scores their own
+    /// utility.
+    pub fn with_score_mode(mut self, score_mode: ScoreMode) -> Self {
+        self.score_mode = score_mode;
+        self
+    }
+
+    /// Set how many times each matchup is played. By default, each matchup is played once.
+    ///
+    /// Repeating the matchups is useful for tournaments with stochastic players, where a single
+    /// play of each matchup may not be representative. The score of each repetition is retained
+    /// so the uncertainty of the players' scores can be estimated with
+    /// [`TournamentResult::score_with_ci`]. A value of zero is treated as one.
+    pub fn with_repetitions(mut self, repetitions: usize) -> Self {
+        self.repetitions = repetitions.max(1);
+        self
+    }
+
+    /// Seed the randomness used while playing the tournament, making tournaments with stochastic
+    /// players or moves of chance reproducible. By default, the tournament is unseeded.
+    ///
+    /// Each matchup is played with its own source of randomness (see [`with_seed`]), seeded
+    /// deterministically from the given seed, the names of the players in the order of their
+    /// roles, and the repetition. Since the seed of a matchup does not depend on which thread
+    /// plays it or on the other matchups in the tournament, the result of each matchup is the
+    /// same across runs, regardless of how the matchups are scheduled in parallel.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let game: Normal<u8, u8, 2> =
+    ///     Normal::symmetric(vec![0, 1, 2, 3], (0..16).collect()).unwrap();
+    /// let players = ["A", "B", "C"]
+    ///     .into_iter()
+    ///     .map(|name| {
+    ///         Arc::new(Player::new(name.to_string(), || {
+    ///             Strategy::mixed_flat(vec![0, 1, 2, 3]).unwrap()
+    ///         }))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let tournament =
+    ///     Tournament::permutations_with_replacement(Arc::new(game), &players).with_seed(7);
+    /// assert_eq!(tournament.seed(), Some(7));
+    /// assert_eq!(tournament.play().score(), tournament.play().score());
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Assign players to teams, given a map from each player's name to the name of their team.
+    /// By default, no players are on teams.
+    ///
+    /// In addition to the individual score of each player, the result of the tournament then
+    /// reports the aggregate [score of each team](TournamentResult::team_score), which is the sum
+    /// of its members' scores. This is useful for studying cooperation within teams in
+    /// multiplayer games. Players that are not assigned to a team are omitted from the team
+    /// scores.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+    ///     Arc::new(Player::new("Meaner".to_string(), || Strategy::pure('D'))),
+    /// ];
+    /// let teams = HashMap::from([
+    ///     ("Nice".to_string(), "Doves".to_string()),
+    ///     ("Mean".to_string(), "Hawks".to_string()),
+    ///     ("Meaner".to_string(), "Hawks".to_string()),
+    /// ]);
+    ///
+    /// let result = Tournament::permutations_without_replacement(Arc::new(pd), &players)
+    ///     .with_teams(teams)
+    ///     .play();
+    ///
+    /// assert_eq!(result.score().get("Mean"), Some(8));
+    /// assert_eq!(result.score().get("Meaner"), Some(8));
+    /// assert_eq!(result.team_score().get("Hawks"), Some(16));
+    /// assert_eq!(result.team_score().get("Doves"), Some(0));
+    /// ```
+    pub fn with_teams(mut self, assignment: HashMap<String, TeamId>) -> Self {
+        self.teams = assignment;
+        self
     }
 
     /// Construct a new tournament where the matchups are all
@@ -303,41 +449,113 @@ impl<G: Game<P>, const P: usize> Tournament<G, P> {
     }
 
     /// Run the matchups of the tournament in parallel and collect the results.
+    ///
+    /// Although the matchups are played in parallel, the results are aggregated in the order of
+    /// the tournament's [matchups](Self::matchups). This ensures that the resulting score is
+    /// reproducible across runs, even for floating point utilities, where the order of summation
+    /// affects the result.
+    ///
+    /// If the tournament has several [repetitions](Self::with_repetitions), all matchups are
+    /// played once per repetition. The score accumulates across all repetitions, while the
+    /// individual result of each matchup is the result from the last repetition.
     pub fn play(&self) -> TournamentResult<G, P> {
         let mut results = HashMap::new();
         let mut score = Score::new();
+        let mut repetition_scores = vec![Score::new(); self.repetitions];
         let mut has_errors = false;
+        let compiled = self.game.compile();
 
-        let (sender, receiver) = std::sync::mpsc::channel();
+        let ordered_results: Vec<(usize, MatchResult<G, P>)> = (0..self.repetitions)
+            .cartesian_product(&self.matchups)
+            .collect_vec()
+            .into_par_iter()
+            .map(|(repetition, matchup)| {
+                let names = matchup.names();
+                let result = self.play_matchup(&compiled, matchup, &names, repetition);
+                (repetition, (names, result))
+            })
+            .collect();
 
-        self.matchups
-            .par_iter()
-            .for_each_with(sender, |s, matchup| {
-                let result = self.game.play(matchup);
-                let send_result = s.send((matchup.names(), result));
-                if let Err(err) = send_result {
-                    error!("error sending result: {:?}", err);
+        ordered_results
+            .into_iter()
+            .for_each(|(repetition, (names, result))| {
+                if let Ok(outcome) = &result {
+                    names.for_each_with_index(|i, name| {
+                        let utility = self.score_mode.utility(outcome.payoff(), i);
+                        score.add(name, utility);
+                        repetition_scores[repetition].add(name, utility);
+                    });
+                } else {
+                    has_errors = true;
                 }
+                results.insert(names, result);
             });
 
-        receiver.iter().for_each(|(names, result)| {
-            if let Ok(outcome) = &result {
-                names.for_each_with_index(|i, name| {
-                    score.add(name, outcome.payoff()[i]);
-                });
-            } else {
-                has_errors = true;
-            }
-            results.insert(names, result);
-        });
-
         TournamentResult {
             results,
+            team_score: score.by_team(&self.teams),
             score,
+            repetition_scores,
             has_errors,
         }
     }
 
+    /// Run the matchups of the tournament in parallel, sending the result of each matchup to the
+    /// given channel as soon as it completes.
+    ///
+    /// Unlike [`play`](Self::play), this does not collect the results, so it can be used to
+    /// aggregate or persist the results of very large tournaments incrementally. Note that results
+    /// are sent in the order the matchups complete, which may vary from run to run. This method
+    /// returns once all matchups have been played, after which the sender is dropped.
+    ///
+    /// If the tournament has [repetitions](Self::with_repetitions), the result of each matchup is
+    /// sent once per repetition, and each repetition is seeded the same way as in
+    /// [`play`](Self::play).
+    ///
+    /// # Errors
+    ///
+    /// If the receiving end of the channel is disconnected, an error is logged for each result
+    /// that could not be sent.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let players = vec!["A", "B", "C"]
+    ///     .into_iter()
+    ///     .map(|name| Arc::new(Player::new(name.to_string(), || Strategy::pure(()))))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let game: Simultaneous<(), u8, 2> = Simultaneous::trivial();
+    ///
+    /// let tournament = Tournament::permutations_without_replacement(
+    ///     Arc::new(game),
+    ///     &players,
+    /// );
+    ///
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    /// std::thread::scope(|scope| {
+    ///     scope.spawn(|| tournament.play_into(sender));
+    ///     assert_eq!(receiver.iter().count(), 6);
+    /// });
+    /// ```
+    pub fn play_into(&self, sender: Sender<MatchResult<G, P>>) {
+        let compiled = self.game.compile();
+        (0..self.repetitions)
+            .cartesian_product(&self.matchups)
+            .collect_vec()
+            .into_par_iter()
+            .for_each_with(sender, |s, (repetition, matchup)| {
+                let names = matchup.names();
+                let result = self.play_matchup(&compiled, matchup, &names, repetition);
+                let send_result = s.send((names, result));
+                if let Err(err) = send_result {
+                    error!("error sending result: {:?}", err);
+                }
+            });
+    }
+
     /// Get a reference to the game being played in this tournament.
     pub fn game(&self) -> &Arc<G> {
         &self.game
@@ -347,6 +565,300 @@ impl<G: Game<P>, const P: usize> Tournament<G, P> {
     pub fn matchups(&self) -> &Vec<Matchup<G, P>> {
         &self.matchups
     }
+
+    /// Get how players are scored after each matchup.
+    pub fn score_mode(&self) -> ScoreMode {
+        self.score_mode
+    }
+
+    /// Get how many times each matchup is played.
+    pub fn repetitions(&self) -> usize {
+        self.repetitions
+    }
+
+    /// Get the seed of the tournament's randomness, if it is [seeded](Self::with_seed).
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Get the team of each player that is [assigned to a team](Self::with_teams).
+    pub fn teams(&self) -> &HashMap<String, TeamId> {
+        &self.teams
+    }
+
+    /// Play a single matchup of the compiled game with the given player names in the given
+    /// repetition, seeding its randomness if the tournament is seeded.
+    fn play_matchup(
+        &self,
+        compiled: &CompiledGame<G, P>,
+        matchup: &Matchup<G, P>,
+        names: &PerPlayer<String, P>,
+        repetition: usize,
+    ) -> PlayResult<G, P> {
+        match self.seed {
+            Some(seed) => with_seed(matchup_seed(seed, names, repetition), || {
+                compiled.play(matchup)
+            }),
+            None => compiled.play(matchup),
+        }
+    }
+}
+
+/// Derive the seed of a matchup from the tournament's seed, the names of the players in the order
+/// of their roles, and the repetition.
+///
+/// This uses a fixed hash function (FNV-1a, finalized with SplitMix64) rather than
+/// [`std::hash::DefaultHasher`], whose output may change between Rust releases.
+fn matchup_seed<const P: usize>(seed: u64, names: &PerPlayer<String, P>, repetition: usize) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+    write(&seed.to_le_bytes());
+    write(&(repetition as u64).to_le_bytes());
+    for name in names {
+        // Prefix each name with its length so that names can't run into each other.
+        write(&(name.len() as u64).to_le_bytes());
+        write(name.as_bytes());
+    }
+
+    let mut z = hash.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl<G: Game<2>> Tournament<G, 2>
+where
+    G::Utility: ToPrimitive,
+{
+    /// Simulate the evolution of a population of the tournament's players over several
+    /// generations using the discrete-time
+    /// [replicator dynamics](https://en.wikipedia.org/wiki/Replicator_equation).
+    ///
+    /// The population initially consists of each player in proportion to its share in
+    /// `initial_shares`, which is normalized to sum to one. Players in the tournament that are
+    /// missing from `initial_shares` start with a share of zero. In each generation, the fitness
+    /// of each player is its expected utility against an opponent drawn from the current
+    /// population, and each player's share grows or shrinks in proportion to its fitness relative
+    /// to the population's average fitness. To keep fitness values non-negative, utilities are
+    /// shifted so that the smallest head-to-head utility is zero.
+    ///
+    /// The tournament is played once, so the tournament should contain a matchup for every pair of
+    /// players, including each player against itself, such as the tournaments produced by
+    /// [`permutations_with_replacement`](Self::permutations_with_replacement) or
+    /// [`combinations_with_replacement`](Self::combinations_with_replacement). If both orders of
+    /// a pair are present, the utilities from each are averaged. Utilities are computed according
+    /// to the tournament's [score mode](Self::score_mode).
+    ///
+    /// Returns the trajectory of the population shares, starting with the normalized initial
+    /// shares and followed by the shares after each generation.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns an empty trajectory if:
+    /// - `initial_shares` names a player not in the tournament, contains a negative or non-finite
+    ///   share, or does not contain any positive share.
+    /// - Any matchup in the tournament results in an error.
+    /// - The tournament is missing a matchup between two players.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Cooperator".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D'))),
+    /// ];
+    /// let tournament = Tournament::permutations_with_replacement(Arc::new(pd), &players);
+    ///
+    /// let initial = HashMap::from([("Cooperator".to_string(), 0.9), ("Defector".to_string(), 0.1)]);
+    /// let trajectory = tournament.evolve(20, initial);
+    ///
+    /// assert_eq!(trajectory.len(), 21);
+    /// assert!(trajectory[20]["Defector"] > 0.9);
+    /// ```
+    pub fn evolve(
+        &self,
+        generations: usize,
+        initial_shares: HashMap<String, f64>,
+    ) -> Vec<HashMap<String, f64>> {
+        self.evolve_with_immigration(generations, initial_shares, &ImmigrationSchedule::new())
+    }
+
+    /// Simulate the evolution of a population of the tournament's players, like
+    /// [`evolve`](Self::evolve), while introducing players into the population at the
+    /// generations given by an [`ImmigrationSchedule`].
+    ///
+    /// When a player immigrates with a share `s`, the shares of all players in the population are
+    /// scaled by `1 - s`, and then `s` is added to the immigrant's share. Immigration at generation
+    /// `g` is applied before the shares at index `g` of the trajectory are recorded, so
+    /// immigration at generation `0` modifies the initial shares. Immigrants must be players in
+    /// the tournament, typically with an initial share of zero. This models mutation and the
+    /// invasion of a population by a new strategy.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns an empty trajectory in the same cases as
+    /// [`evolve`](Self::evolve), or if the schedule names a player not in the tournament or has a
+    /// share outside of the range `[0, 1]`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Cooperator".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D'))),
+    /// ];
+    /// let tournament = Tournament::permutations_with_replacement(Arc::new(pd), &players);
+    ///
+    /// let initial = HashMap::from([("Cooperator".to_string(), 1.0)]);
+    /// let schedule = ImmigrationSchedule::new().immigrate(5, "Defector", 0.01);
+    /// let trajectory = tournament.evolve_with_immigration(30, initial, &schedule);
+    ///
+    /// assert_eq!(trajectory[4]["Defector"], 0.0);
+    /// assert_eq!(trajectory[5]["Defector"], 0.01);
+    /// assert!(trajectory[30]["Defector"] > 0.9);
+    /// ```
+    pub fn evolve_with_immigration(
+        &self,
+        generations: usize,
+        initial_shares: HashMap<String, f64>,
+        schedule: &ImmigrationSchedule,
+    ) -> Vec<HashMap<String, f64>> {
+        let names: Vec<String> = self
+            .matchups
+            .iter()
+            .flat_map(|matchup| matchup.names())
+            .unique()
+            .sorted()
+            .collect();
+
+        if let Some(unknown) = initial_shares.keys().find(|name| !names.contains(name)) {
+            error!(
+                "Tournament::evolve: unknown player in initial shares: {}",
+                unknown
+            );
+            return Vec::new();
+        }
+        if let Some(share) = initial_shares
+            .values()
+            .find(|share| !share.is_finite() || **share < 0.0)
+        {
+            error!("Tournament::evolve: invalid initial share: {}", share);
+            return Vec::new();
+        }
+        let total: f64 = initial_shares.values().sum();
+        if total <= 0.0 {
+            error!("Tournament::evolve: initial shares must include a positive share");
+            return Vec::new();
+        }
+        for (_, name, share) in schedule.arrivals() {
+            if !names.iter().any(|known| known == name) {
+                error!("Tournament::evolve: unknown immigrant: {}", name);
+                return Vec::new();
+            }
+            if !(0.0..=1.0).contains(&share) {
+                error!("Tournament::evolve: invalid immigrant share: {}", share);
+                return Vec::new();
+            }
+        }
+
+        // The sum and count of utilities for the player at each index against each other player.
+        let n = names.len();
+        let mut sums = vec![vec![0.0; n]; n];
+        let mut counts = vec![vec![0; n]; n];
+        for (matchup_names, result) in self.play().results() {
+            let Ok(outcome) = result else {
+                error!("Tournament::evolve: matchup failed: {:?}", matchup_names);
+                return Vec::new();
+            };
+            let [a, b] = [for2::P0, for2::P1].map(|player| {
+                names
+                    .iter()
+                    .position(|name| *name == matchup_names[player])
+                    .unwrap()
+            });
+            for (player, me, them) in [(for2::P0, a, b), (for2::P1, b, a)] {
+                let utility = self.score_mode.utility(outcome.payoff(), player);
+                sums[me][them] += utility.to_f64().unwrap_or(f64::NAN);
+                counts[me][them] += 1;
+            }
+        }
+        if let Some((i, j)) = (0..n)
+            .cartesian_product(0..n)
+            .find(|&(i, j)| counts[i][j] == 0)
+        {
+            error!(
+                "Tournament::evolve: missing matchup between {} and {}",
+                names[i], names[j]
+            );
+            return Vec::new();
+        }
+
+        let averages: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| sums[i][j] / counts[i][j] as f64).collect())
+            .collect();
+        let baseline = averages
+            .iter()
+            .flatten()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+
+        let mut shares: Vec<f64> = names
+            .iter()
+            .map(|name| initial_shares.get(name).copied().unwrap_or(0.0) / total)
+            .collect();
+        let to_map = |shares: &[f64]| -> HashMap<String, f64> {
+            names.iter().cloned().zip(shares.iter().copied()).collect()
+        };
+        let immigrate = |generation: usize, shares: &mut Vec<f64>| {
+            for (_, name, share) in schedule
+                .arrivals()
+                .filter(|(arrival, _, _)| *arrival == generation)
+            {
+                for existing in shares.iter_mut() {
+                    *existing *= 1.0 - share;
+                }
+                let index = names.iter().position(|known| known == name).unwrap();
+                shares[index] += share;
+            }
+        };
+
+        immigrate(0, &mut shares);
+        let mut trajectory = vec![to_map(&shares)];
+        for generation in 1..=generations {
+            let fitness: Vec<f64> = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| shares[j] * (averages[i][j] - baseline))
+                        .sum()
+                })
+                .collect();
+            let average_fitness: f64 = (0..n).map(|i| shares[i] * fitness[i]).sum();
+            if average_fitness > 0.0 {
+                for i in 0..n {
+                    shares[i] *= fitness[i] / average_fitness;
+                }
+            }
+            immigrate(generation, &mut shares);
+            trajectory.push(to_map(&shares));
+        }
+        trajectory
+    }
 }
 
 impl<G: Game<P>, const P: usize> TournamentResult<G, P> {
@@ -363,8 +875,1056 @@ impl<G: Game<P>, const P: usize> TournamentResult<G, P> {
         &self.score
     }
 
+    /// The utility for each player across all matchups within each repetition of the tournament.
+    ///
+    /// The scores of all repetitions sum to the cumulative [score](Self::score).
+    pub fn repetition_scores(&self) -> &[Score<G::Utility>] {
+        &self.repetition_scores
+    }
+
+    /// The cumulative utility for each team across all matchups, that is, the sum of the
+    /// [scores](Self::score) of the team's members. See [`Tournament::with_teams`].
+    ///
+    /// This is empty if no players are assigned to teams.
+    pub fn team_score(&self) -> &Score<G::Utility> {
+        &self.team_score
+    }
+
     /// Did any of the matchups end in an error rather than a successful outcome?
     pub fn has_errors(&self) -> bool {
         self.has_errors
     }
+
+    /// Merge the results of another tournament into this one.
+    ///
+    /// The matchup results are combined, each player's score from `other` is
+    /// [added](Score::add_all) to their score in this result, and the merged result has errors if
+    /// either result does. This enables splitting a large tournament's matchups into several
+    /// smaller tournaments, for example, to play them on separate machines, then combining the
+    /// results. If both results contain a matchup with the same player names, the result from
+    /// `other` is kept, while the scores from both are counted, as when a tournament contains
+    /// duplicate matchups.
+    ///
+    /// The [team scores](Self::team_score) and the scores of corresponding
+    /// [repetitions](Self::repetition_scores) are also added, so merging tournaments with the same
+    /// number of repetitions preserves the per-repetition scores of the whole tournament.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let game = Arc::new(pd);
+    /// let players = vec![
+    ///     Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+    /// ];
+    ///
+    /// let whole = Tournament::permutations_with_replacement(game.clone(), &players);
+    /// let (first, second) = whole.matchups().split_at(2);
+    ///
+    /// let mut merged = Tournament::new(game.clone(), first.to_vec()).play();
+    /// merged.merge(Tournament::new(game, second.to_vec()).play());
+    ///
+    /// assert_eq!(merged.score(), whole.play().score());
+    /// assert_eq!(merged.results().len(), 4);
+    /// ```
+    pub fn merge(&mut self, other: TournamentResult<G, P>) {
+        self.results.extend(other.results);
+        self.score.add_all(&other.score);
+        self.team_score.add_all(&other.team_score);
+        for (i, other_score) in other.repetition_scores.into_iter().enumerate() {
+            match self.repetition_scores.get_mut(i) {
+                Some(score) => score.add_all(&other_score),
+                None => self.repetition_scores.push(other_score),
+            }
+        }
+        self.has_errors |= other.has_errors;
+    }
+
+    /// Compute [Elo ratings](https://en.wikipedia.org/wiki/Elo_rating_system) for the players
+    /// from the head-to-head results of each matchup.
+    ///
+    /// Every player starts with a rating of 1500. Within each successful matchup, each pair of
+    /// distinct players is treated as a game between those two players, where the player with the
+    /// higher utility wins, and equal utilities are a draw. After each such game, both players'
+    /// ratings are updated, where `k` determines the maximum change in rating from a single game.
+    ///
+    /// Since Elo ratings depend on the order that games are processed, matchups are processed in
+    /// lexicographic order of the names of the players in each matchup, so the result is
+    /// reproducible. Failed matchups are skipped.
+    ///
+    /// Unlike the cumulative [score](Self::score), the ratings reflect who wins each matchup rather
+    /// than by how much, and a win against a highly rated opponent counts for more than a win
+    /// against a poorly rated one.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Cooperator".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D'))),
+    /// ];
+    /// let result = Tournament::permutations_without_replacement(Arc::new(pd), &players).play();
+    ///
+    /// let ratings = result.elo_ratings(32.0);
+    /// assert!(ratings["Defector"] > 1500.0);
+    /// assert!(ratings["Cooperator"] < 1500.0);
+    /// assert_eq!(ratings["Defector"] + ratings["Cooperator"], 3000.0);
+    /// ```
+    pub fn elo_ratings(&self, k: f64) -> HashMap<String, f64> {
+        const INITIAL_RATING: f64 = 1500.0;
+
+        let mut ratings = HashMap::new();
+        let sorted_results = self
+            .results
+            .iter()
+            .sorted_by(|(a, _), (b, _)| a.iter().cmp(b.iter()));
+
+        for (names, result) in sorted_results {
+            for name in names.iter() {
+                ratings.entry(name.clone()).or_insert(INITIAL_RATING);
+            }
+            let Ok(outcome) = result else {
+                continue;
+            };
+            let payoff = outcome.payoff();
+            for (i, j) in PlayerIndex::<P>::all()
+                .collect_vec()
+                .into_iter()
+                .tuple_combinations()
+            {
+                if names[i] == names[j] {
+                    continue;
+                }
+                let actual = match payoff[i].partial_cmp(&payoff[j]) {
+                    Some(Ordering::Greater) => 1.0,
+                    Some(Ordering::Less) => 0.0,
+                    _ => 0.5,
+                };
+                let (rating_i, rating_j) = (ratings[&names[i]], ratings[&names[j]]);
+                let expected = 1.0 / (1.0 + 10f64.powf((rating_j - rating_i) / 400.0));
+                let change = k * (actual - expected);
+                *ratings.get_mut(&names[i]).unwrap() += change;
+                *ratings.get_mut(&names[j]).unwrap() -= change;
+            }
+        }
+        ratings
+    }
+
+    /// Get the [Condorcet winner](https://en.wikipedia.org/wiki/Condorcet_winner_criterion) of
+    /// the tournament, if there is one: the player who beats every other player head-to-head.
+    ///
+    /// Player A beats player B head-to-head if, among the successful matchups in which both
+    /// players appear, A wins more of them than B does, where the player with the higher utility
+    /// wins a matchup. Failed matchups are skipped, as are players who never meet.
+    ///
+    /// Unlike the cumulative [score](Self::score), this considers only who wins each matchup, so
+    /// the Condorcet winner need not have the highest score. For non-transitive tournaments, like
+    /// rock-paper-scissors, there may be no Condorcet winner at all.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+    ///     Arc::new(Player::new("Also Nice".to_string(), || Strategy::pure('C'))),
+    /// ];
+    /// let result = Tournament::permutations_without_replacement(Arc::new(pd), &players).play();
+    ///
+    /// assert_eq!(result.condorcet_winner(), Some("Mean".to_string()));
+    /// ```
+    pub fn condorcet_winner(&self) -> Option<String> {
+        let (names, wins) = self.head_to_head_wins();
+        (0..names.len())
+            .find(|&i| (0..names.len()).all(|j| i == j || wins[i][j] > wins[j][i]))
+            .map(|i| names[i].clone())
+    }
+
+    /// Rank the players by their head-to-head results, grouping players into tiers from best to
+    /// worst.
+    ///
+    /// Player A ranks above player B if A beats B head-to-head, as defined for the
+    /// [Condorcet winner](Self::condorcet_winner), or if A beats some player that ranks above or
+    /// alongside B. Players who cannot be ordered this way, because they tie, never meet, or beat
+    /// each other in a cycle, share a tier. Each tier's players are sorted by name.
+    ///
+    /// Every player in a tier beats every player in lower tiers, so the ranking is a total order
+    /// exactly when every tier contains a single player. If there is a Condorcet winner, they are
+    /// alone in the first tier.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+    ///     Arc::new(Player::new("Also Nice".to_string(), || Strategy::pure('C'))),
+    /// ];
+    /// let result = Tournament::permutations_without_replacement(Arc::new(pd), &players).play();
+    ///
+    /// assert_eq!(
+    ///     result.condorcet_ranking(),
+    ///     vec![
+    ///         vec!["Mean".to_string()],
+    ///         vec!["Also Nice".to_string(), "Nice".to_string()],
+    ///     ],
+    /// );
+    /// ```
+    pub fn condorcet_ranking(&self) -> Vec<Vec<String>> {
+        let (names, wins) = self.head_to_head_wins();
+        let n = names.len();
+
+        // Player i reaches player j if there is a chain of players from i to j, each of whom does
+        // not lose to the next. Since of any two players at least one does not lose to the other,
+        // players who reach each other form a tier, and higher tiers reach more players.
+        let mut reaches: Vec<Vec<bool>> = (0..n)
+            .map(|i| (0..n).map(|j| wins[j][i] <= wins[i][j]).collect())
+            .collect();
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    reaches[i][j] |= reaches[i][k] && reaches[k][j];
+                }
+            }
+        }
+
+        names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| (reaches[i].iter().filter(|&&r| r).count(), name))
+            .sorted_by(|(a, _), (b, _)| b.cmp(a))
+            .chunk_by(|(reached, _)| *reached)
+            .into_iter()
+            .map(|(_, tier)| tier.map(|(_, name)| name).collect())
+            .collect()
+    }
+
+    /// Count the head-to-head wins between each pair of distinct players, across all successful
+    /// matchups in which both players appear.
+    ///
+    /// Returns the names of all players, sorted, and a matrix whose entry at `[i][j]` is the
+    /// number of matchups in which the `i`th player beat the `j`th player.
+    fn head_to_head_wins(&self) -> (Vec<String>, Vec<Vec<usize>>) {
+        let names: Vec<String> = self
+            .results
+            .keys()
+            .flat_map(|names| names.iter().cloned())
+            .sorted()
+            .dedup()
+            .collect();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        let mut wins = vec![vec![0; names.len()]; names.len()];
+        for (matchup_names, result) in &self.results {
+            let Ok(outcome) = result else {
+                continue;
+            };
+            let payoff = outcome.payoff();
+            for i in PlayerIndex::<P>::all() {
+                for j in PlayerIndex::<P>::all() {
+                    if matchup_names[i] != matchup_names[j]
+                        && payoff[i].partial_cmp(&payoff[j]) == Some(Ordering::Greater)
+                    {
+                        wins[index[matchup_names[i].as_str()]][index[matchup_names[j].as_str()]] +=
+                            1;
+                    }
+                }
+            }
+        }
+        (names, wins)
+    }
+}
+
+impl<G: Game<P>, const P: usize> TournamentResult<G, P>
+where
+    G::Utility: ToPrimitive,
+{
+    /// Estimate each player's expected score per repetition of the tournament, with a
+    /// [confidence interval](https://en.wikipedia.org/wiki/Confidence_interval) at the given
+    /// confidence level, e.g. `0.95`.
+    ///
+    /// Returns a map from each player's name to a tuple `(mean, lower, upper)`, where `mean` is
+    /// the player's mean score across the tournament's [repetitions](Tournament::with_repetitions)
+    /// and `[lower, upper]` is the confidence interval for the mean, based on a normal
+    /// approximation of the per-repetition scores. A player that scores the same in every
+    /// repetition has an interval containing only their mean score.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if `confidence` is not strictly between 0 and 1, or if
+    /// the tournament has fewer than two repetitions.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let players = vec![
+    ///     Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Coin".to_string(), || Strategy::mixed_flat(vec!['C', 'D']).unwrap())),
+    /// ];
+    /// let result = Tournament::permutations_without_replacement(Arc::new(pd), &players)
+    ///     .with_repetitions(100)
+    ///     .play();
+    ///
+    /// let estimates = result.score_with_ci(0.95).unwrap();
+    /// let (mean, lower, upper) = estimates["Coin"];
+    /// assert!(lower < mean && mean < upper);
+    ///
+    /// // The cooperator's score depends on the coin flips of its opponent.
+    /// let (mean, lower, upper) = estimates["Nice"];
+    /// assert!(lower < mean && mean < upper);
+    ///
+    /// assert!(result.score_with_ci(1.5).is_none());
+    /// ```
+    pub fn score_with_ci(&self, confidence: f64) -> Option<HashMap<String, (f64, f64, f64)>> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            error!(
+                "TournamentResult::score_with_ci: confidence must be between 0 and 1, got {}",
+                confidence
+            );
+            return None;
+        }
+        let n = self.repetition_scores.len();
+        if n < 2 {
+            error!(
+                "TournamentResult::score_with_ci: at least two repetitions are required, got {}",
+                n
+            );
+            return None;
+        }
+
+        let z = standard_normal_quantile(0.5 + confidence / 2.0);
+        let names = self
+            .repetition_scores
+            .iter()
+            .flat_map(|score| score.best_to_worst().into_iter().map(|(name, _)| name))
+            .unique();
+        let estimates = names
+            .map(|name| {
+                let samples: Vec<f64> = self
+                    .repetition_scores
+                    .iter()
+                    .map(|score| score.get_or_zero(name).to_f64().unwrap_or(f64::NAN))
+                    .collect();
+                let mean = samples.iter().sum::<f64>() / n as f64;
+                let variance =
+                    samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+                let margin = z * (variance / n as f64).sqrt();
+                (name.to_string(), (mean, mean - margin, mean + margin))
+            })
+            .collect();
+        Some(estimates)
+    }
+}
+
+impl<S: Game<P>, G: Game<P, Outcome = History<S, P>>, const P: usize> TournamentResult<G, P>
+where
+    S::Utility: ToPrimitive,
+{
+    /// The cumulative [per-round average](History::average_payoff) utility for each player
+    /// across all matchups of a tournament of repeated games, where the utility of each matchup
+    /// is determined by the given score mode.
+    ///
+    /// Unlike the cumulative [score](Self::score), this normalizes for the length of each game,
+    /// which enables comparing players across repeated games of different lengths. Since it is
+    /// computed from the individual [results](Self::results), only the last repetition of the
+    /// tournament is counted, and failed matchups are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let rpd = Repeated::new(Arc::new(pd), 50);
+    /// let players = vec![
+    ///     Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+    ///     Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+    /// ];
+    /// let result = Tournament::permutations_without_replacement(Arc::new(rpd), &players).play();
+    ///
+    /// assert_eq!(result.score().get("Mean"), Some(300));
+    /// let average = result.per_round_average_score(ScoreMode::OwnUtility);
+    /// assert_eq!(average.get("Mean"), Some(6.0));
+    /// assert_eq!(average.get("Nice"), Some(0.0));
+    /// ```
+    pub fn per_round_average_score(&self, score_mode: ScoreMode) -> Score<f64> {
+        let mut score = Score::new();
+        for (names, result) in &self.results {
+            if let Ok(history) = result {
+                let average = history.average_payoff();
+                names.for_each_with_index(|i, name| {
+                    score.add(name, score_mode.utility(&average, i))
+                });
+            }
+        }
+        score
+    }
+}
+
+/// Compute the quantile function (inverse cumulative distribution function) of the standard
+/// normal distribution for a probability `p` strictly between 0 and 1, using
+/// [Acklam's algorithm](https://web.archive.org/web/20151030215612/http://home.online.no/~pjacklam/notes/invnorm/),
+/// which has a relative error below `1.15e-9`.
+fn standard_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{for2, Context, Normal, Payoff, Profile, Repeated, RepeatedState, Strategy};
+    use test_log::test;
+
+    #[test]
+    fn play_into_sends_every_matchup_result() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![3, 0, 5, 1]).unwrap();
+        let players = ['C', 'D', 'C']
+            .into_iter()
+            .enumerate()
+            .map(|(i, m)| {
+                Arc::new(Player::new(format!("{}{}", m, i), move || {
+                    Strategy::pure(m)
+                }))
+            })
+            .collect::<Vec<_>>();
+        let tournament = Tournament::combinations_with_replacement(Arc::new(pd), &players);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        tournament.play_into(sender);
+        let results = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(results.len(), tournament.matchups().len());
+
+        let mut score = Score::new();
+        for (names, result) in results {
+            let payoff = *result.unwrap().payoff();
+            names.for_each_with_index(|i, name| score.add(name, payoff[i]));
+        }
+        assert_eq!(&score, tournament.play().score());
+    }
+
+    #[test]
+    fn play_into_plays_every_seeded_repetition() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![3, 0, 5, 1]).unwrap();
+        let players = ["A", "B"]
+            .into_iter()
+            .map(|name| {
+                Arc::new(Player::new(name.to_string(), || {
+                    Strategy::mixed_flat(vec!['C', 'D']).unwrap()
+                }))
+            })
+            .collect::<Vec<_>>();
+        let tournament = Tournament::combinations_with_replacement(Arc::new(pd), &players)
+            .with_repetitions(5)
+            .with_seed(7);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        tournament.play_into(sender);
+        let results = receiver.iter().collect::<Vec<_>>();
+        assert_eq!(results.len(), 5 * tournament.matchups().len());
+
+        let mut score = Score::new();
+        for (names, result) in results {
+            let payoff = *result.unwrap().payoff();
+            names.for_each_with_index(|i, name| score.add(name, payoff[i]));
+        }
+        assert_eq!(&score, tournament.play().score());
+    }
+
+    #[test]
+    fn tit_for_tat_invades_defectors_above_threshold() {
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd), 10);
+        let players = vec![
+            Arc::new(Player::new("Tit-for-Tat".to_string(), || {
+                Strategy::lagged_mirror(1, 'C')
+            })),
+            Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D'))),
+        ];
+        let tournament = Tournament::permutations_with_replacement(Arc::new(rpd), &players);
+        let defectors = || HashMap::from([("Defector".to_string(), 1.0)]);
+
+        // Over 10 rounds, the invasion threshold for tit-for-tat is a share of 1/9.
+        let invasion = ImmigrationSchedule::new().immigrate(10, "Tit-for-Tat", 0.2);
+        let trajectory = tournament.evolve_with_immigration(60, defectors(), &invasion);
+        assert_eq!(trajectory.len(), 61);
+        assert_eq!(trajectory[9]["Tit-for-Tat"], 0.0);
+        assert!((trajectory[10]["Tit-for-Tat"] - 0.2).abs() < 1e-9);
+        assert!(trajectory[60]["Tit-for-Tat"] > 0.99);
+
+        let failed = ImmigrationSchedule::new().immigrate(10, "Tit-for-Tat", 0.05);
+        let trajectory = tournament.evolve_with_immigration(60, defectors(), &failed);
+        assert!(trajectory[60]["Tit-for-Tat"] < 0.01);
+
+        let unknown = ImmigrationSchedule::new().immigrate(10, "Pavlov", 0.5);
+        assert!(tournament
+            .evolve_with_immigration(60, defectors(), &unknown)
+            .is_empty());
+    }
+
+    #[test]
+    fn merging_split_tournaments_matches_whole_tournament() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Arc::new(Repeated::new(Arc::new(pd), 10));
+        let players = vec![
+            Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+            Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+            Arc::new(Player::new("Tit-for-Tat".to_string(), || {
+                Strategy::lagged_mirror(1, 'C')
+            })),
+            Arc::new(Player::new("Alternator".to_string(), || {
+                Strategy::periodic_pure(vec!['C', 'D'])
+            })),
+        ];
+
+        let whole = Tournament::combinations_with_replacement(rpd.clone(), &players);
+        let (first, second) = whole.matchups().split_at(whole.matchups().len() / 2);
+
+        let mut merged = Tournament::new(rpd.clone(), first.to_vec()).play();
+        merged.merge(Tournament::new(rpd.clone(), second.to_vec()).play());
+
+        let expected = whole.play();
+        assert_eq!(merged.score(), expected.score());
+        assert_eq!(merged.results(), expected.results());
+        assert!(!merged.has_errors());
+    }
+
+    #[test]
+    fn seeded_stochastic_tournament_is_reproducible_per_matchup() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Arc::new(Repeated::new(Arc::new(pd), 20));
+        let players = ["Coin", "Die", "Tit-for-Tat"]
+            .into_iter()
+            .map(|name| {
+                Arc::new(Player::new(name.to_string(), move || match name {
+                    "Tit-for-Tat" => Strategy::lagged_mirror(1, 'C'),
+                    "Die" => Strategy::mixed_flat(vec!['C', 'D', 'D']).unwrap(),
+                    _ => Strategy::mixed_flat(vec!['C', 'D']).unwrap(),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let tournament =
+            Tournament::permutations_with_replacement(rpd.clone(), &players).with_seed(42);
+        let expected = tournament.play();
+        for _ in 0..5 {
+            assert_eq!(tournament.play().results(), expected.results());
+        }
+
+        // Each matchup's result is the same when played on its own or streamed.
+        for matchup in tournament.matchups() {
+            let names = matchup.names();
+            let alone = Tournament::new(rpd.clone(), vec![matchup.clone()])
+                .with_seed(42)
+                .play();
+            assert_eq!(alone.results().get(&names), expected.results().get(&names));
+        }
+        let (sender, receiver) = std::sync::mpsc::channel();
+        tournament.play_into(sender);
+        assert_eq!(
+            receiver.iter().collect::<HashMap<_, _>>(),
+            expected.results().clone()
+        );
+
+        let reseeded = tournament.clone().with_seed(43).play();
+        assert_ne!(reseeded.results(), expected.results());
+    }
+
+    #[test]
+    fn score_with_ci_collapses_for_deterministic_players() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Arc::new(Repeated::new(Arc::new(pd), 10));
+        let players = vec![
+            Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+            Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+            Arc::new(Player::new("Tit-for-Tat".to_string(), || {
+                Strategy::lagged_mirror(1, 'C')
+            })),
+        ];
+
+        let once = Tournament::combinations_with_replacement(rpd.clone(), &players).play();
+        assert_eq!(once.repetition_scores().len(), 1);
+        assert!(once.score_with_ci(0.95).is_none());
+
+        let tournament =
+            Tournament::combinations_with_replacement(rpd, &players).with_repetitions(50);
+        assert_eq!(tournament.repetitions(), 50);
+        let result = tournament.play();
+        assert_eq!(result.repetition_scores().len(), 50);
+        for score in result.repetition_scores() {
+            assert_eq!(score, once.score());
+        }
+
+        let estimates = result.score_with_ci(0.99).unwrap();
+        assert_eq!(estimates.len(), 3);
+        for (name, (mean, lower, upper)) in estimates {
+            let exact = once.score().get(&name).unwrap() as f64;
+            assert_eq!((mean, lower, upper), (exact, exact, exact));
+        }
+    }
+
+    #[test]
+    fn standard_normal_quantiles() {
+        assert!(standard_normal_quantile(0.5).abs() < 1e-9);
+        assert!((standard_normal_quantile(0.975) - 1.959964).abs() < 1e-6);
+        assert!((standard_normal_quantile(0.005) + 2.575829).abs() < 1e-6);
+    }
+
+    #[test]
+    fn float_scores_are_reproducible() {
+        let moves: Vec<u8> = (0..24).collect();
+        // Utilities spanning many orders of magnitude, so that the sum depends on the order.
+        let utility = |m: u8, n: u8| {
+            let x = (m as f64 * 7.3 + n as f64 * 1.9).sin();
+            x * 10f64.powi((m as i32 * 5 + n as i32 * 3) % 17 - 8)
+        };
+        let game = Normal::from_payoff_fn(PerPlayer::new([moves.clone(), moves.clone()]), {
+            move |profile| {
+                Payoff::from([
+                    utility(profile[for2::P0], profile[for2::P1]),
+                    utility(profile[for2::P1], profile[for2::P0]),
+                ])
+            }
+        });
+        let players = moves
+            .into_iter()
+            .map(|m| Arc::new(Player::new(format!("P{}", m), move || Strategy::pure(m))))
+            .collect::<Vec<_>>();
+        let tournament = Tournament::permutations_with_replacement(Arc::new(game), &players);
+
+        let expected = tournament.play().score().clone();
+        for _ in 0..10 {
+            let score = tournament.play().score().clone();
+            for (name, utility) in expected.best_to_worst() {
+                assert_eq!(score.get(name).unwrap().to_bits(), utility.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn elo_ratings_rank_dominant_player_highest() {
+        // The game is won by the player who picks the higher number.
+        let moves: Vec<u8> = (0..5).collect();
+        let game = Normal::from_payoff_fn(PerPlayer::new([moves.clone(), moves.clone()]), {
+            |profile| {
+                let (a, b) = (profile[for2::P0] as i32, profile[for2::P1] as i32);
+                Payoff::from([(a - b).signum(), (b - a).signum()])
+            }
+        });
+        let players = moves
+            .into_iter()
+            .map(|m| Arc::new(Player::new(format!("P{}", m), move || Strategy::pure(m))))
+            .collect::<Vec<_>>();
+        let tournament = Tournament::combinations_with_replacement(Arc::new(game), &players);
+
+        let ratings = tournament.play().elo_ratings(32.0);
+        assert_eq!(ratings.len(), 5);
+
+        let ranked = ratings
+            .iter()
+            .sorted_by(|a, b| b.1.partial_cmp(a.1).unwrap())
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ranked, vec!["P4", "P3", "P2", "P1", "P0"]);
+
+        let total: f64 = ratings.values().sum();
+        assert!((total - 5.0 * 1500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn learning_strategy_state_is_cleared_between_matchups() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        // Cooperates until it observes a defection, then defects forever. The learned state is
+        // shared by every strategy the player produces, so it persists across matchups unless the
+        // strategy's reset hook clears it.
+        let learner = |with_reset: bool| {
+            let seen_defection = Arc::new(AtomicBool::new(false));
+            let player = Player::<Rpd, 2>::new("Learner".to_string(), {
+                let seen_defection = seen_defection.clone();
+                move || {
+                    let learned = seen_defection.clone();
+                    let strategy = Strategy::new(
+                        move |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+                            if context.their_last_move() == Some('D') {
+                                learned.store(true, Ordering::Relaxed);
+                            }
+                            if learned.load(Ordering::Relaxed) {
+                                'D'
+                            } else {
+                                'C'
+                            }
+                        },
+                    );
+                    if with_reset {
+                        let cleared = seen_defection.clone();
+                        strategy.with_reset(move || cleared.store(false, Ordering::Relaxed))
+                    } else {
+                        strategy
+                    }
+                }
+            });
+            (Arc::new(player), seen_defection)
+        };
+        let defector = Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D')));
+        let cooperator = Arc::new(Player::new("Cooperator".to_string(), || {
+            Strategy::pure('C')
+        }));
+
+        // Play the matchups one at a time, in order, so that the learner meets the defector before
+        // the cooperator and the shared state is not raced by matchups running in parallel.
+        let play = |learner: Arc<Player<Rpd, 2>>| {
+            let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+            let tournament = Tournament::permutations_with_replacement(
+                Arc::new(Repeated::new(Arc::new(pd), 5)),
+                &[defector.clone(), learner, cooperator.clone()],
+            );
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(1)
+                .build()
+                .unwrap();
+            let result = pool.install(|| tournament.play());
+            assert!(!result.has_errors());
+            result
+        };
+        let learner_opens_with = |result: &TournamentResult<Rpd, 2>| {
+            result
+                .results()
+                .iter()
+                .flat_map(|(names, history)| {
+                    let history = history.as_ref().unwrap();
+                    PlayerIndex::all()
+                        .filter(|&player| names[player] == "Learner")
+                        .map(|player| history.moves_for_player(player).next().unwrap())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let versus_cooperator = PerPlayer::new(["Learner".to_string(), "Cooperator".to_string()]);
+
+        // Without a reset hook, the defection learned against the defector carries over, through
+        // the learner's last matchup against the cooperator.
+        let (forgetful, seen_defection) = learner(false);
+        let result = play(forgetful);
+        assert!(learner_opens_with(&result).contains(&'D'));
+        assert!(seen_defection.load(Ordering::Relaxed));
+
+        // With a reset hook, the state is cleared before each matchup.
+        let (resetting, seen_defection) = learner(true);
+        let result = play(resetting);
+        assert!(learner_opens_with(&result).iter().all(|&m| m == 'C'));
+        assert!(!seen_defection.load(Ordering::Relaxed));
+        assert_eq!(
+            *result.results()[&versus_cooperator]
+                .as_ref()
+                .unwrap()
+                .score(),
+            Payoff::from([10, 10])
+        );
+    }
+
+    #[test]
+    fn evolve_tit_for_tat_grows_past_critical_mass() {
+        type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd), 10);
+        let players = vec![
+            Arc::new(Player::new("Tit-for-Tat".to_string(), || {
+                Strategy::lagged_mirror(1, 'C')
+            })),
+            Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D'))),
+        ];
+        let tournament = Tournament::permutations_with_replacement(Arc::new(rpd), &players);
+
+        // Over 10 rounds, tit-for-tat outperforms defectors when its share exceeds 1/9.
+        let shares = |tft: f64| {
+            HashMap::from([
+                ("Tit-for-Tat".to_string(), tft),
+                ("Defector".to_string(), 1.0 - tft),
+            ])
+        };
+
+        let growing = tournament.evolve(50, shares(0.3));
+        assert_eq!(growing.len(), 51);
+        for window in growing.windows(2) {
+            assert!(window[1]["Tit-for-Tat"] >= window[0]["Tit-for-Tat"]);
+            let total: f64 = window[1].values().sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+        assert!(growing[1]["Tit-for-Tat"] > growing[0]["Tit-for-Tat"]);
+        assert!(growing[50]["Tit-for-Tat"] > 0.99);
+
+        let shrinking = tournament.evolve(50, shares(0.05));
+        assert!(shrinking[50]["Tit-for-Tat"] < 0.05);
+
+        let unknown = HashMap::from([("Pavlov".to_string(), 1.0)]);
+        assert!(tournament.evolve(10, unknown).is_empty());
+    }
+
+    #[test]
+    fn egalitarian_score_penalizes_defector() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let players = [
+            Arc::new(Player::new("Cooperator".to_string(), || {
+                Strategy::pure('C')
+            })),
+            Arc::new(Player::new("Defector".to_string(), || Strategy::pure('D'))),
+        ];
+        let tournament = Tournament::combinations_with_replacement(Arc::new(pd), &players);
+
+        let own = tournament.play();
+        assert_eq!(own.score().get("Cooperator"), Some(4));
+        assert_eq!(own.score().get("Defector"), Some(5));
+
+        let welfare = tournament
+            .clone()
+            .with_score_mode(ScoreMode::SocialWelfare)
+            .play();
+        assert_eq!(welfare.score().get("Cooperator"), Some(11));
+        assert_eq!(welfare.score().get("Defector"), Some(7));
+
+        let egalitarian = tournament.with_score_mode(ScoreMode::Egalitarian).play();
+        assert_eq!(egalitarian.score().get("Cooperator"), Some(4));
+        assert_eq!(egalitarian.score().get("Defector"), Some(2));
+        assert_eq!(egalitarian.score().best_to_worst()[0].0, "Cooperator");
+    }
+
+    /// A game that plays like the wrapped game, but counts how many times its game tree is built.
+    #[derive(Clone)]
+    struct CountingBuilds<G> {
+        game: G,
+        builds: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl<G: Game<2>> Game<2> for CountingBuilds<G> {
+        type Move = G::Move;
+        type Utility = G::Utility;
+        type Outcome = G::Outcome;
+        type State = G::State;
+        type View = G::View;
+
+        fn into_game_tree(self) -> crate::GameTree<G::State, G::Move, G::Utility, G::Outcome, 2> {
+            self.builds
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.game.into_game_tree()
+        }
+
+        fn state_view(&self, state: &G::State, player: PlayerIndex<2>) -> G::View {
+            self.game.state_view(state, player)
+        }
+    }
+
+    #[test]
+    fn tournament_builds_game_tree_once() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let builds = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let game = CountingBuilds {
+            game: Repeated::new(Arc::new(pd), 20),
+            builds: builds.clone(),
+        };
+        let players = ['C', 'D']
+            .into_iter()
+            .map(|m| Arc::new(Player::new(m.to_string(), move || Strategy::pure(m))))
+            .collect::<Vec<_>>();
+        let tournament =
+            Tournament::combinations_with_replacement(Arc::new(game.clone()), &players)
+                .with_repetitions(5);
+
+        let result = tournament.play();
+        assert!(!result.has_errors());
+        assert_eq!(result.score().get("C"), Some(400));
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // Playing without compiling builds the tree for every game.
+        for matchup in tournament.matchups() {
+            game.play(matchup).unwrap();
+        }
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn compiled_repeated_game_builds_stage_game_tree_once() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let builds = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let stage = CountingBuilds {
+            game: pd,
+            builds: builds.clone(),
+        };
+        let game = Repeated::new(Arc::new(stage), 20);
+        let players = ['C', 'D']
+            .into_iter()
+            .map(|m| Arc::new(Player::new(m.to_string(), move || Strategy::pure(m))))
+            .collect::<Vec<_>>();
+        let tournament =
+            Tournament::combinations_with_replacement(Arc::new(game.clone()), &players)
+                .with_repetitions(5);
+
+        // Every round of every game reuses the stage game tree built when the game was compiled.
+        let result = tournament.play();
+        assert!(!result.has_errors());
+        assert_eq!(result.score().get("C"), Some(400));
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // Without compiling, the stage game tree is built once per game rather than per round.
+        for matchup in tournament.matchups() {
+            game.play(matchup).unwrap();
+        }
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn non_transitive_tournament_has_no_condorcet_winner() {
+        // Rock-paper-scissors, plus a move that loses to every other move.
+        let beats = |a: char, b: char| matches!((a, b), ('R', 'S') | ('P', 'R') | ('S', 'P'));
+        let rps = Normal::from_payoff_fn(
+            PerPlayer::new([vec!['R', 'P', 'S', 'X'], vec!['R', 'P', 'S', 'X']]),
+            move |profile: Profile<char, 2>| {
+                let (a, b) = (profile[for2::P0], profile[for2::P1]);
+                if a == b {
+                    Payoff::from([0, 0])
+                } else if b == 'X' || (a != 'X' && beats(a, b)) {
+                    Payoff::from([1, -1])
+                } else {
+                    Payoff::from([-1, 1])
+                }
+            },
+        );
+        let player = |m: char| Arc::new(Player::new(m.to_string(), move || Strategy::pure(m)));
+        let rps_players = ['R', 'P', 'S'].map(player);
+
+        let result =
+            Tournament::permutations_with_replacement(Arc::new(rps.clone()), &rps_players).play();
+        assert_eq!(result.condorcet_winner(), None);
+        assert_eq!(result.condorcet_ranking(), vec![vec!["P", "R", "S"]]);
+
+        let mut all_players = rps_players.to_vec();
+        all_players.push(player('X'));
+        let result = Tournament::permutations_with_replacement(Arc::new(rps), &all_players).play();
+        assert_eq!(result.condorcet_winner(), None);
+        assert_eq!(
+            result.condorcet_ranking(),
+            vec![vec!["P", "R", "S"], vec!["X"]]
+        );
+    }
+
+    #[test]
+    fn team_scores_sum_members_and_total() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Arc::new(Repeated::new(Arc::new(pd), 10));
+        let tit_for_tat = |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+            context.their_last_move().unwrap_or('C')
+        };
+        let players = vec![
+            Arc::new(Player::new("Nice".to_string(), || Strategy::pure('C'))),
+            Arc::new(Player::new("TFT".to_string(), move || {
+                Strategy::new(tit_for_tat)
+            })),
+            Arc::new(Player::new("Mean".to_string(), || Strategy::pure('D'))),
+            Arc::new(Player::new("Alternator".to_string(), || {
+                Strategy::periodic_pure(vec!['C', 'D'])
+            })),
+        ];
+        let teams: HashMap<String, TeamId> = [
+            ("Nice", "Blue"),
+            ("TFT", "Red"),
+            ("Mean", "Red"),
+            ("Alternator", "Blue"),
+        ]
+        .into_iter()
+        .map(|(name, team)| (name.to_string(), team.to_string()))
+        .collect();
+
+        let tournament =
+            Tournament::combinations_with_replacement(rpd.clone(), &players).with_teams(teams);
+        assert_eq!(tournament.teams()["Mean"], "Red");
+
+        let result = tournament.play();
+        let score = result.score();
+        let team_score = result.team_score();
+        assert_eq!(team_score.best_to_worst().len(), 2);
+        assert_eq!(
+            team_score.get("Red"),
+            Some(score.get_or_zero("TFT") + score.get_or_zero("Mean"))
+        );
+        assert_eq!(
+            team_score.get("Blue"),
+            Some(score.get_or_zero("Nice") + score.get_or_zero("Alternator"))
+        );
+        let total: i32 = score.best_to_worst().iter().map(|(_, s)| s).sum();
+        assert_eq!(
+            team_score.get_or_zero("Red") + team_score.get_or_zero("Blue"),
+            total
+        );
+
+        // Without teams, there are no team scores.
+        let result = Tournament::combinations_with_replacement(rpd, &players).play();
+        assert!(result.team_score().best_to_worst().is_empty());
+    }
 }
@@ -0,0 +1,41 @@
+use crate::{Payoff, PlayerIndex, Ply};
+
+/// An event emitted while playing a game with
+/// [`Game::play_with_observer`](crate::Game::play_with_observer).
+///
+/// Events are emitted in the order they occur during the execution of the game tree, so the
+/// sequence of events observed while playing a game describes exactly how the game unfolded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraceEvent<'a, S, M, U, const P: usize> {
+    /// The given players are about to move in the given state.
+    Turn {
+        /// The state of the game before the players move.
+        state: &'a S,
+        /// The players whose turn it is to move.
+        to_move: &'a [PlayerIndex<P>],
+    },
+
+    /// A move of chance is about to be sampled in the given state.
+    Chance {
+        /// The state of the game before the move of chance.
+        state: &'a S,
+    },
+
+    /// A move was played, either by a player or by chance.
+    ///
+    /// When several players move simultaneously, one event is emitted for each player once all
+    /// of their moves have been accepted by the game.
+    ///
+    /// A move played by a player is annotated with the name of the
+    /// [strategy that chose it](crate::Strategy::acting_name), if the strategy is named. Moves of
+    /// chance are not annotated.
+    Move(Ply<M, P>, Option<&'a str>),
+
+    /// The game ended with the given payoff.
+    End {
+        /// The final state of the game.
+        state: &'a S,
+        /// The payoff awarded to each player.
+        payoff: &'a Payoff<U, P>,
+    },
+}
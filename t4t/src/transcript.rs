@@ -1,12 +1,24 @@
-use crate::{Move, PerPlayer, PlayerIndex, Plies, Ply, Profile, Record, Summary};
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    Action, Game, History, Move, PerPlayer, PlayerIndex, Plies, Ply, Profile, Record, Summary,
+};
 
 /// A transcript of the moves played (so far) in a sequential game.
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+///
+/// Each ply in a transcript may optionally be annotated with a string, such as the reasoning
+/// behind a move or the time it was played. This makes transcripts useful as logs of experiments.
+/// Annotations are stored alongside the plies and do not affect the moves in the transcript, so
+/// they are ignored when comparing or hashing transcripts.
+#[derive(Clone, Debug, Eq)]
 pub struct Transcript<M, const P: usize> {
     /// The sequence of played moves.
     plies: Vec<Ply<M, P>>,
     /// The number of moves played by each player.
     summary: Summary<P>,
+    /// Annotations on plies, keyed by the index of the ply in the transcript.
+    annotations: BTreeMap<usize, String>,
 }
 
 impl<M, const P: usize> Default for Transcript<M, P> {
@@ -14,12 +26,25 @@ impl<M, const P: usize> Default for Transcript<M, P> {
         Transcript {
             plies: Vec::new(),
             summary: Summary::empty(),
+            annotations: BTreeMap::new(),
         }
     }
 }
 
+impl<M: PartialEq, const P: usize> PartialEq for Transcript<M, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.plies == other.plies
+    }
+}
+
+impl<M: Hash, const P: usize> Hash for Transcript<M, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.plies.hash(state);
+    }
+}
+
 impl<M: Move, const P: usize> Record<M, P> for Transcript<M, P> {
-    fn plies(&self) -> Plies<M, P> {
+    fn plies(&self) -> Plies<'_, M, P> {
         Plies::from_vec(self.plies.clone())
     }
 
@@ -44,7 +69,11 @@ impl<M: Move, const P: usize> Transcript<M, P> {
         for ply in &plies {
             summary.increment_moves_by(ply.player)
         }
-        Transcript { plies, summary }
+        Transcript {
+            plies,
+            summary,
+            annotations: BTreeMap::new(),
+        }
     }
 
     /// Construct a transcript from a ply iterator.
@@ -79,6 +108,63 @@ impl<M: Move, const P: usize> Transcript<M, P> {
         }
     }
 
+    /// Add an annotated ply to the transcript.
+    pub fn add_annotated(&mut self, ply: Ply<M, P>, annotation: impl Into<String>) {
+        self.add(ply);
+        self.annotations
+            .insert(self.plies.len() - 1, annotation.into());
+    }
+
+    /// Annotate the ply at the given index, replacing any existing annotation on that ply.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and leaves the transcript unchanged if there is no ply at the given index.
+    pub fn annotate(&mut self, index: usize, annotation: impl Into<String>) {
+        if index < self.plies.len() {
+            self.annotations.insert(index, annotation.into());
+        } else {
+            log::error!(
+                "Transcript::annotate: no ply at index {} in a transcript of length {}",
+                index,
+                self.plies.len()
+            );
+        }
+    }
+
+    /// Builder method that annotates the ply at the given index. See [`annotate`](Self::annotate).
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let transcript: Transcript<char, 2> = Transcript::from_ply_vec(vec![
+    ///     Ply::player(for2::P0, 'A'),
+    ///     Ply::player(for2::P1, 'B'),
+    /// ])
+    /// .with_annotation(1, "copied the opponent");
+    ///
+    /// assert_eq!(transcript.annotation(0), None);
+    /// assert_eq!(transcript.annotation(1), Some("copied the opponent"));
+    /// ```
+    pub fn with_annotation(mut self, index: usize, annotation: impl Into<String>) -> Self {
+        self.annotate(index, annotation);
+        self
+    }
+
+    /// Get the annotation on the ply at the given index, if any.
+    pub fn annotation(&self, index: usize) -> Option<&str> {
+        self.annotations.get(&index).map(String::as_str)
+    }
+
+    /// Get an iterator over the annotated plies in the transcript, in order, paired with their
+    /// indexes and annotations.
+    pub fn annotated_plies(&self) -> impl Iterator<Item = (usize, &Ply<M, P>, &str)> {
+        self.annotations
+            .iter()
+            .map(|(&index, annotation)| (index, &self.plies[index], annotation.as_str()))
+    }
+
     /// Add a move played by chance to the transcript.
     pub fn add_chance_move(&mut self, the_move: M) {
         self.add(Ply::new(None, the_move));
@@ -158,6 +244,154 @@ impl<M: Move, const P: usize> Transcript<M, P> {
     pub fn last_move_by_player(&self, player: PlayerIndex<P>) -> Option<M> {
         self.last_move_by(Some(player))
     }
+
+    /// Get the index of the first ply at which this transcript differs from another.
+    ///
+    /// If one transcript is a prefix of the other, the result is the length of the shorter
+    /// transcript. Returns `None` if the two transcripts are identical.
+    ///
+    /// This is useful for debugging why two similar executions of a game produced different
+    /// results. Since [`History`](crate::History) implements [`Record`], the histories of two
+    /// repeated games can be compared by first converting them with
+    /// [`to_transcript`](Record::to_transcript).
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let t1: Transcript<char, 2> = Transcript::from_ply_vec(vec![
+    ///     Ply::player(for2::P0, 'A'),
+    ///     Ply::player(for2::P1, 'B'),
+    ///     Ply::player(for2::P0, 'C'),
+    /// ]);
+    /// let t2 = Transcript::from_ply_vec(vec![
+    ///     Ply::player(for2::P0, 'A'),
+    ///     Ply::player(for2::P1, 'B'),
+    ///     Ply::player(for2::P0, 'D'),
+    /// ]);
+    /// let t3 = Transcript::from_ply_vec(vec![Ply::player(for2::P0, 'A')]);
+    ///
+    /// assert_eq!(t1.first_divergence(&t1), None);
+    /// assert_eq!(t1.first_divergence(&t2), Some(2));
+    /// assert_eq!(t1.first_divergence(&t3), Some(1));
+    /// assert_eq!(t3.first_divergence(&t2), Some(1));
+    /// ```
+    pub fn first_divergence(&self, other: &Transcript<M, P>) -> Option<usize> {
+        match self
+            .plies
+            .iter()
+            .zip(&other.plies)
+            .position(|(a, b)| a != b)
+        {
+            Some(index) => Some(index),
+            None if self.plies.len() != other.plies.len() => {
+                Some(self.plies.len().min(other.plies.len()))
+            }
+            None => None,
+        }
+    }
+
+    /// Convert this transcript into the history of a repeated game, by replaying its moves as a
+    /// sequence of games of the given stage game.
+    ///
+    /// The moves are fed, in order, into the stage game's [tree](Game::game_tree): each player's
+    /// turn consumes the next plies, which must be by the players whose turn it is, and each move
+    /// of chance consumes the next ply, which must be by chance. When the stage game ends, its
+    /// outcome is added to the history, and the next game begins with the next ply. This
+    /// recovers the payoffs of each game, which the transcript does not record.
+    ///
+    /// Annotations are not preserved.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if the transcript's moves do not form a sequence of
+    /// complete, valid games of the stage game.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let transcript = Transcript::from_ply_vec(vec![
+    ///     Ply::player(for2::P0, 'C'),
+    ///     Ply::player(for2::P1, 'D'),
+    ///     Ply::player(for2::P0, 'D'),
+    ///     Ply::player(for2::P1, 'D'),
+    /// ]);
+    ///
+    /// let history = transcript.clone().into_history(&pd).unwrap();
+    /// assert_eq!(history.num_games(), 2);
+    /// assert_eq!(*history.score(), Payoff::from([1, 4]));
+    /// assert_eq!(history.to_transcript(), transcript);
+    ///
+    /// // The last game is incomplete.
+    /// let unfinished = Transcript::from_ply_vec(vec![Ply::player(for2::P0, 'C')]);
+    /// assert!(unfinished.into_history(&pd).is_none());
+    /// ```
+    pub fn into_history<G: Game<P, Move = M>>(self, stage_game: &G) -> Option<History<G, P>> {
+        let mut history = History::empty();
+        let mut plies = self.plies.into_iter().peekable();
+        let root = stage_game.game_tree();
+
+        while plies.peek().is_some() {
+            let mut node = root.clone();
+            let mut consumed = false;
+            let outcome = loop {
+                let result = match node.action {
+                    Action::Turns { to_move, next } => {
+                        let mut moves = Vec::with_capacity(to_move.len());
+                        for player in to_move {
+                            match plies.next() {
+                                Some(ply) if ply.player == Some(player) => moves.push(ply.the_move),
+                                ply => {
+                                    log::error!(
+                                        "Transcript::into_history: expected a move by player {} in game {}, got {:?}",
+                                        player,
+                                        history.num_games() + 1,
+                                        ply,
+                                    );
+                                    return None;
+                                }
+                            }
+                        }
+                        next(node.state, moves)
+                    }
+                    Action::Chance { next, .. } => match plies.next() {
+                        Some(ply) if ply.player.is_none() => next(node.state, ply.the_move),
+                        ply => {
+                            log::error!(
+                                "Transcript::into_history: expected a move by chance in game {}, got {:?}",
+                                history.num_games() + 1,
+                                ply,
+                            );
+                            return None;
+                        }
+                    },
+                    Action::End { outcome, .. } => break outcome,
+                };
+                consumed = true;
+                match result {
+                    Ok(next_node) => node = next_node,
+                    Err(kind) => {
+                        log::error!(
+                            "Transcript::into_history: invalid move in game {}: {:?}",
+                            history.num_games() + 1,
+                            kind,
+                        );
+                        return None;
+                    }
+                }
+            };
+            if !consumed {
+                log::error!(
+                    "Transcript::into_history: the stage game ends without any moves, but the transcript has moves left"
+                );
+                return None;
+            }
+            history.add(outcome);
+        }
+        Some(history)
+    }
 }
 
 impl<M, const P: usize> Transcript<M, P> {
@@ -196,3 +430,193 @@ impl<'a, M, const P: usize> IntoIterator for &'a mut Transcript<M, P> {
         self.plies.iter_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        for2, Context, Game, Matchup, Normal, Player, Repeated, RepeatedState, Strategy, TraceEvent,
+    };
+    use std::sync::Arc;
+    use test_log::test;
+
+    type Rpd = Repeated<Normal<char, i32, 2>, 2>;
+
+    fn defect_from(round: usize) -> Player<Rpd, 2> {
+        Player::new(format!("Defect from {}", round), move || {
+            Strategy::new(
+                move |context: &Context<RepeatedState<Normal<char, i32, 2>, 2>, 2>| {
+                    if context.state_view().history().outcomes().len() < round {
+                        'C'
+                    } else {
+                        'D'
+                    }
+                },
+            )
+        })
+    }
+
+    #[test]
+    fn annotations_round_trip() {
+        let mut transcript: Transcript<char, 2> = Transcript::new();
+        transcript.add_annotated(Ply::player(for2::P0, 'C'), "opening");
+        transcript.add(Ply::player(for2::P1, 'D'));
+        transcript.add_annotated(Ply::player(for2::P0, 'D'), "retaliate");
+        transcript.annotate(1, "probe");
+        transcript.annotate(5, "out of range");
+
+        assert_eq!(transcript.annotation(0), Some("opening"));
+        assert_eq!(transcript.annotation(1), Some("probe"));
+        assert_eq!(transcript.annotation(2), Some("retaliate"));
+        assert_eq!(transcript.annotation(3), None);
+        assert_eq!(
+            transcript.annotated_plies().collect::<Vec<_>>(),
+            vec![
+                (0, &Ply::player(for2::P0, 'C'), "opening"),
+                (1, &Ply::player(for2::P1, 'D'), "probe"),
+                (2, &Ply::player(for2::P0, 'D'), "retaliate"),
+            ]
+        );
+
+        // Annotations do not affect the moves in the transcript.
+        assert_eq!(transcript.moves_by_player(for2::P0), vec!['C', 'D']);
+        assert_eq!(
+            transcript
+                .clone()
+                .with_annotation(0, "changed")
+                .first_divergence(&transcript),
+            None
+        );
+
+        // Nor do they affect equality or hashing.
+        let unannotated = Transcript::from_ply_vec(transcript.iter().copied().collect());
+        assert_eq!(transcript, unannotated);
+        let hash = |t: &Transcript<char, 2>| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            t.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&transcript), hash(&unannotated));
+    }
+
+    #[test]
+    fn annotate_plies_recorded_by_observer() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd), 3);
+
+        let mut transcript = Transcript::new();
+        let mut round = 0;
+        let history = rpd
+            .play_with_observer(
+                &Matchup::from_players([defect_from(1), defect_from(2)]),
+                |event| match event {
+                    TraceEvent::Turn { .. } => round += 1,
+                    TraceEvent::Move(ply, _) => {
+                        transcript.add_annotated(*ply, format!("round {}", round))
+                    }
+                    _ => {}
+                },
+            )
+            .unwrap();
+
+        assert_eq!(transcript.first_divergence(&history.to_transcript()), None);
+        assert_eq!(transcript.annotation(0), Some("round 1"));
+        assert_eq!(transcript.annotation(5), Some("round 3"));
+    }
+
+    #[test]
+    fn play_with_observer_preserves_strategy_annotations() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd), 3);
+        let named = Player::new("Named".to_string(), || {
+            Strategy::pure('C').with_name("always C")
+        });
+        let matchup = Matchup::from_players([named, defect_from(1)]);
+
+        let mut observed = Transcript::new();
+        rpd.play_with_observer(&matchup, |event| {
+            if let TraceEvent::Move(ply, annotation) = event {
+                match annotation {
+                    Some(annotation) => observed.add_annotated(*ply, *annotation),
+                    None => observed.add(*ply),
+                }
+            }
+        })
+        .unwrap();
+
+        let (_, recorded) = rpd.play_with_transcript(&matchup);
+        assert_eq!(observed, recorded);
+        assert_eq!(
+            observed.annotated_plies().collect::<Vec<_>>(),
+            recorded.annotated_plies().collect::<Vec<_>>()
+        );
+        assert_eq!(observed.annotated_plies().count(), 3);
+    }
+
+    #[test]
+    fn first_divergence_of_repeated_histories() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd), 10);
+
+        let h1 = rpd
+            .play(&Matchup::from_players([defect_from(10), defect_from(10)]))
+            .unwrap();
+        let h2 = rpd
+            .play(&Matchup::from_players([defect_from(10), defect_from(5)]))
+            .unwrap();
+
+        let t1 = h1.to_transcript();
+        let t2 = h2.to_transcript();
+        let index = t1.first_divergence(&t2).unwrap();
+
+        assert_eq!(index / 2, 5);
+        assert_eq!(t2.iter().nth(index), Some(&Ply::player(for2::P1, 'D')));
+        assert_eq!(t1.first_divergence(&h1.to_transcript()), None);
+    }
+
+    /// The fraction of rounds in which both players cooperated, and the number of times each
+    /// player switched moves between rounds.
+    fn cooperation_summary<R: Record<char, 2>>(record: &R) -> (f64, [usize; 2]) {
+        let profiles = record.played_profiles().unwrap();
+        let mutual = profiles
+            .iter()
+            .filter(|profile| **profile == Profile::new(['C', 'C']))
+            .count();
+        let switches = |player| {
+            let moves: Vec<char> = record.played_moves_by_player(player).collect();
+            moves.windows(2).filter(|pair| pair[0] != pair[1]).count()
+        };
+        (
+            mutual as f64 / profiles.len() as f64,
+            [switches(for2::P0), switches(for2::P1)],
+        )
+    }
+
+    #[test]
+    fn analysis_agrees_on_transcript_and_history() {
+        let pd = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd: Rpd = Repeated::new(Arc::new(pd.clone()), 8);
+        let history = rpd
+            .play(&Matchup::from_players([defect_from(3), defect_from(6)]))
+            .unwrap();
+        let transcript = history.to_transcript();
+
+        assert_eq!(cooperation_summary(&history), (3.0 / 8.0, [1, 1]));
+        assert_eq!(
+            cooperation_summary(&transcript),
+            cooperation_summary(&history)
+        );
+
+        let replayed = transcript.into_history(&pd).unwrap();
+        assert_eq!(replayed, history);
+        assert_eq!(
+            cooperation_summary(&replayed),
+            cooperation_summary(&history)
+        );
+
+        // A transcript of a different game cannot be replayed.
+        let invalid =
+            Transcript::from_ply_vec(vec![Ply::player(for2::P0, 'C'), Ply::player(for2::P1, 'X')]);
+        assert!(invalid.into_history(&pd).is_none());
+    }
+}
@@ -1,7 +1,12 @@
+use itertools::Itertools;
+use std::fmt::Write;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-use crate::{Distribution, ErrorKind, Game, Move, Outcome, PlayerIndex, Profile, State, Utility};
+use crate::{
+    Distribution, ErrorKind, FiniteGame, Game, Move, Outcome, Payoff, PlayerIndex, Profile, State,
+    Utility,
+};
 
 /// The outgoing edges of a node in a game tree, represented as a function.
 ///
@@ -162,6 +167,394 @@ impl<S, M: Move, U, O, const P: usize> GameTree<S, M, U, O, P> {
     }
 }
 
+impl<S, M: Move, U: Utility, O: Outcome<M, U, P>, const P: usize> GameTree<S, M, U, O, P> {
+    /// Render this game tree in the [DOT](https://graphviz.org/doc/info/lang.html) language for
+    /// visualization with [Graphviz](https://graphviz.org/).
+    ///
+    /// Since the moves available at each node are not stored in the tree, the given finite `game`
+    /// is used to enumerate the moves available to each player from each node's state. Nodes where
+    /// several players move simultaneously have one outgoing edge for each combination of moves.
+    ///
+    /// The tree is rendered up to `max_depth` actions from this node. Deeper subtrees are replaced
+    /// by nodes labeled `...`, which makes it possible to render prefixes of very large or infinite
+    /// game trees.
+    ///
+    /// In the output, decision nodes are circles labeled by the players to move, chance nodes are
+    /// diamonds, and leaves are boxes labeled by the payoff. Edges are labeled by moves, and edges
+    /// out of chance nodes also include the probability of each move.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let dot = pd.game_tree().to_dot(&pd, 5);
+    ///
+    /// assert!(dot.starts_with("digraph {"));
+    /// assert!(dot.contains("n0 [label=\"P0, P1\", shape=circle];"));
+    /// assert!(dot.contains("n0 -> n2 [label=\"'C', 'D'\"];"));
+    /// assert!(dot.contains("n2 [label=\"[0, 3]\", shape=box];"));
+    /// ```
+    pub fn to_dot<G>(&self, game: &G, max_depth: usize) -> String
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+    {
+        let mut dot = String::from("digraph {\n");
+        let mut next_id = 0;
+        self.write_dot(game, max_depth, &mut next_id, &mut dot);
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Write the DOT nodes and edges for this subtree, returning the identifier of its root node.
+    fn write_dot<G>(&self, game: &G, depth: usize, next_id: &mut usize, dot: &mut String) -> usize
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+    {
+        let id = *next_id;
+        *next_id += 1;
+
+        if depth == 0 && !matches!(self.action, Action::End { .. }) {
+            writeln!(dot, "  n{} [label=\"...\", shape=plaintext];", id).unwrap();
+            return id;
+        }
+
+        match &self.action {
+            Action::Turns { to_move, next } => {
+                writeln!(
+                    dot,
+                    "  n{} [label=\"{}\", shape=circle];",
+                    id,
+                    to_move.iter().join(", ")
+                )
+                .unwrap();
+                let profiles = to_move
+                    .iter()
+                    .map(|&player| game.possible_moves(player, &self.state).collect::<Vec<_>>())
+                    .multi_cartesian_product();
+                for moves in profiles {
+                    let label = escape_dot(&moves.iter().map(|m| format!("{:?}", m)).join(", "));
+                    if let Ok(child) = next(self.state.clone(), moves) {
+                        let child_id = child.write_dot(game, depth - 1, next_id, dot);
+                        writeln!(dot, "  n{} -> n{} [label=\"{}\"];", id, child_id, label).unwrap();
+                    }
+                }
+            }
+
+            Action::Chance { distribution, next } => {
+                writeln!(dot, "  n{} [label=\"Chance\", shape=diamond];", id).unwrap();
+                for (the_move, prob) in distribution.probabilities() {
+                    let label = escape_dot(&format!("{:?} ({})", the_move, prob));
+                    if let Ok(child) = next(self.state.clone(), *the_move) {
+                        let child_id = child.write_dot(game, depth - 1, next_id, dot);
+                        writeln!(dot, "  n{} -> n{} [label=\"{}\"];", id, child_id, label).unwrap();
+                    }
+                }
+            }
+
+            Action::End { outcome, .. } => {
+                let payoff = outcome
+                    .payoff()
+                    .iter()
+                    .map(|u| format!("{:?}", u))
+                    .join(", ");
+                writeln!(
+                    dot,
+                    "  n{} [label=\"[{}]\", shape=box];",
+                    id,
+                    escape_dot(&payoff)
+                )
+                .unwrap();
+            }
+        }
+
+        id
+    }
+
+    /// Compare the structure of this game tree with another game tree, up to `max_depth` actions
+    /// from the root.
+    ///
+    /// Since the functions computing the next node cannot be compared directly, the trees are
+    /// compared by executing both of them on every sequence of moves. The given finite `game` is
+    /// used to enumerate the moves available to each player from each node's state in this tree.
+    ///
+    /// Two trees are structurally equal if, at every corresponding node:
+    /// - Both nodes are decision nodes for the same players, and for every combination of moves,
+    ///   either both trees reject the moves with the same error or both yield structurally equal
+    ///   subtrees.
+    /// - Both nodes are chance nodes with the same distribution and structurally equal subtrees
+    ///   for each move.
+    /// - Both nodes are leaves with the same payoff.
+    ///
+    /// The game states at each node are not compared, so trees constructed in different ways for
+    /// the same game compare equal. Nodes deeper than `max_depth` are not compared, which makes
+    /// it possible to compare prefixes of very large or infinite game trees.
+    ///
+    /// This is primarily useful for testing game transformations against an expected tree.
+    ///
+    /// # Examples
+    /// ```
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let same = Normal::bimatrix(
+    ///     ['C', 'D'],
+    ///     ['C', 'D'],
+    ///     [[2, 0], [3, 1]],
+    ///     [[2, 3], [0, 1]],
+    /// );
+    /// let chicken: Normal<char, i32, 2> =
+    ///     Normal::symmetric(vec!['C', 'D'], vec![2, 1, 3, 0]).unwrap();
+    ///
+    /// assert!(pd.game_tree().structurally_eq(&same.game_tree(), &pd, 5));
+    /// assert!(!pd.game_tree().structurally_eq(&chicken.game_tree(), &pd, 5));
+    /// ```
+    pub fn structurally_eq<G>(&self, other: &Self, game: &G, max_depth: usize) -> bool
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+    {
+        if max_depth == 0 {
+            return true;
+        }
+
+        match (&self.action, &other.action) {
+            (
+                Action::Turns { to_move, next },
+                Action::Turns {
+                    to_move: other_to_move,
+                    next: other_next,
+                },
+            ) => {
+                to_move == other_to_move
+                    && to_move
+                        .iter()
+                        .map(|&player| game.possible_moves(player, &self.state).collect::<Vec<_>>())
+                        .multi_cartesian_product()
+                        .all(|moves| {
+                            let child = next(self.state.clone(), moves.clone());
+                            let other_child = other_next(other.state.clone(), moves);
+                            Self::children_eq(child, other_child, game, max_depth - 1)
+                        })
+            }
+
+            (
+                Action::Chance { distribution, next },
+                Action::Chance {
+                    distribution: other_distribution,
+                    next: other_next,
+                },
+            ) => {
+                distribution
+                    .probabilities()
+                    .eq(other_distribution.probabilities())
+                    && distribution.probabilities().all(|(the_move, _)| {
+                        let child = next(self.state.clone(), *the_move);
+                        let other_child = other_next(other.state.clone(), *the_move);
+                        Self::children_eq(child, other_child, game, max_depth - 1)
+                    })
+            }
+
+            (
+                Action::End { outcome, .. },
+                Action::End {
+                    outcome: other_outcome,
+                    ..
+                },
+            ) => outcome.payoff() == other_outcome.payoff(),
+
+            _ => false,
+        }
+    }
+
+    /// Solve the game from this node by
+    /// [backward induction](https://en.wikipedia.org/wiki/Backward_induction), returning the
+    /// game-theoretic value of the node along with its
+    /// [principal variation](https://en.wikipedia.org/wiki/Principal_variation_search), the
+    /// sequence of moves played when every player plays optimally from this node on.
+    ///
+    /// This applies to combinatorial games, such as tic-tac-toe or Connect Four, where players
+    /// take turns one at a time and there are no moves of chance. At each decision node, the
+    /// player to move picks the move that maximizes their own utility in the solved subtree,
+    /// preferring the first such move as enumerated by the given finite `game`. Moves that the
+    /// tree rejects as invalid are skipped.
+    ///
+    /// The entire tree below this node is searched, so this is practical only for small games or
+    /// for positions near the end of larger games. For larger games, see
+    /// [`solve_to_depth`](GameTree::solve_to_depth).
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if a reachable node is a chance node or a node where
+    /// several players move simultaneously, or if a player has no valid moves at a decision node.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// // In the alternating prisoner's dilemma, the second mover always defects, so the first
+    /// // mover defects too.
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let game = AlternatingRepeated::new(Arc::new(pd.clone()), 2);
+    ///
+    /// let (value, line) = game.game_tree().solve_combinatorial(&game).unwrap();
+    /// assert_eq!(value, Payoff::from([2, 2]));
+    /// assert_eq!(line, vec!['D', 'D', 'D', 'D']);
+    ///
+    /// // Simultaneous games are not combinatorial.
+    /// let simultaneous = Repeated::new(Arc::new(pd), 2);
+    /// assert!(simultaneous.game_tree().solve_combinatorial(&simultaneous).is_none());
+    /// ```
+    pub fn solve_combinatorial<G>(&self, game: &G) -> Option<(Payoff<U, P>, Vec<M>)>
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+    {
+        // Without a depth limit, the search only stops at terminal nodes, so the evaluation
+        // function is never called.
+        let (value, mut line) =
+            self.solve_reversed(game, None, &|_: &S| Payoff::zeros(), "solve_combinatorial")?;
+        line.reverse();
+        Some((value, line))
+    }
+
+    /// Search the game from this node by depth-limited
+    /// [minimax](https://en.wikipedia.org/wiki/Minimax), returning the estimated value of the node
+    /// along with its principal variation up to the depth limit.
+    ///
+    /// This generalizes [`solve_combinatorial`](GameTree::solve_combinatorial) to games that are
+    /// too large to search to the end. Players move one at a time, and at each decision node the
+    /// player to move picks the move that maximizes their own component of the payoff, so games
+    /// with any number of players and scored terminal states are supported. After `max_depth`
+    /// moves, the search stops and the `evaluate` function estimates the payoff from the state of
+    /// the node reached. Terminal nodes within the depth limit are valued by their outcome.
+    ///
+    /// # Errors
+    ///
+    /// Logs an error and returns `None` if a node reachable within the depth limit is a chance
+    /// node or a node where several players move simultaneously, or if a player has no valid
+    /// moves at a decision node.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::sync::Arc;
+    /// use t4t::*;
+    ///
+    /// let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+    /// let game = AlternatingRepeated::new(Arc::new(pd), 100);
+    ///
+    /// // Looking one round ahead and valuing positions by the score so far, both players defect.
+    /// let (value, line) = game
+    ///     .game_tree()
+    ///     .solve_to_depth(&game, 2, |state| *state.history().score())
+    ///     .unwrap();
+    /// assert_eq!(value, Payoff::from([1, 1]));
+    /// assert_eq!(line, vec!['D', 'D']);
+    /// ```
+    pub fn solve_to_depth<G>(
+        &self,
+        game: &G,
+        max_depth: usize,
+        evaluate: impl Fn(&S) -> Payoff<U, P>,
+    ) -> Option<(Payoff<U, P>, Vec<M>)>
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+    {
+        let (value, mut line) =
+            self.solve_reversed(game, Some(max_depth), &evaluate, "solve_to_depth")?;
+        line.reverse();
+        Some((value, line))
+    }
+
+    /// Search the game from this node, returning its principal variation in reverse order. See
+    /// [`solve_to_depth`](GameTree::solve_to_depth).
+    fn solve_reversed<G, E>(
+        &self,
+        game: &G,
+        depth: Option<usize>,
+        evaluate: &E,
+        caller: &str,
+    ) -> Option<(Payoff<U, P>, Vec<M>)>
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+        E: Fn(&S) -> Payoff<U, P>,
+    {
+        if depth == Some(0) && !matches!(self.action, Action::End { .. }) {
+            return Some((evaluate(&self.state), Vec::new()));
+        }
+        let child_depth = depth.map(|depth| depth.saturating_sub(1));
+
+        match &self.action {
+            Action::Turns { to_move, next } if to_move.len() == 1 => {
+                let player = to_move[0];
+                let mut best: Option<(Payoff<U, P>, Vec<M>, M)> = None;
+                for the_move in game.possible_moves(player, &self.state) {
+                    let Ok(child) = next(self.state.clone(), vec![the_move]) else {
+                        continue;
+                    };
+                    let (value, line) =
+                        child.solve_reversed(game, child_depth, evaluate, caller)?;
+                    let improves = match &best {
+                        Some((best_value, _, _)) => value[player] > best_value[player],
+                        None => true,
+                    };
+                    if improves {
+                        best = Some((value, line, the_move));
+                    }
+                }
+                match best {
+                    Some((value, mut line, the_move)) => {
+                        line.push(the_move);
+                        Some((value, line))
+                    }
+                    None => {
+                        log::error!("GameTree::{}: player {} has no valid moves", caller, player);
+                        None
+                    }
+                }
+            }
+
+            Action::Turns { to_move, .. } => {
+                log::error!(
+                    "GameTree::{}: players {} move simultaneously, which is not supported",
+                    caller,
+                    to_move.iter().join(", ")
+                );
+                None
+            }
+
+            Action::Chance { .. } => {
+                log::error!("GameTree::{}: moves of chance are not supported", caller);
+                None
+            }
+
+            Action::End { outcome, .. } => Some((*outcome.payoff(), Vec::new())),
+        }
+    }
+
+    /// Compare the results of computing the next node in two game trees. See
+    /// [`structurally_eq`](GameTree::structurally_eq).
+    fn children_eq<G>(
+        child: Result<Self, ErrorKind<M, P>>,
+        other_child: Result<Self, ErrorKind<M, P>>,
+        game: &G,
+        max_depth: usize,
+    ) -> bool
+    where
+        G: FiniteGame<P, State = S, Move = M>,
+    {
+        match (child, other_child) {
+            (Ok(child), Ok(other_child)) => child.structurally_eq(&other_child, game, max_depth),
+            (Err(err), Err(other_err)) => err == other_err,
+            _ => false,
+        }
+    }
+}
+
+/// Escape a string for use within a double-quoted DOT label.
+pub(crate) fn escape_dot(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 impl<S: State, M: Move, U: Utility, O: Outcome<M, U, P>, const P: usize> Game<P>
     for GameTree<S, M, U, O, P>
 {
@@ -179,3 +572,299 @@ impl<S: State, M: Move, U: Utility, O: Outcome<M, U, P>, const P: usize> Game<P>
         state.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{for2, Normal, PerPlayer, PossibleMoves, Repeated, SequentialOutcome, Transcript};
+    use test_log::test;
+
+    #[test]
+    fn to_dot_two_round_repeated_dilemma() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let rpd = Repeated::new(Arc::new(pd), 2);
+        let dot = rpd.game_tree().to_dot(&rpd, 10);
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches("shape=box").count(), 16);
+        assert_eq!(dot.matches("shape=circle").count(), 5);
+        assert_eq!(dot.matches(" -> ").count(), 20);
+        assert!(dot.contains("[label=\"[6, 0]\", shape=box]"));
+
+        let truncated = rpd.game_tree().to_dot(&rpd, 1);
+        assert_eq!(truncated.matches("shape=box").count(), 0);
+        assert_eq!(truncated.matches("shape=plaintext").count(), 4);
+    }
+
+    #[test]
+    fn independently_constructed_trees_are_structurally_equal() {
+        let pd: Normal<char, i32, 2> = Normal::symmetric(vec!['C', 'D'], vec![2, 0, 3, 1]).unwrap();
+        let pd_fn = Normal::from_payoff_fn(
+            PerPlayer::new([vec!['C', 'D'], vec!['C', 'D']]),
+            |profile: Profile<char, 2>| match (profile[for2::P0], profile[for2::P1]) {
+                ('C', 'C') => Payoff::from([2, 2]),
+                ('C', 'D') => Payoff::from([0, 3]),
+                ('D', 'C') => Payoff::from([3, 0]),
+                _ => Payoff::from([1, 1]),
+            },
+        );
+        assert!(pd.game_tree().structurally_eq(&pd_fn.game_tree(), &pd, 5));
+
+        // Repeated games are compared by their move sequences, up to the depth bound.
+        let rpd = Repeated::new(Arc::new(pd.clone()), 2);
+        let rpd_fn = Repeated::new(Arc::new(pd_fn), 2);
+        assert!(rpd
+            .game_tree()
+            .structurally_eq(&rpd_fn.game_tree(), &rpd, 10));
+
+        let longer = Repeated::new(Arc::new(pd.clone()), 3);
+        assert!(!rpd
+            .game_tree()
+            .structurally_eq(&longer.game_tree(), &rpd, 10));
+        assert!(rpd
+            .game_tree()
+            .structurally_eq(&longer.game_tree(), &rpd, 2));
+
+        let stag_hunt: Normal<char, i32, 2> =
+            Normal::symmetric(vec!['C', 'D'], vec![3, 0, 2, 1]).unwrap();
+        assert!(!pd
+            .game_tree()
+            .structurally_eq(&stag_hunt.game_tree(), &pd, 5));
+    }
+
+    /// A tic-tac-toe board, where cells are indexed row by row from the top left.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Board {
+        cells: [Option<PlayerIndex<2>>; 9],
+        to_move: PlayerIndex<2>,
+        transcript: Transcript<usize, 2>,
+    }
+
+    const LINES: [[usize; 3]; 8] = [
+        [0, 1, 2],
+        [3, 4, 5],
+        [6, 7, 8],
+        [0, 3, 6],
+        [1, 4, 7],
+        [2, 5, 8],
+        [0, 4, 8],
+        [2, 4, 6],
+    ];
+
+    impl Board {
+        fn winner(&self) -> Option<PlayerIndex<2>> {
+            LINES.iter().find_map(|line| {
+                let first = self.cells[line[0]]?;
+                line.iter()
+                    .all(|&cell| self.cells[cell] == Some(first))
+                    .then_some(first)
+            })
+        }
+
+        fn is_full(&self) -> bool {
+            self.cells.iter().all(|cell| cell.is_some())
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct TicTacToe;
+
+    type TicTacToeTree = GameTree<Board, usize, i32, SequentialOutcome<usize, i32, 2>, 2>;
+
+    fn tic_tac_toe_node(board: Board) -> TicTacToeTree {
+        if let Some(winner) = board.winner() {
+            let payoff = Payoff::zeros().except(winner, 1).except(winner.next(), -1);
+            let outcome = SequentialOutcome::new(board.transcript.clone(), payoff);
+            return GameTree::end(Arc::new(board), outcome);
+        }
+        if board.is_full() {
+            let outcome = SequentialOutcome::new(board.transcript.clone(), Payoff::zeros());
+            return GameTree::end(Arc::new(board), outcome);
+        }
+
+        let to_move = board.to_move;
+        GameTree::player(Arc::new(board), to_move, move |board: Arc<Board>, cell| {
+            if board.cells.get(cell) != Some(&None) {
+                return Err(ErrorKind::InvalidMove(to_move, cell));
+            }
+            let mut next = (*board).clone();
+            next.cells[cell] = Some(to_move);
+            next.to_move = to_move.next();
+            next.transcript.add_player_move(to_move, cell);
+            Ok(tic_tac_toe_node(next))
+        })
+    }
+
+    impl Game<2> for TicTacToe {
+        type Move = usize;
+        type Utility = i32;
+        type Outcome = SequentialOutcome<usize, i32, 2>;
+        type State = Board;
+        type View = Board;
+
+        fn into_game_tree(self) -> TicTacToeTree {
+            tic_tac_toe_node(Board {
+                cells: [None; 9],
+                to_move: for2::P0,
+                transcript: Transcript::new(),
+            })
+        }
+
+        fn state_view(&self, state: &Board, _player: PlayerIndex<2>) -> Board {
+            state.clone()
+        }
+    }
+
+    impl FiniteGame<2> for TicTacToe {
+        fn possible_moves(
+            &self,
+            _player: PlayerIndex<2>,
+            state: &Board,
+        ) -> PossibleMoves<'_, usize> {
+            PossibleMoves::from_vec((0..9).filter(|&i| state.cells[i].is_none()).collect())
+        }
+    }
+
+    #[test]
+    fn solving_tic_tac_toe_is_a_draw() {
+        let (value, line) = TicTacToe
+            .game_tree()
+            .solve_combinatorial(&TicTacToe)
+            .unwrap();
+        assert_eq!(value, Payoff::zeros());
+
+        // Replaying the principal variation reaches the end of the game with the solved value.
+        let mut node = TicTacToe.game_tree();
+        for &cell in &line {
+            match node.action {
+                Action::Turns { next, .. } => node = next(node.state.clone(), vec![cell]).unwrap(),
+                _ => panic!("the principal variation continues past the end of the game"),
+            }
+        }
+        match node.action {
+            Action::End { outcome, .. } => assert_eq!(*outcome.payoff(), value),
+            _ => panic!("the principal variation ends before the end of the game"),
+        }
+        assert!(node.state.is_full() || node.state.winner().is_some());
+        assert_eq!(node.state.winner(), None);
+        assert_eq!(line.len(), 9);
+
+        // From a position where the first player can complete a row, they win immediately.
+        let mut board = TicTacToe.game_tree();
+        for cell in [0, 3, 1, 4] {
+            if let Action::Turns { next, .. } = board.action {
+                board = next(board.state.clone(), vec![cell]).unwrap();
+            }
+        }
+        let (value, line) = board.solve_combinatorial(&TicTacToe).unwrap();
+        assert_eq!(value, Payoff::from([1, -1]));
+        assert_eq!(line, vec![2]);
+    }
+
+    /// A three-player game where each player in turn takes one to three coins from a pot of six.
+    /// Each player keeps what they took, unless the players took more than six coins in total, in
+    /// which case nobody gets anything.
+    #[derive(Clone, Copy)]
+    struct Pot;
+
+    type PotTree = GameTree<Vec<u8>, u8, u8, SequentialOutcome<u8, u8, 3>, 3>;
+
+    fn pot_node(taken: Vec<u8>) -> PotTree {
+        if taken.len() == 3 {
+            let mut transcript = Transcript::new();
+            let mut payoff = Payoff::zeros();
+            for (player, &coins) in PlayerIndex::all().zip(&taken) {
+                transcript.add_player_move(player, coins);
+                payoff = payoff.except(player, coins);
+            }
+            if taken.iter().sum::<u8>() > 6 {
+                payoff = Payoff::zeros();
+            }
+            return GameTree::end(Arc::new(taken), SequentialOutcome::new(transcript, payoff));
+        }
+
+        let to_move = PlayerIndex::new(taken.len()).unwrap();
+        GameTree::player(Arc::new(taken), to_move, |taken: Arc<Vec<u8>>, coins| {
+            let mut next = (*taken).clone();
+            next.push(coins);
+            Ok(pot_node(next))
+        })
+    }
+
+    impl Game<3> for Pot {
+        type Move = u8;
+        type Utility = u8;
+        type Outcome = SequentialOutcome<u8, u8, 3>;
+        type State = Vec<u8>;
+        type View = Vec<u8>;
+
+        fn into_game_tree(self) -> PotTree {
+            pot_node(Vec::new())
+        }
+
+        fn state_view(&self, state: &Vec<u8>, _player: PlayerIndex<3>) -> Vec<u8> {
+            state.clone()
+        }
+    }
+
+    impl FiniteGame<3> for Pot {
+        fn possible_moves(
+            &self,
+            _player: PlayerIndex<3>,
+            _state: &Vec<u8>,
+        ) -> PossibleMoves<'_, u8> {
+            PossibleMoves::from_vec(vec![1, 2, 3])
+        }
+    }
+
+    #[test]
+    fn solve_to_depth_maximizes_each_movers_own_payoff() {
+        // Estimate the payoff of an unfinished game by what each player has taken so far.
+        let taken_so_far = |taken: &Vec<u8>| {
+            PlayerIndex::all()
+                .zip(taken)
+                .fold(Payoff::zeros(), |payoff, (player, &coins)| {
+                    payoff.except(player, coins)
+                })
+        };
+
+        // The last player takes as much as the pot allows, and the earlier players anticipate it,
+        // so the first player takes the most.
+        let (value, line) = Pot
+            .game_tree()
+            .solve_to_depth(&Pot, 3, taken_so_far)
+            .unwrap();
+        assert_eq!(value, Payoff::from([3, 2, 1]));
+        assert_eq!(line, vec![3, 2, 1]);
+        assert_eq!(
+            Pot.game_tree().solve_combinatorial(&Pot),
+            Some((value, line))
+        );
+
+        // Looking only one move ahead, each player greedily takes three coins, and the second
+        // player doesn't see that the third player will empty the pot.
+        let (value, line) = Pot
+            .game_tree()
+            .solve_to_depth(&Pot, 1, taken_so_far)
+            .unwrap();
+        assert_eq!(value, Payoff::from([3, 0, 0]));
+        assert_eq!(line, vec![3]);
+
+        // Looking two moves ahead, the second player still expects three coins.
+        let (value, line) = Pot
+            .game_tree()
+            .solve_to_depth(&Pot, 2, taken_so_far)
+            .unwrap();
+        assert_eq!(value, Payoff::from([3, 3, 0]));
+        assert_eq!(line, vec![3, 3]);
+
+        // At depth zero, the node is evaluated directly.
+        let (value, line) = Pot
+            .game_tree()
+            .solve_to_depth(&Pot, 0, taken_so_far)
+            .unwrap();
+        assert_eq!(value, Payoff::zeros());
+        assert!(line.is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+//! Tests that count heap allocations. These install a counting global allocator, so they live in
+//! their own test binary to keep the library's unit tests on the system allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use t4t::{Normal, Payoff, PerPlayer, PossibleProfiles, Profile};
+
+/// An allocator that counts the allocations made by each thread, so that tests running in
+/// parallel do not affect each other's counts.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn profiles_of_large_game_are_generated_without_copying_moves() {
+    let n = 40;
+    let game = Normal::from_payoff_fn(
+        PerPlayer::new([(0..n).collect(), (0..n).collect(), (0..n).collect()]),
+        |_: Profile<u32, 3>| Payoff::<i32, 3>::zeros(),
+    );
+
+    // Count the profiles without collecting them, to measure only the iterator's allocations.
+    let (count, allocations) = allocations_during(|| game.possible_profiles().count());
+    assert_eq!(count, (n * n * n) as usize);
+    // One allocation per restart of a player's moves (cloning the boxed iterator), rather than
+    // one or more allocations per profile.
+    assert!(
+        allocations <= 2 * (n * n) as usize,
+        "{} allocations for {} profiles",
+        allocations,
+        count
+    );
+
+    let expected: Vec<Profile<u32, 3>> = (0..n)
+        .flat_map(|a| (0..n).flat_map(move |b| (0..n).map(move |c| Profile::new([a, b, c]))))
+        .collect();
+    assert_eq!(game.possible_profiles().collect::<Vec<_>>(), expected);
+    let shared = PossibleProfiles::from_move_vecs(PerPlayer::init_with((0..n).collect()));
+    assert_eq!(shared.collect::<Vec<_>>(), expected);
+}